@@ -0,0 +1,78 @@
+// Advent of code (adventofcode.com) build script
+// 
+// Collects all puzzle modules (`./src/yearYYYY/dayDD.rs`) and generates their module
+// declarations and registration, so that a new day (or year) is picked up just by
+// adding its file (or directory)
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Build script entry point
+fn main() {
+  // Get source and output directories
+  let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+  let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+  println!("cargo:rerun-if-changed={}", src.display());
+
+  // Find all year directories
+  let years = find_numbered(&src, "year", "");
+  let mut years_code = String::from("// Generated by build.rs: one module per ./src/yearYYYY directory\n\n");
+  let mut years_registration = String::new();
+  for (year, year_path) in years.iter() {
+    // Find all days of the year and generate their declaration and registration
+    let days = find_numbered(year_path, "day", ".rs");
+    let mut days_code = format!("// Generated by build.rs: one module per ./src/year{}/dayDD.rs file\n\n", year);
+    let mut days_registration = String::new();
+    for (day, day_path) in days.iter() {
+      days_code.push_str(&format!("#[path = {:?}]\nmod day{:02};\n", day_path.display().to_string(), day));
+      days_registration.push_str(&format!("  registry = day{:02}::init(registry);\n", day));
+    }
+    days_code.push_str(&format!("\n/// Registers all puzzles of the year\npub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {{\n{}  registry\n}}\n", days_registration));
+    write_if_changed(&out.join(format!("year{}.rs", year)), &days_code);
+
+    // Generate year's declaration and registration
+    years_code.push_str(&format!("#[path = {:?}]\nmod year{};\n", year_path.join("mod.rs").display().to_string(), year));
+    years_registration.push_str(&format!("  if puzzle.includes_year({}) {{\n    registry = year{}::init(registry);\n  }}\n", year, year));
+  }
+  years_code.push_str(&format!("\n/// Registers puzzles of all years included in a puzzle selection\nfn register_years (mut registry: PuzzleRegistry, puzzle: &PuzzleFilter) -> PuzzleRegistry {{\n{}  registry\n}}\n", years_registration));
+  write_if_changed(&out.join("years.rs"), &years_code);
+}
+
+/// Finds directory entries named as a prefix followed by a number and a suffix (e.g. `day07.rs`)
+/// 
+/// # Arguments
+/// * dir:    Directory to search
+/// * prefix: Name prefix, preceding the number
+/// * suffix: Name suffix, following the number
+/// 
+/// # Returns
+/// Numbers and paths of all matching entries, ordered by number
+fn find_numbered (dir: &Path, prefix: &str, suffix: &str) -> Vec<(u32, PathBuf)> {
+  let mut found: Vec<(u32, PathBuf)> = fs::read_dir(dir).unwrap()
+    .filter_map(|entry| entry.ok())
+    .filter_map(|entry| {
+      let name = entry.file_name().to_string_lossy().to_string();
+      let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+      if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+      }
+      Some((number.parse::<u32>().ok()?, entry.path()))
+    })
+    .collect();
+  found.sort();
+  found
+}
+
+/// Writes a file, unless it already has the same content (so that nothing gets needlessly recompiled)
+/// 
+/// # Arguments
+/// * path:    Path of the file to write
+/// * content: Content to write
+fn write_if_changed (path: &Path, content: &str) {
+  if fs::read_to_string(path).ok().as_deref() != Some(content) {
+    fs::write(path, content).unwrap();
+  }
+}
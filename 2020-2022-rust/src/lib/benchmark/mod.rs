@@ -0,0 +1,121 @@
+//! Benchmark module
+//! 
+//! Provides statistics over repeated puzzle execution timings
+// -----------------------------------------------------------------------------
+
+/// Benchmark struct
+/// 
+/// Holds timing samples (in seconds) of repeated executions
+pub struct Benchmark {
+  samples: Vec<f64>
+}
+/// Benchmark implementation
+/// 
+/// Provides statistics over repeated puzzle execution timings
+impl Benchmark {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * samples: Timing samples (in seconds) of repeated executions
+  pub fn new (mut samples: Vec<f64>) -> Benchmark {
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Benchmark { samples }
+  }
+
+  /// Gets number of collected samples
+  pub fn count (&self) -> usize {
+    self.samples.len()
+  }
+
+  /// Gets fastest sample
+  pub fn min (&self) -> f64 {
+    if self.samples.is_empty() { 0.0 } else { self.samples[0] }
+  }
+
+  /// Gets average of all samples
+  pub fn mean (&self) -> f64 {
+    if self.samples.is_empty() { 0.0 } else { self.samples.iter().sum::<f64>() / self.samples.len() as f64 }
+  }
+
+  /// Gets median of all samples
+  pub fn median (&self) -> f64 {
+    let n = self.samples.len();
+    if n == 0 { 0.0 }
+    else if n % 2 == 1 { self.samples[n / 2] }
+    else { (self.samples[n / 2 - 1] + self.samples[n / 2]) / 2.0 }
+  }
+
+  /// Gets a percentile of all samples (using nearest-rank method)
+  /// 
+  /// # Arguments
+  /// * percentile: Percentile to get, between 0 and 100
+  pub fn percentile (&self, percentile: f64) -> f64 {
+    if self.samples.is_empty() { return 0.0; }
+    let rank = ((percentile / 100.0) * self.samples.len() as f64).ceil() as usize;
+    self.samples[rank.clamp(1, self.samples.len()) - 1]
+  }
+
+  /// Gets (population) standard deviation of all samples
+  pub fn stddev (&self) -> f64 {
+    if self.samples.is_empty() { return 0.0; }
+    let mean = self.mean();
+    let variance = self.samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / self.samples.len() as f64;
+    variance.sqrt()
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Checks two floats are equal, up to rounding errors
+  fn assert_close (a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+  }
+
+  #[test]
+  fn sorts_samples_and_finds_min_mean_and_median () {
+    let benchmark = Benchmark::new(vec![4.0, 1.0, 3.0, 2.0]);
+    assert_eq!(benchmark.count(), 4);
+    assert_close(benchmark.min(), 1.0);
+    assert_close(benchmark.mean(), 2.5);
+    // Median of an even number of samples averages the two middle samples
+    assert_close(benchmark.median(), 2.5);
+    assert_close(Benchmark::new(vec![5.0, 1.0, 3.0]).median(), 3.0);
+  }
+
+  #[test]
+  fn finds_nearest_rank_percentiles () {
+    let benchmark = Benchmark::new((1..=20).rev().map(|n| n as f64).collect());
+    // Rank of 95th percentile of 20 samples is ceil(0.95 * 20) = 19
+    assert_close(benchmark.percentile(95.0), 19.0);
+    assert_close(benchmark.percentile(96.0), 20.0);
+    assert_close(benchmark.percentile(100.0), 20.0);
+    assert_close(benchmark.percentile(0.0), 1.0);
+    assert_close(Benchmark::new(vec![1.0, 2.0, 3.0]).percentile(50.0), 2.0);
+  }
+
+  #[test]
+  fn finds_population_standard_deviation () {
+    assert_close(Benchmark::new(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).stddev(), 2.0);
+  }
+
+  #[test]
+  fn handles_single_and_no_samples () {
+    let single = Benchmark::new(vec![0.5]);
+    assert_eq!(single.count(), 1);
+    assert_close(single.min(), 0.5);
+    assert_close(single.mean(), 0.5);
+    assert_close(single.median(), 0.5);
+    assert_close(single.percentile(95.0), 0.5);
+    assert_close(single.stddev(), 0.0);
+    let empty = Benchmark::new(vec![]);
+    assert_eq!(empty.count(), 0);
+    assert_close(empty.median(), 0.0);
+    assert_close(empty.percentile(95.0), 0.0);
+    assert_close(empty.stddev(), 0.0);
+  }
+
+}
@@ -0,0 +1,168 @@
+//! Context module
+//! 
+//! Execution context passed to puzzles, carrying verbosity and channels for log messages and visualization frames
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::cell::RefCell;
+use crate::lib::stdout::*;
+use crate::lib::playback::*;
+use crate::lib::image::*;
+
+/// Trace entry, emitted by a puzzle while executing
+#[derive(Clone, Debug, PartialEq)]
+pub enum Trace {
+  /// Log message (output with `--verbose`)
+  Log(String),
+  /// Rendered visualization frame (output with `--visualize`)
+  Frame(String)
+}
+/// Trace implementation
+impl Trace {
+
+  /// Outputs a trace entry
+  /// 
+  /// # Arguments
+  /// * stderr: If trace should be output to STDERR (keeping STDOUT clean for structured output formats)
+  pub fn output (&self, stderr: bool) {
+    let text = match self {
+      Trace::Log(message) => message.clone(),
+      Trace::Frame(frame) => format!("{}\n", frame)
+    };
+    if stderr {
+      eprintln!("{}", text);
+    } else {
+      StdOut::println(text, None);
+    }
+  }
+
+}
+
+/// PuzzleContext struct
+/// 
+/// Passed to contextual puzzle implementations, allowing them to log and visualize their
+/// progress without knowing if (or where) any of it is going to be output
+pub struct PuzzleContext {
+  /// If log messages are being collected (`--verbose`)
+  pub verbose: bool,
+  /// If visualization frames are being collected (`--visualize`)
+  pub visualize: bool,
+  /// If traces are being kept until the execution concludes, instead of output as they are emitted
+  buffered: bool,
+  traces: RefCell<Vec<Trace>>,
+  /// Interactive playback showing visualization frames as they are emitted (while keeping log messages)
+  playback: Option<RefCell<Playback>>,
+  /// Image sequence saving visualization frames as they are emitted (regardless of them also being collected)
+  export: RefCell<Option<ImageSequence>>
+}
+/// PuzzleContext implementation
+#[allow(dead_code)]
+impl PuzzleContext {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * verbose:   If log messages should be collected
+  /// * visualize: If visualization frames should be collected
+  /// * buffered:  If traces should be kept until taken, instead of being output as they are emitted
+  pub fn new (verbose: bool, visualize: bool, buffered: bool) -> PuzzleContext {
+    PuzzleContext {
+      verbose,
+      visualize,
+      buffered,
+      traces: RefCell::new(vec![]),
+      playback: None,
+      export: RefCell::new(None)
+    }
+  }
+
+  /// Sets interactive playback to show visualization frames as they are emitted
+  /// 
+  /// # Arguments
+  /// * playback: Playback to show frames in
+  pub fn with_playback (mut self, playback: Playback) -> PuzzleContext {
+    self.playback = Some(RefCell::new(playback));
+    self
+  }
+
+  /// Sets image sequence to save visualization frames to, as they are emitted
+  /// 
+  /// # Arguments
+  /// * export: Image sequence to save frames to
+  pub fn with_export (mut self, export: ImageSequence) -> PuzzleContext {
+    self.export = RefCell::new(Some(export));
+    self
+  }
+
+  /// Constructor
+  /// 
+  /// Context discarding all traces (used when repeating executions)
+  pub fn silent () -> PuzzleContext {
+    PuzzleContext::new(false, false, true)
+  }
+
+  /// Emits a log message, if log messages are being collected
+  /// 
+  /// # Arguments
+  /// * message: Log message
+  pub fn log<S: Into<String>> (&self, message: S) {
+    if self.verbose {
+      self.emit(Trace::Log(message.into()));
+    }
+  }
+
+  /// Checks if visualization frames are being collected or exported
+  pub fn is_visualizing (&self) -> bool {
+    self.export.borrow().is_some() || self.is_collecting()
+  }
+
+  /// Emits a visualization frame, if visualization frames are being collected or exported
+  /// 
+  /// # Arguments
+  /// * render: Callback rendering the frame (only called if frames are being collected or exported)
+  pub fn frame<F: FnOnce() -> String> (&self, render: F) {
+    if !self.is_visualizing() {
+      return;
+    }
+    let frame = render();
+    // Export frame, dropping the export (with an explanation) once a frame fails to save
+    let failed = self.export.borrow_mut().as_mut().and_then(|export| export.save_text(&frame).err());
+    if let Some(err) = failed {
+      *self.export.borrow_mut() = None;
+      self.emit(Trace::Log(format!("Stopped exporting visualization frames: {}", err)));
+    }
+    if self.is_collecting() {
+      self.emit(Trace::Frame(frame));
+    }
+  }
+
+  /// Takes all traces kept so far
+  pub fn take (&self) -> Vec<Trace> {
+    self.traces.borrow_mut().drain(..).collect()
+  }
+
+  /// Finishes any interactive playback, once the puzzle has stopped emitting frames
+  pub fn finish (&self) {
+    if let Some(playback) = &self.playback {
+      playback.borrow_mut().finish();
+    }
+  }
+
+  /// Checks if visualization frames are being collected (and not skipped by a playback having been quit)
+  fn is_collecting (&self) -> bool {
+    self.visualize && self.playback.as_ref().is_none_or(|playback| playback.borrow().is_playing())
+  }
+
+  /// Keeps, outputs or plays back an emitted trace
+  fn emit (&self, trace: Trace) {
+    match (trace, &self.playback) {
+      (Trace::Frame(frame), Some(playback)) => playback.borrow_mut().show(frame),
+      (trace, playback) => if self.buffered || playback.is_some() {
+        self.traces.borrow_mut().push(trace);
+      } else {
+        trace.output(false);
+      }
+    }
+  }
+
+}
@@ -0,0 +1,122 @@
+//! Error module
+//! 
+//! Defines errors reported by the runner and the process exit codes they map to
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::fmt;
+use std::num::{ParseIntError, ParseFloatError};
+
+/// Process exit codes
+pub struct ExitCode {}
+/// Process exit codes implementation
+impl ExitCode {
+  /// All executed puzzles succeeded (or had no expected result)
+  pub const SUCCESS: i32 = 0;
+  /// At least one puzzle's result didn't match the expected result
+  pub const MISMATCH: i32 = 1;
+  /// Startup arguments were invalid
+  pub const USAGE: i32 = 2;
+  /// No registered puzzle matched the puzzle selection
+  pub const NO_MATCH: i32 = 3;
+  /// Input data (or manifest) could not be read
+  pub const INPUT: i32 = 4;
+}
+
+/// Enumerates runner errors
+#[derive(Debug)]
+pub enum CliError {
+  /// Flag was passed as the last argument, without a value
+  MissingValue(String),
+  /// Flag was passed with a value which couldn't be parsed (flag, value, expected value description)
+  InvalidValue(String, String, String),
+  /// Unrecognized flag
+  UnknownFlag(String),
+  /// Unexpected positional argument
+  UnexpectedArgument(String),
+  /// Input file couldn't be read (path, reason)
+  UnreadableInput(String, String)
+}
+/// CliError implementation
+impl CliError {
+
+  /// Gets process exit code matching the error
+  pub fn exit_code (&self) -> i32 {
+    match self {
+      CliError::UnreadableInput(_, _) => ExitCode::INPUT,
+      _ => ExitCode::USAGE
+    }
+  }
+
+}
+impl fmt::Display for CliError {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CliError::MissingValue(flag) => write!(f, "Missing value for '{}' parameter!", flag),
+      CliError::InvalidValue(flag, value, expected) => write!(f, "Failed parsing '{}' parameter value \"{}\" - expecting {}!", flag, value, expected),
+      CliError::UnknownFlag(flag) => write!(f, "Unknown parameter '{}'!", flag),
+      CliError::UnexpectedArgument(arg) => write!(f, "Unexpected argument \"{}\" - values need to follow a parameter!", arg),
+      CliError::UnreadableInput(path, reason) => write!(f, "Failed reading from input file \"{}\": {}", path, reason)
+    }
+  }
+}
+
+/// Input data parsing error, pointing to the line and column (both starting at 1) parsing failed at
+#[derive(Debug)]
+pub struct ParseError {
+  pub line: usize,
+  pub column: usize,
+  pub message: String
+}
+impl fmt::Display for ParseError {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} (at line {}, column {})", self.message, self.line, self.column)
+  }
+}
+
+/// Enumerates errors a (fallible) puzzle implementation can fail with
+#[derive(Debug)]
+pub enum PuzzleError {
+  /// Input data couldn't be parsed
+  Parse(String),
+  /// Input data was parsed, but isn't valid for the puzzle
+  InvalidInput(String),
+  /// No solution could be found for the input data
+  NoSolution(String),
+  /// Puzzle implementation panicked
+  Panic(String),
+  /// Puzzle didn't finish within the time limit (in milliseconds)
+  Timeout(u64),
+  /// Puzzle exceeded the memory budget (in megabytes)
+  OutOfMemory(usize),
+  /// Supervised worker process running the puzzle failed
+  Worker(String)
+}
+impl fmt::Display for PuzzleError {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      PuzzleError::Parse(message) => write!(f, "Failed parsing input data: {}", message),
+      PuzzleError::InvalidInput(message) => write!(f, "Invalid input data: {}", message),
+      PuzzleError::NoSolution(message) => write!(f, "No solution found: {}", message),
+      PuzzleError::Panic(message) => write!(f, "Puzzle panicked: {}", message),
+      PuzzleError::Timeout(ms) => write!(f, "TIMEOUT: Puzzle didn't finish within {}ms", ms),
+      PuzzleError::OutOfMemory(mb) => write!(f, "OOM: Puzzle exceeded the {}MB memory budget", mb),
+      PuzzleError::Worker(message) => write!(f, "Worker failed: {}", message)
+    }
+  }
+}
+impl From<ParseError> for PuzzleError {
+  fn from (err: ParseError) -> Self {
+    PuzzleError::Parse(err.to_string())
+  }
+}
+impl From<ParseIntError> for PuzzleError {
+  fn from (err: ParseIntError) -> Self {
+    PuzzleError::Parse(err.to_string())
+  }
+}
+impl From<ParseFloatError> for PuzzleError {
+  fn from (err: ParseFloatError) -> Self {
+    PuzzleError::Parse(err.to_string())
+  }
+}
@@ -0,0 +1,610 @@
+//! Image module
+//! 
+//! Raster images of rendered cells, exported as PPM, PNG or SVG files (singly, or as numbered frame sequences)
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Largest distance a compressed sequence of bytes can refer back to
+const DEFLATE_WINDOW: usize = 32768;
+/// Lengths encoded by each (fixed Huffman) length symbol, starting with symbol 257
+const DEFLATE_LENGTH_BASE: [usize; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+/// Number of extra bits following each length symbol
+const DEFLATE_LENGTH_EXTRA: [u32; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+/// Distances encoded by each distance symbol
+const DEFLATE_DISTANCE_BASE: [usize; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+/// Number of extra bits following each distance symbol
+const DEFLATE_DISTANCE_EXTRA: [u32; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+/// RGB color
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+/// RGB color implementation
+impl Rgb {
+
+  /// Parses a color from its hex notation
+  /// 
+  /// # Arguments
+  /// * value: Hex notation of the color, e.g. `ffff66` or `#ffff66`
+  /// 
+  /// # Returns
+  /// Parsed color, or `None` if not valid hex notation
+  pub fn parse (value: &str) -> Option<Rgb> {
+    let value = value.trim().trim_start_matches('#');
+    if value.len() != 6 || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+      return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&value[i..(i + 2)], 16).ok();
+    Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+  }
+
+}
+impl fmt::Display for Rgb {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+  }
+}
+
+/// Palette struct
+/// 
+/// Maps characters of a rendered frame onto colors: characters without a color of their own are painted in the
+/// background color if blank (`.` or ` `), or in the foreground color otherwise
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+  pub background: Rgb,
+  pub foreground: Rgb,
+  pub colors: Vec<(char, Rgb)>
+}
+/// Palette implementation
+impl Palette {
+
+  /// Parses a palette
+  /// 
+  /// # Arguments
+  /// * value: Comma separated list of `<character>=<color>` pairs, with `background` and `foreground`
+  ///   allowed instead of a character, e.g. `background=000000,#=ffffff,o=c2b280`
+  /// 
+  /// # Returns
+  /// Parsed palette (starting from the default palette), or a description of expected syntax
+  pub fn parse (value: &str) -> Result<Palette, String> {
+    let expected = String::from("a comma separated list of <character>=<hex color> pairs, e.g. background=000000,#=ffffff,o=c2b280");
+    let mut palette = Palette::default();
+    for pair in value.split(',').filter(|pair| !pair.trim().is_empty()) {
+      let (key, color) = pair.split_once('=').ok_or_else(|| expected.clone())?;
+      let color = Rgb::parse(color).ok_or_else(|| expected.clone())?;
+      match key.trim() {
+        "background" => palette.background = color,
+        "foreground" => palette.foreground = color,
+        key if key.chars().count() == 1 => palette = palette.with(key.chars().next().unwrap(), color),
+        _ => return Err(expected)
+      }
+    }
+    Ok(palette)
+  }
+
+  /// Sets color of a character
+  /// 
+  /// # Arguments
+  /// * c:     Character to set the color of
+  /// * color: Color of the character
+  pub fn with (mut self, c: char, color: Rgb) -> Palette {
+    self.colors.retain(|(key, _)| key != &c);
+    self.colors.push((c, color));
+    self
+  }
+
+  /// Gets color of a character
+  /// 
+  /// # Arguments
+  /// * c: Character to get the color of
+  pub fn color (&self, c: char) -> Rgb {
+    match self.colors.iter().find(|(key, _)| key == &c) {
+      Some((_, color)) => *color,
+      None => if c == '.' || c == ' ' { self.background } else { self.foreground }
+    }
+  }
+
+}
+impl Default for Palette {
+  /// Light on dark palette, in the colors of adventofcode.com
+  fn default () -> Palette {
+    Palette {
+      background: Rgb(15, 15, 35),
+      foreground: Rgb(255, 255, 102),
+      colors: vec![]
+    }
+  }
+}
+impl fmt::Display for Palette {
+  /// Formats the palette in the same syntax it's parsed from
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let hex = |color: &Rgb| color.to_string()[1..].to_string();
+    let mut pairs = vec![format!("background={}", hex(&self.background)), format!("foreground={}", hex(&self.foreground))];
+    pairs.extend(self.colors.iter().map(|(c, color)| format!("{}={}", c, hex(color))));
+    write!(f, "{}", pairs.join(","))
+  }
+}
+
+/// Supported image file formats
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+  /// Binary portable pixmap (`.ppm`)
+  Ppm,
+  /// Portable network graphics (`.png`)
+  Png,
+  /// Scalable vector graphics (`.svg`)
+  Svg
+}
+/// Image format implementation
+impl ImageFormat {
+
+  /// Gets image format from a file's extension
+  /// 
+  /// # Arguments
+  /// * path: Path of the image file
+  /// 
+  /// # Returns
+  /// Image format, or `None` if extension isn't supported
+  pub fn from_path (path: &str) -> Option<ImageFormat> {
+    match Path::new(path).extension()?.to_str()?.to_lowercase().as_str() {
+      "ppm" => Some(ImageFormat::Ppm),
+      "png" => Some(ImageFormat::Png),
+      "svg" => Some(ImageFormat::Svg),
+      _ => None
+    }
+  }
+
+}
+
+/// Image struct
+/// 
+/// Holds a color for each cell (row by row, cell at (x, y) being stored at `pixels[y * width + x]`),
+/// each cell being exported as a `scale` by `scale` pixels square
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+  pub width: usize,
+  pub height: usize,
+  pub pixels: Vec<Rgb>,
+  pub scale: usize
+}
+/// Image implementation
+impl Image {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * width:  Width of the image, in cells
+  /// * height: Height of the image, in cells
+  /// * f:      Callback getting the color of a cell from its (x, y) coordinates
+  pub fn from_fn<F: Fn(usize, usize) -> Rgb> (width: usize, height: usize, f: F) -> Image {
+    let pixels = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+    Image { width, height, pixels, scale: 1 }
+  }
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * text:    Rendered text, one row of cells per line and one cell per character (shorter lines are padded with background)
+  /// * palette: Palette to color characters with
+  pub fn from_text (text: &str, palette: &Palette) -> Image {
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    Image::from_fn(width, lines.len(), |x, y| lines[y].get(x).map_or(palette.background, |c| palette.color(*c)))
+  }
+
+  /// Sets number of pixels each cell is exported as, along each side
+  /// 
+  /// # Arguments
+  /// * scale: Size of each cell, in pixels
+  pub fn with_scale (mut self, scale: usize) -> Image {
+    self.scale = scale.max(1);
+    self
+  }
+
+  /// Encodes the image as a binary PPM (P6) file
+  pub fn to_ppm (&self) -> Vec<u8> {
+    let (width, height) = (self.width * self.scale, self.height * self.scale);
+    let mut bytes = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    for row in self.scaled_rows() {
+      bytes.extend(row);
+    }
+    bytes
+  }
+
+  /// Encodes the image as a (truecolor, non interlaced) PNG file
+  pub fn to_png (&self) -> Vec<u8> {
+    let (width, height) = (self.width * self.scale, self.height * self.scale);
+    // Compose scanlines, each preceded by its filter type (none)
+    let mut scanlines: Vec<u8> = Vec::with_capacity(height * (width * 3 + 1));
+    for row in self.scaled_rows() {
+      scanlines.push(0);
+      scanlines.extend(row);
+    }
+    // Compose chunks
+    let mut header: Vec<u8> = vec![];
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]);
+    let mut bytes: Vec<u8> = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+    Image::png_chunk(&mut bytes, b"IHDR", &header);
+    Image::png_chunk(&mut bytes, b"IDAT", &Image::zlib(&scanlines, &[3, width * 3 + 1]));
+    Image::png_chunk(&mut bytes, b"IEND", &[]);
+    bytes
+  }
+
+  /// Encodes the image as an SVG file, each horizontal run of same colored cells drawn as a single rectangle
+  pub fn to_svg (&self) -> String {
+    let background = self.background();
+    let mut svg = format!(
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+      self.width * self.scale, self.height * self.scale, self.width, self.height
+    );
+    svg.push_str(&format!("  <rect width=\"{}\" height=\"{}\" fill=\"{}\"/>\n", self.width, self.height, background));
+    for y in 0..self.height {
+      let row = &self.pixels[(y * self.width)..((y + 1) * self.width)];
+      let mut x = 0;
+      while x < self.width {
+        let run = row[x..].iter().take_while(|color| **color == row[x]).count();
+        if row[x] != background {
+          svg.push_str(&format!("  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>\n", x, y, run, row[x]));
+        }
+        x += run;
+      }
+    }
+    svg.push_str("</svg>\n");
+    svg
+  }
+
+  /// Encodes the image in a format
+  /// 
+  /// # Arguments
+  /// * format: Format to encode the image in
+  pub fn encode (&self, format: ImageFormat) -> Vec<u8> {
+    match format {
+      ImageFormat::Ppm => self.to_ppm(),
+      ImageFormat::Png => self.to_png(),
+      ImageFormat::Svg => self.to_svg().into_bytes()
+    }
+  }
+
+  /// Saves the image to a file, in the format matching the file's extension
+  /// 
+  /// # Arguments
+  /// * path: Path of the file to save to (any missing parent directories are created)
+  /// 
+  /// # Returns
+  /// Nothing, or a description of why image couldn't be saved
+  pub fn save (&self, path: &str) -> Result<(), String> {
+    let format = ImageFormat::from_path(path).ok_or_else(|| format!("Unsupported image format \"{}\" (expecting .ppm, .png or .svg)", path))?;
+    if let Some(dir) = Path::new(path).parent().filter(|dir| !dir.as_os_str().is_empty()) {
+      fs::create_dir_all(dir).map_err(|err| format!("Failed creating directory \"{}\": {}", dir.display(), err))?;
+    }
+    fs::write(path, self.encode(format)).map_err(|err| format!("Failed writing \"{}\": {}", path, err))
+  }
+
+  /// Finds the most common color (drawn as background of SVG files)
+  fn background (&self) -> Rgb {
+    let mut counts: Vec<(Rgb, usize)> = vec![];
+    for color in self.pixels.iter() {
+      match counts.iter_mut().find(|(c, _)| c == color) {
+        Some((_, count)) => *count += 1,
+        None => counts.push((*color, 1))
+      }
+      // Give up counting on very colorful images
+      if counts.len() > 256 { break; }
+    }
+    counts.iter().max_by_key(|(_, count)| *count).map_or(Rgb(0, 0, 0), |(color, _)| *color)
+  }
+
+  /// Iterates over rows of scaled pixels, as RGB bytes
+  fn scaled_rows (&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+    (0..self.height).flat_map(move |y| {
+      let row: Vec<u8> = self.pixels[(y * self.width)..((y + 1) * self.width)].iter()
+        .flat_map(|color| [color.0, color.1, color.2].repeat(self.scale))
+        .collect();
+      std::iter::repeat_n(row, self.scale)
+    })
+  }
+
+  /// Appends a PNG chunk
+  fn png_chunk (bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    bytes.extend(kind);
+    bytes.extend(data);
+    bytes.extend(Image::crc32(&[kind, data]).to_be_bytes());
+  }
+
+  /// Calculates CRC-32 checksum of a sequence of byte slices
+  fn crc32 (data: &[&[u8]]) -> u32 {
+    let table: Vec<u32> = (0..256u32).map(|n| (0..8).fold(n, |c, _| if c & 1 != 0 { 0xedb88320 ^ (c >> 1) } else { c >> 1 })).collect();
+    !data.iter().flat_map(|bytes| bytes.iter()).fold(0xffffffffu32, |crc, byte| table[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8))
+  }
+
+  /// Compresses data into a zlib stream
+  /// 
+  /// # Arguments
+  /// * data:      Data to compress
+  /// * distances: Distances to always try referring back to, besides the last occurrence of the same bytes
+  ///   (previous pixel and previous row, for images)
+  fn zlib (data: &[u8], distances: &[usize]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![0x78, 0x01];
+    bytes.extend(Image::deflate(data, distances));
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), byte| ((a + *byte as u32) % 65521, (b + (a + *byte as u32) % 65521) % 65521));
+    bytes.extend(((b << 16) | a).to_be_bytes());
+    bytes
+  }
+
+  /// Compresses data as a single deflate block using fixed Huffman codes, greedily replacing
+  /// repeated sequences of bytes with references back to their previous occurrence
+  fn deflate (data: &[u8], distances: &[usize]) -> Vec<u8> {
+    let hash = |i: usize| ((data[i] as usize) << 10 ^ (data[i + 1] as usize) << 5 ^ data[i + 2] as usize) & (DEFLATE_WINDOW - 1);
+    let mut last: Vec<usize> = vec![usize::MAX; DEFLATE_WINDOW];
+    let mut writer = BitWriter::default();
+    // Start final block, compressed with fixed Huffman codes
+    writer.write(1, 1);
+    writer.write(1, 2);
+    let mut i = 0;
+    while i < data.len() {
+      // Find longest match among candidate previous occurrences
+      let mut best: (usize, usize) = (0, 0);
+      if i + 3 <= data.len() {
+        let h = hash(i);
+        let candidates = distances.iter().filter(|distance| **distance <= i).map(|distance| i - distance).chain(Some(last[h]));
+        for candidate in candidates.filter(|candidate| *candidate < i && i - candidate <= DEFLATE_WINDOW) {
+          let length = data[i..].iter().zip(data[candidate..].iter()).take(258).take_while(|(a, b)| a == b).count();
+          if length > best.0 {
+            best = (length, i - candidate);
+          }
+        }
+        last[h] = i;
+      }
+      // Write a reference back to the match, or a literal byte
+      if best.0 >= 3 {
+        Image::deflate_match(&mut writer, best.0, best.1);
+        for j in (i + 1)..(i + best.0) {
+          if j + 3 <= data.len() { last[hash(j)] = j; }
+        }
+        i += best.0;
+      } else {
+        Image::deflate_symbol(&mut writer, data[i] as u32);
+        i += 1;
+      }
+    }
+    // End block
+    Image::deflate_symbol(&mut writer, 256);
+    writer.finish()
+  }
+
+  /// Writes a reference to a previous occurrence of a sequence of bytes
+  fn deflate_match (writer: &mut BitWriter, length: usize, distance: usize) {
+    let index = DEFLATE_LENGTH_BASE.iter().rposition(|base| *base <= length).unwrap();
+    Image::deflate_symbol(writer, 257 + index as u32);
+    writer.write((length - DEFLATE_LENGTH_BASE[index]) as u32, DEFLATE_LENGTH_EXTRA[index]);
+    let index = DEFLATE_DISTANCE_BASE.iter().rposition(|base| *base <= distance).unwrap();
+    writer.write_code(index as u32, 5);
+    writer.write((distance - DEFLATE_DISTANCE_BASE[index]) as u32, DEFLATE_DISTANCE_EXTRA[index]);
+  }
+
+  /// Writes a literal/length symbol, using its fixed Huffman code
+  fn deflate_symbol (writer: &mut BitWriter, symbol: u32) {
+    match symbol {
+      0..=143 => writer.write_code(0x30 + symbol, 8),
+      144..=255 => writer.write_code(0x190 + symbol - 144, 9),
+      256..=279 => writer.write_code(symbol - 256, 7),
+      _ => writer.write_code(0xc0 + symbol - 280, 8)
+    }
+  }
+
+}
+
+/// Writes a stream of bits, least significant bit first
+#[derive(Default)]
+struct BitWriter {
+  bytes: Vec<u8>,
+  buffer: u32,
+  count: u32
+}
+impl BitWriter {
+
+  /// Writes a value, least significant bit first
+  fn write (&mut self, value: u32, bits: u32) {
+    self.buffer |= value << self.count;
+    self.count += bits;
+    while self.count >= 8 {
+      self.bytes.push(self.buffer as u8);
+      self.buffer >>= 8;
+      self.count -= 8;
+    }
+  }
+
+  /// Writes a Huffman code, most significant bit first
+  fn write_code (&mut self, code: u32, bits: u32) {
+    self.write(code.reverse_bits() >> (32 - bits), bits);
+  }
+
+  /// Flushes any partially written byte
+  fn finish (mut self) -> Vec<u8> {
+    if self.count > 0 {
+      self.bytes.push(self.buffer as u8);
+    }
+    self.bytes
+  }
+
+}
+
+/// ImageSequence struct
+/// 
+/// Saves frames of an animation as numbered image files
+pub struct ImageSequence {
+  /// Path to save frames to, with `[:frame]` replaced by the frame number (appended to the file name if missing)
+  pub path: String,
+  pub palette: Palette,
+  pub scale: usize,
+  /// Number of frames saved so far
+  pub count: usize
+}
+/// Image sequence implementation
+impl ImageSequence {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * path:    Path to save frames to, e.g. `./frames/[:frame].png`
+  /// * palette: Palette to color rendered text frames with
+  /// * scale:   Size of each cell, in pixels
+  pub fn new (path: &str, palette: Palette, scale: usize) -> ImageSequence {
+    ImageSequence { path: String::from(path), palette, scale, count: 0 }
+  }
+
+  /// Composes path of a frame
+  /// 
+  /// # Arguments
+  /// * index: Number of the frame (starting with 1)
+  pub fn frame_path (&self, index: usize) -> String {
+    let frame = format!("{:05}", index);
+    if self.path.contains("[:frame]") {
+      return self.path.replace("[:frame]", &frame);
+    }
+    match self.path.rfind('.').filter(|dot| !self.path[*dot..].contains('/')) {
+      Some(dot) => format!("{}-{}{}", &self.path[..dot], frame, &self.path[dot..]),
+      None => format!("{}-{}", self.path, frame)
+    }
+  }
+
+  /// Saves the next frame
+  /// 
+  /// # Arguments
+  /// * image: Image of the frame
+  /// 
+  /// # Returns
+  /// Path the frame was saved to, or a description of why it couldn't be saved
+  pub fn save (&mut self, image: &Image) -> Result<String, String> {
+    let path = self.frame_path(self.count + 1);
+    image.save(&path)?;
+    self.count += 1;
+    Ok(path)
+  }
+
+  /// Saves the next frame, rendered as text
+  /// 
+  /// # Arguments
+  /// * text: Rendered frame, one row of cells per line and one cell per character
+  /// 
+  /// # Returns
+  /// Path the frame was saved to, or a description of why it couldn't be saved
+  pub fn save_text (&mut self, text: &str) -> Result<String, String> {
+    let image = Image::from_text(text, &self.palette).with_scale(self.scale);
+    self.save(&image)
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Reads a stream of bits, least significant bit first
+  struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize
+  }
+  impl BitReader<'_> {
+    fn bit (&mut self) -> u32 {
+      let bit = (self.bytes[self.position / 8] >> (self.position % 8)) & 1;
+      self.position += 1;
+      bit as u32
+    }
+    fn read (&mut self, bits: u32) -> u32 {
+      (0..bits).fold(0, |value, i| value | (self.bit() << i))
+    }
+    fn read_code (&mut self, bits: u32) -> u32 {
+      (0..bits).fold(0, |code, _| (code << 1) | self.bit())
+    }
+  }
+
+  /// Decompresses a deflate stream made of a single, final block compressed with fixed Huffman codes
+  fn inflate (bytes: &[u8]) -> Vec<u8> {
+    let mut reader = BitReader { bytes, position: 0 };
+    assert_eq!((reader.read(1), reader.read(2)), (1, 1), "Expected a single, final, fixed Huffman block");
+    let mut data: Vec<u8> = vec![];
+    loop {
+      // Decode literal/length symbol
+      let mut code = reader.read_code(7);
+      let symbol = if code <= 0x17 { 256 + code } else {
+        code = (code << 1) | reader.bit();
+        match code {
+          0x30..=0xbf => code - 0x30,
+          0xc0..=0xc7 => 280 + code - 0xc0,
+          _ => 144 + ((code << 1) | reader.bit()) - 0x190
+        }
+      };
+      match symbol {
+        0..=255 => data.push(symbol as u8),
+        256 => return data,
+        _ => {
+          let index = (symbol - 257) as usize;
+          let length = DEFLATE_LENGTH_BASE[index] + reader.read(DEFLATE_LENGTH_EXTRA[index]) as usize;
+          let index = reader.read_code(5) as usize;
+          let distance = DEFLATE_DISTANCE_BASE[index] + reader.read(DEFLATE_DISTANCE_EXTRA[index]) as usize;
+          for _ in 0..length {
+            data.push(data[data.len() - distance]);
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn calculates_checksums_of_known_vectors () {
+    assert_eq!(Image::crc32(&[b"123456789"]), 0xcbf43926);
+    assert_eq!(Image::crc32(&[b"1234", b"56789"]), 0xcbf43926);
+    let compressed = Image::zlib(b"Wikipedia", &[]);
+    assert_eq!(compressed[(compressed.len() - 4)..], 0x11e60398u32.to_be_bytes());
+  }
+
+  #[test]
+  fn round_trips_data_through_deflate () {
+    let data: Vec<u8> = (0..20000).map(|i: usize| if i.is_multiple_of(7) { (i % 251) as u8 } else { b"abcabcabd"[i % 9] }).collect();
+    let compressed = Image::zlib(&data, &[3, 300]);
+    assert_eq!((compressed[0] as u32 * 256 + compressed[1] as u32) % 31, 0);
+    assert_eq!(inflate(&compressed[2..(compressed.len() - 4)]), data);
+    let data: Vec<u8> = b"abcd".repeat(5000);
+    let compressed = Image::zlib(&data, &[]);
+    assert_eq!(inflate(&compressed[2..(compressed.len() - 4)]), data);
+    assert!(compressed.len() < data.len() / 50);
+  }
+
+  #[test]
+  fn encodes_png () {
+    let palette = Palette::default().with('o', Rgb(1, 2, 3));
+    let image = Image::from_text("#.o\no#\n", &palette).with_scale(2);
+    let png = image.to_png();
+    assert_eq!(png[..8], [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]);
+    // Parse and check chunks
+    let mut chunks: Vec<(&[u8], &[u8])> = vec![];
+    let mut i = 8;
+    while i < png.len() {
+      let length = u32::from_be_bytes([png[i], png[i + 1], png[i + 2], png[i + 3]]) as usize;
+      let (kind, data) = (&png[(i + 4)..(i + 8)], &png[(i + 8)..(i + 8 + length)]);
+      assert_eq!(png[(i + 8 + length)..(i + 12 + length)], Image::crc32(&[kind, data]).to_be_bytes());
+      chunks.push((kind, data));
+      i += 12 + length;
+    }
+    assert_eq!(chunks.iter().map(|(kind, _)| *kind).collect::<Vec<&[u8]>>(), vec![b"IHDR" as &[u8], b"IDAT", b"IEND"]);
+    assert_eq!(chunks[0].1, [0, 0, 0, 6, 0, 0, 0, 4, 8, 2, 0, 0, 0]);
+    // Decompress and check scanlines
+    let idat = chunks[1].1;
+    let scanlines = inflate(&idat[2..(idat.len() - 4)]);
+    assert_eq!(scanlines.len(), 4 * (6 * 3 + 1));
+    let (f, b, o) = (palette.foreground, palette.background, Rgb(1, 2, 3));
+    let rows = [[f, f, b, b, o, o], [f, f, b, b, o, o], [o, o, f, f, b, b], [o, o, f, f, b, b]];
+    let expected: Vec<u8> = rows.iter().flat_map(|row| std::iter::once(0).chain(row.iter().flat_map(|color| [color.0, color.1, color.2]))).collect();
+    assert_eq!(scanlines, expected);
+    let compressed = Image::zlib(&expected, &[]);
+    assert_eq!(idat[(idat.len() - 4)..], compressed[(compressed.len() - 4)..]);
+  }
+
+}
@@ -3,8 +3,16 @@
 //! Provides functionality for reading and processing input data
 // -----------------------------------------------------------------------------
 
+// Load (sub)modules
+pub mod parser;
+
 // Include dependencies
+pub use self::parser::*;
 use std::fs;
+use crate::lib::error::*;
+
+/// Infix marking an expected answer file, following the input data file's name
+const ANSWER_INFIX: &str = ".answer-";
 
 /// Input struct
 pub struct Input {}
@@ -19,10 +27,60 @@ impl Input {
   /// * path: Path to file which to read
   /// 
   /// # Returns
-  /// File content as a string
-  pub fn read_file(path: &String) -> String {
-    return fs::read_to_string(path)
-      .expect(format!("Failed reading from input file: \"{}\"!", path).as_str());
+  /// File content as a string, or an error if file couldn't be read
+  pub fn read_file(path: &String) -> Result<String, CliError> {
+    fs::read_to_string(path)
+      .map_err(|err| CliError::UnreadableInput(path.clone(), err.to_string()))
+  }
+
+  /// Composes path of the expected answer file for an input data file and a puzzle part
+  /// 
+  /// Answers are stored next to the input data file, one file per part, e.g.
+  /// `input-test.txt` is answered by `input-test.answer-01.txt` and `input-test.answer-02.txt`
+  /// 
+  /// # Arguments
+  /// * path:   Path to the input data file
+  /// * index:  Puzzle part index
+  pub fn answer_path (path: &str, index: u32) -> String {
+    let stem = path.strip_suffix(".txt").unwrap_or(path);
+    format!("{}{}{:0>2}.txt", stem, ANSWER_INFIX, index)
+  }
+
+  /// Reads expected answer for an input data file and a puzzle part
+  /// 
+  /// # Arguments
+  /// * path:   Path to the input data file
+  /// * index:  Puzzle part index
+  /// 
+  /// # Returns
+  /// Expected answer, or `None` if no answer is known
+  pub fn read_answer (path: &str, index: u32) -> Option<String> {
+    fs::read_to_string(Input::answer_path(path, index)).ok()
+      .map(|answer| answer.trim().to_string())
+      .filter(|answer| !answer.is_empty())
+  }
+
+  /// Lists all input data files in a directory (skipping expected answer files)
+  /// 
+  /// # Arguments
+  /// * dir: Path to the directory
+  /// 
+  /// # Returns
+  /// Sorted paths of all input data files (none if directory couldn't be read)
+  pub fn list_inputs (dir: &str) -> Vec<String> {
+    let mut paths: Vec<String> = match fs::read_dir(dir) {
+      Ok(entries) => entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| path.file_name().and_then(|name| name.to_str()).map(|name| name.to_string()))
+        .filter(|name| name.ends_with(".txt") && !name.contains(ANSWER_INFIX))
+        .map(|name| format!("{}/{}", dir.trim_end_matches('/'), name))
+        .collect(),
+      Err(_) => vec![]
+    };
+    paths.sort();
+    paths
   }
 
   /// Parses a string of values
@@ -0,0 +1,413 @@
+//! Input parser module
+//! 
+//! Provides typed, zero-copy parsing of common puzzle input shapes: lines, blank line
+//! separated sections, character grids, "key: value" lines, regex captures and integers
+//! embedded in free text. All errors point to the line and column of the original input.
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use regex::Regex;
+use crate::lib::error::*;
+
+/// FromInput trait
+/// 
+/// Types which can be parsed from a (trimmed) slice of input data
+pub trait FromInput<'a>: Sized {
+  /// Parses a value from a slice of input data
+  /// 
+  /// # Arguments
+  /// * text: Slice of input data to parse
+  /// 
+  /// # Returns
+  /// Parsed value, or a description of why parsing failed
+  fn from_input (text: &'a str) -> Result<Self, String>;
+}
+/// Implements FromInput trait for types implementing FromStr
+macro_rules! impl_from_input {
+  ($($t:ty),*) => {
+    $(
+      impl<'a> FromInput<'a> for $t {
+        fn from_input (text: &'a str) -> Result<Self, String> {
+          text.parse::<$t>().map_err(|err| format!("Failed parsing \"{}\" as {}: {}", text, stringify!($t), err))
+        }
+      }
+    )*
+  };
+}
+impl_from_input!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, String);
+impl<'a> FromInput<'a> for &'a str {
+  fn from_input (text: &'a str) -> Result<Self, String> {
+    Ok(text)
+  }
+}
+impl<'a> FromInput<'a> for char {
+  fn from_input (text: &'a str) -> Result<Self, String> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+      (Some(c), None) => Ok(c),
+      _ => Err(format!("Failed parsing \"{}\" as a single character", text))
+    }
+  }
+}
+
+/// FromCaptures trait
+/// 
+/// Types which can be composed from regular expression capture groups
+pub trait FromCaptures<'a>: Sized {
+  /// Composes a value from regular expression capture groups
+  /// 
+  /// # Arguments
+  /// * captures: Captured groups to compose the value from
+  fn from_captures (captures: &Captures<'a>) -> Result<Self, ParseError>;
+}
+/// Implements FromCaptures trait for tuples, composed from consecutive capture groups
+macro_rules! impl_from_captures {
+  ($(($($t:ident: $i:expr),*)),*) => {
+    $(
+      impl<'a, $($t: FromInput<'a>),*> FromCaptures<'a> for ($($t,)*) {
+        fn from_captures (captures: &Captures<'a>) -> Result<Self, ParseError> {
+          Ok(($(captures.get::<$t>($i)?,)*))
+        }
+      }
+    )*
+  };
+}
+impl_from_captures!(
+  (A: 1),
+  (A: 1, B: 2),
+  (A: 1, B: 2, C: 3),
+  (A: 1, B: 2, C: 3, D: 4),
+  (A: 1, B: 2, C: 3, D: 4, E: 5),
+  (A: 1, B: 2, C: 3, D: 4, E: 5, F: 6)
+);
+
+/// Parser struct
+/// 
+/// Points to a slice of input data, while remembering the full input data so that
+/// any error can be reported at its line and column of the full input data
+#[derive(Clone, Copy, Debug)]
+pub struct Parser<'a> {
+  source: &'a str,
+  text: &'a str
+}
+/// Parser implementation
+/// 
+/// Provides typed, zero-copy parsing of common puzzle input shapes
+impl<'a> Parser<'a> {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * data: Full input data to parse
+  pub fn new (data: &'a str) -> Parser<'a> {
+    Parser {
+      source: data,
+      text: data
+    }
+  }
+
+  /// Gets the slice of input data being parsed
+  pub fn text (&self) -> &'a str {
+    self.text
+  }
+
+  /// Gets line and column (both starting at 1) of the start of the slice of input data being parsed
+  pub fn position (&self) -> (usize, usize) {
+    Parser::position_of(self.source, self.text)
+  }
+
+  /// Composes an error pointing to the start of the slice of input data being parsed
+  /// 
+  /// # Arguments
+  /// * message: Error message
+  pub fn error (&self, message: String) -> ParseError {
+    let (line, column) = self.position();
+    ParseError { line, column, message }
+  }
+
+  /// Gets a parser for a trimmed slice of input data being parsed
+  pub fn trim (&self) -> Parser<'a> {
+    self.slice(self.text.trim())
+  }
+
+  /// Gets a parser for the slice of input data being parsed, without trailing whitespace
+  pub fn trim_end (&self) -> Parser<'a> {
+    self.slice(self.text.trim_end())
+  }
+
+  /// Parses the (trimmed) slice of input data being parsed as a value
+  /// 
+  /// # Returns
+  /// Parsed value, or an error pointing to the slice of input data
+  pub fn parse<T: FromInput<'a>> (&self) -> Result<T, ParseError> {
+    let trimmed = self.trim();
+    T::from_input(trimmed.text).map_err(|message| trimmed.error(message))
+  }
+
+  /// Splits the slice of input data being parsed on a separator
+  /// 
+  /// # Arguments
+  /// * separator: Separator to split on
+  pub fn split (&self, separator: &str) -> Vec<Parser<'a>> {
+    self.text.split(separator).map(|text| self.slice(text)).collect()
+  }
+
+  /// Splits the slice of input data being parsed on any whitespace
+  pub fn words (&self) -> Vec<Parser<'a>> {
+    self.text.split_whitespace().map(|text| self.slice(text)).collect()
+  }
+
+  /// Splits the slice of input data being parsed into lines (without line endings)
+  pub fn lines (&self) -> Vec<Parser<'a>> {
+    self.text.lines().map(|text| self.slice(text)).collect()
+  }
+
+  /// Parses each (non empty) line of the slice of input data being parsed
+  /// 
+  /// # Arguments
+  /// * f: Callback parsing a single line
+  /// 
+  /// # Returns
+  /// Values parsed from all lines, or the first error encountered
+  pub fn parse_lines<T, F: Fn(Parser<'a>) -> Result<T, ParseError>> (&self, f: F) -> Result<Vec<T>, ParseError> {
+    self.lines().into_iter().filter(|line| !line.text.trim().is_empty()).map(f).collect()
+  }
+
+  /// Splits the slice of input data being parsed into sections separated by blank lines
+  pub fn sections (&self) -> Vec<Parser<'a>> {
+    let mut sections: Vec<Parser<'a>> = vec![];
+    let mut section: Option<(&'a str, &'a str)> = None;
+    for line in self.text.lines() {
+      // Close current section on a blank line
+      if line.trim().is_empty() {
+        if let Some((first, last)) = section.take() {
+          sections.push(self.span(first, last));
+        }
+      }
+      // Extend current section with a non blank line
+      else {
+        section = Some(match section {
+          Some((first, _)) => (first, line),
+          None => (line, line)
+        });
+      }
+    }
+    if let Some((first, last)) = section {
+      sections.push(self.span(first, last));
+    }
+    sections
+  }
+
+  /// Parses the slice of input data being parsed as a grid of characters (one row per line)
+  /// 
+  /// # Arguments
+  /// * f: Callback converting a character into a grid value, or returning `None` if character isn't allowed
+  /// 
+  /// # Returns
+  /// Rows of grid values, or an error pointing to the first character which isn't allowed
+  pub fn grid<T, F: Fn(char) -> Option<T>> (&self, f: F) -> Result<Vec<Vec<T>>, ParseError> {
+    self.lines().iter()
+      .map(|line| {
+        line.text.char_indices()
+          .map(|(i, c)| f(c).ok_or_else(|| self.slice(&line.text[i..]).error(format!("Unexpected character '{}'", c))))
+          .collect()
+      })
+      .collect()
+  }
+
+  /// Splits a "key: value" line of input data being parsed into (trimmed) key and value
+  /// 
+  /// # Arguments
+  /// * separator: Separator between the key and the value, e.g. ":" or "->"
+  pub fn key_value (&self, separator: &str) -> Result<(Parser<'a>, Parser<'a>), ParseError> {
+    match self.text.split_once(separator) {
+      Some((key, value)) => Ok((self.slice(key).trim(), self.slice(value).trim())),
+      None => Err(self.trim().error(format!("Expected \"key{}value\"", separator)))
+    }
+  }
+
+  /// Splits each (non empty) "key: value" line of input data being parsed into (trimmed) key and value
+  /// 
+  /// # Arguments
+  /// * separator: Separator between the key and the value, e.g. ":" or "->"
+  pub fn key_values (&self, separator: &str) -> Result<Vec<(Parser<'a>, Parser<'a>)>, ParseError> {
+    self.parse_lines(|line| line.key_value(separator))
+  }
+
+  /// Matches the slice of input data being parsed against a regular expression
+  /// 
+  /// # Arguments
+  /// * regex: Regular expression to match
+  /// 
+  /// # Returns
+  /// Captured groups, or an error if input data didn't match
+  pub fn captures (&self, regex: &Regex) -> Result<Captures<'a>, ParseError> {
+    match regex.captures(self.text) {
+      Some(captures) => Ok(Captures { parser: *self, captures }),
+      None => Err(self.trim().error(format!("Expected input matching /{}/", regex.as_str())))
+    }
+  }
+
+  /// Matches the slice of input data being parsed against a regular expression and
+  /// composes a value (e.g. a tuple) from the captured groups
+  /// 
+  /// # Arguments
+  /// * regex: Regular expression to match
+  pub fn capture<T: FromCaptures<'a>> (&self, regex: &Regex) -> Result<T, ParseError> {
+    T::from_captures(&self.captures(regex)?)
+  }
+
+  /// Extracts all integers from the slice of input data being parsed, ignoring any other text
+  /// 
+  /// A `-` is only considered a sign if not directly following a digit, so that ranges
+  /// like `2-4` are extracted as `2` and `4`
+  pub fn integers<T: FromInput<'a>> (&self) -> Result<Vec<T>, ParseError> {
+    let bytes = self.text.as_bytes();
+    let mut integers: Vec<T> = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+      // Find start of next integer (including sign)
+      let signed = bytes[i] == b'-' && i + 1 < bytes.len() && bytes[i + 1].is_ascii_digit() && (i == 0 || !bytes[i - 1].is_ascii_digit());
+      if !signed && !bytes[i].is_ascii_digit() {
+        i += 1;
+        continue;
+      }
+      // Find end of the integer and parse it
+      let start = i;
+      i += 1;
+      while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+      }
+      integers.push(self.slice(&self.text[start..i]).parse()?);
+    }
+    Ok(integers)
+  }
+
+  /// Gets a parser for a subslice of input data being parsed
+  fn slice (&self, text: &'a str) -> Parser<'a> {
+    Parser { source: self.source, text }
+  }
+
+  /// Gets a parser for a subslice spanning from the start of one subslice to the end of another
+  fn span (&self, first: &'a str, last: &'a str) -> Parser<'a> {
+    let start = first.as_ptr() as usize - self.text.as_ptr() as usize;
+    let end = last.as_ptr() as usize - self.text.as_ptr() as usize + last.len();
+    self.slice(&self.text[start..end])
+  }
+
+  /// Gets line and column (both starting at 1) of the start of a subslice of input data
+  fn position_of (source: &str, text: &str) -> (usize, usize) {
+    let offset = (text.as_ptr() as usize).saturating_sub(source.as_ptr() as usize).min(source.len());
+    let preceding = &source[..offset];
+    let line = preceding.matches('\n').count() + 1;
+    let column = preceding.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, column)
+  }
+
+}
+
+/// Captures struct
+/// 
+/// Holds groups captured by matching a regular expression against input data
+pub struct Captures<'a> {
+  parser: Parser<'a>,
+  captures: regex::Captures<'a>
+}
+/// Captures implementation
+/// 
+/// Provides typed access to captured groups
+impl<'a> Captures<'a> {
+
+  /// Parses a group captured by index
+  /// 
+  /// # Arguments
+  /// * index: Index of the capture group (starting at 1)
+  pub fn get<T: FromInput<'a>> (&self, index: usize) -> Result<T, ParseError> {
+    match self.captures.get(index) {
+      Some(group) => self.parser.slice(group.as_str()).parse(),
+      None => Err(self.parser.trim().error(format!("Missing capture group #{}", index)))
+    }
+  }
+
+  /// Parses a group captured by name
+  /// 
+  /// # Arguments
+  /// * name: Name of the capture group
+  pub fn name<T: FromInput<'a>> (&self, name: &str) -> Result<T, ParseError> {
+    match self.captures.name(name) {
+      Some(group) => self.parser.slice(group.as_str()).parse(),
+      None => Err(self.parser.trim().error(format!("Missing capture group \"{}\"", name)))
+    }
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reports_line_and_column_of_errors () {
+    let parser = Parser::new("1 2\n3 x\n");
+    let words: Vec<Parser> = parser.lines().iter().flat_map(|line| line.words()).collect();
+    assert_eq!(words.iter().map(|word| word.position()).collect::<Vec<(usize, usize)>>(), vec![(1, 1), (1, 3), (2, 1), (2, 3)]);
+    let error = words[3].parse::<u32>().err().unwrap();
+    assert_eq!((error.line, error.column), (2, 3));
+    // Columns count characters, not bytes
+    let error = Parser::new("é: x").key_value(":").unwrap().1.parse::<u32>().err().unwrap();
+    assert_eq!((error.line, error.column), (1, 4));
+  }
+
+  #[test]
+  fn splits_sections_keeping_positions () {
+    let parser = Parser::new("\na\nb\n\n\n  c\nd 7\n\n");
+    let sections = parser.sections();
+    assert_eq!(sections.iter().map(|section| section.text()).collect::<Vec<&str>>(), vec!["a\nb", "  c\nd 7"]);
+    assert_eq!(sections[1].position(), (6, 1));
+    let lines = sections[1].lines();
+    assert_eq!(lines[0].trim().position(), (6, 3));
+    let error = lines[1].split(" ")[1].parse::<bool>().err().unwrap();
+    assert_eq!((error.line, error.column), (7, 3));
+  }
+
+  #[test]
+  fn extracts_signed_integers_but_not_ranges () {
+    let parser = Parser::new("x=-3, y=12 (2-4) -7-8 --5");
+    assert_eq!(parser.integers::<isize>().unwrap(), vec![-3, 12, 2, 4, -7, 8, -5]);
+    assert_eq!(Parser::new("none here").integers::<isize>().unwrap(), vec![]);
+    // Unsigned integers reject negative numbers, pointing at them
+    let error = parser.integers::<usize>().err().unwrap();
+    assert_eq!((error.line, error.column), (1, 3));
+  }
+
+  #[test]
+  fn splits_key_values () {
+    let parser = Parser::new("a: 1\nbb :2\n\n");
+    let pairs = parser.key_values(":").unwrap();
+    assert_eq!(pairs.iter().map(|(key, value)| (key.text(), value.parse::<u32>().unwrap())).collect::<Vec<(&str, u32)>>(), vec![("a", 1), ("bb", 2)]);
+    assert_eq!(pairs[1].1.position(), (2, 5));
+    // Missing separator is reported at the (trimmed) line
+    let error = Parser::new("a: 1\n  b = 2").key_values(":").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 3));
+  }
+
+  #[test]
+  fn composes_tuples_from_captures () {
+    let regex = Regex::new(r"^move (\d+) from (\w) to (\w)$").unwrap();
+    let parser = Parser::new("move 12 from a to b\nmove x from a to b");
+    let lines = parser.lines();
+    let (count, from, to): (usize, char, &str) = lines[0].capture(&regex).unwrap();
+    assert_eq!((count, from, to), (12, 'a', "b"));
+    let captures = lines[0].captures(&Regex::new(r"(?P<count>\d+)").unwrap()).unwrap();
+    assert_eq!(captures.name::<usize>("count").unwrap(), 12);
+    assert!(captures.name::<usize>("missing").is_err());
+    // Input not matching the regular expression is reported at the line
+    let error = lines[1].capture::<(usize, char, char)>(&regex).err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+    // Captured group failing to parse is reported at the group
+    let regex = Regex::new(r"^move (\d+) from (\w+) to (\w+)$").unwrap();
+    let error = Parser::new("move 1 from ab to c").capture::<(usize, char, char)>(&regex).err().unwrap();
+    assert_eq!((error.line, error.column), (1, 13));
+  }
+
+}
@@ -0,0 +1,293 @@
+//! JSON module
+//! 
+//! Provides a minimal JSON parser (with support for `//` and `/* */` comments)
+// -----------------------------------------------------------------------------
+
+/// Enumerates JSON values
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<Json>),
+  Object(Vec<(String, Json)>)
+}
+/// Json implementation
+/// 
+/// Provides parsing of JSON text and access to parsed values
+impl Json {
+
+  /// Parses JSON text
+  /// 
+  /// # Arguments
+  /// * text: JSON text to parse (may contain `//` and `/* */` comments)
+  /// 
+  /// # Returns
+  /// Parsed JSON value, or a description of where and why parsing failed
+  pub fn parse (text: &str) -> Result<Json, String> {
+    let mut parser = JsonParser { chars: text.chars().collect(), position: 0 };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace()?;
+    if parser.position < parser.chars.len() {
+      return Err(parser.error("Unexpected trailing content"));
+    }
+    Ok(value)
+  }
+
+  /// Serializes JSON value as (compact) JSON text
+  pub fn stringify (&self) -> String {
+    match self {
+      Json::Null => String::from("null"),
+      Json::Bool(value) => value.to_string(),
+      Json::Number(value) => if value.is_finite() { value.to_string() } else { String::from("null") },
+      Json::String(value) => Json::quote(value),
+      Json::Array(items) => format!("[{}]", items.iter().map(|item| item.stringify()).collect::<Vec<String>>().join(",")),
+      Json::Object(properties) => format!("{{{}}}", properties.iter().map(|(key, value)| format!("{}:{}", Json::quote(key), value.stringify())).collect::<Vec<String>>().join(","))
+    }
+  }
+
+  /// Encodes a string as a quoted and escaped JSON string
+  /// 
+  /// # Arguments
+  /// * value: String to encode
+  pub fn quote (value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len() + 2);
+    encoded.push('"');
+    for c in value.chars() {
+      match c {
+        '"' => encoded.push_str("\\\""),
+        '\\' => encoded.push_str("\\\\"),
+        '\n' => encoded.push_str("\\n"),
+        '\r' => encoded.push_str("\\r"),
+        '\t' => encoded.push_str("\\t"),
+        c if (c as u32) < 0x20 => encoded.push_str(format!("\\u{:04x}", c as u32).as_str()),
+        c => encoded.push(c)
+      }
+    }
+    encoded.push('"');
+    encoded
+  }
+
+  /// Gets a property of a JSON object
+  /// 
+  /// # Arguments
+  /// * key: Name of the property
+  pub fn get (&self, key: &str) -> Option<&Json> {
+    match self {
+      Json::Object(properties) => properties.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+      _ => None
+    }
+  }
+
+  /// Gets array items (if JSON value is an array)
+  pub fn as_array (&self) -> Option<&Vec<Json>> {
+    match self {
+      Json::Array(items) => Some(items),
+      _ => None
+    }
+  }
+
+  /// Gets numeric value (if JSON value is a number)
+  pub fn as_number (&self) -> Option<f64> {
+    match self {
+      Json::Number(value) => Some(*value),
+      _ => None
+    }
+  }
+
+  /// Gets a textual representation of a scalar value (strings are not quoted)
+  pub fn to_text (&self) -> Option<String> {
+    match self {
+      Json::Null => None,
+      Json::Bool(value) => Some(value.to_string()),
+      Json::Number(value) => Some(if value.fract() == 0.0 && value.abs() < 1e15 { format!("{}", *value as i64) } else { value.to_string() }),
+      Json::String(value) => Some(value.clone()),
+      _ => None
+    }
+  }
+
+}
+
+/// JSON parser struct
+struct JsonParser {
+  chars: Vec<char>,
+  position: usize
+}
+/// JSON parser implementation
+impl JsonParser {
+
+  /// Composes an error message pointing at current line and column
+  fn error (&self, message: &str) -> String {
+    let consumed = &self.chars[0..self.position.min(self.chars.len())];
+    let line = consumed.iter().filter(|c| **c == '\n').count() + 1;
+    let column = consumed.iter().rev().take_while(|c| **c != '\n').count() + 1;
+    format!("{} at line {}, column {}", message, line, column)
+  }
+
+  /// Gets current character without consuming it
+  fn peek (&self) -> Option<char> {
+    self.chars.get(self.position).copied()
+  }
+
+  /// Skips whitespace and comments
+  fn skip_whitespace (&mut self) -> Result<(), String> {
+    while let Some(c) = self.peek() {
+      if c.is_whitespace() {
+        self.position += 1;
+      } else if c == '/' && self.chars.get(self.position + 1) == Some(&'/') {
+        while let Some(c) = self.peek() {
+          if c == '\n' { break; }
+          self.position += 1;
+        }
+      } else if c == '/' && self.chars.get(self.position + 1) == Some(&'*') {
+        self.position += 2;
+        loop {
+          match self.peek() {
+            None => return Err(self.error("Unterminated comment")),
+            Some('*') if self.chars.get(self.position + 1) == Some(&'/') => { self.position += 2; break; },
+            _ => self.position += 1
+          }
+        }
+      } else {
+        break;
+      }
+    }
+    Ok(())
+  }
+
+  /// Consumes an expected character
+  fn expect (&mut self, expected: char) -> Result<(), String> {
+    self.skip_whitespace()?;
+    if self.peek() == Some(expected) {
+      self.position += 1;
+      Ok(())
+    } else {
+      Err(self.error(format!("Expected '{}'", expected).as_str()))
+    }
+  }
+
+  /// Consumes an expected keyword
+  fn keyword (&mut self, keyword: &str, value: Json) -> Result<Json, String> {
+    for expected in keyword.chars() {
+      if self.peek() != Some(expected) {
+        return Err(self.error(format!("Expected '{}'", keyword).as_str()));
+      }
+      self.position += 1;
+    }
+    Ok(value)
+  }
+
+  /// Parses any JSON value
+  fn parse_value (&mut self) -> Result<Json, String> {
+    self.skip_whitespace()?;
+    match self.peek() {
+      Some('{') => self.parse_object(),
+      Some('[') => self.parse_array(),
+      Some('"') => Ok(Json::String(self.parse_string()?)),
+      Some('t') => self.keyword("true", Json::Bool(true)),
+      Some('f') => self.keyword("false", Json::Bool(false)),
+      Some('n') => self.keyword("null", Json::Null),
+      Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+      Some(_) => Err(self.error("Unexpected character")),
+      None => Err(self.error("Unexpected end of input"))
+    }
+  }
+
+  /// Parses a JSON object
+  fn parse_object (&mut self) -> Result<Json, String> {
+    self.expect('{')?;
+    let mut properties: Vec<(String, Json)> = vec![];
+    self.skip_whitespace()?;
+    if self.peek() == Some('}') {
+      self.position += 1;
+      return Ok(Json::Object(properties));
+    }
+    loop {
+      self.skip_whitespace()?;
+      let key = self.parse_string()?;
+      self.expect(':')?;
+      let value = self.parse_value()?;
+      properties.push((key, value));
+      self.skip_whitespace()?;
+      match self.peek() {
+        Some(',') => self.position += 1,
+        Some('}') => { self.position += 1; return Ok(Json::Object(properties)); },
+        _ => return Err(self.error("Expected ',' or '}'"))
+      }
+    }
+  }
+
+  /// Parses a JSON array
+  fn parse_array (&mut self) -> Result<Json, String> {
+    self.expect('[')?;
+    let mut items: Vec<Json> = vec![];
+    self.skip_whitespace()?;
+    if self.peek() == Some(']') {
+      self.position += 1;
+      return Ok(Json::Array(items));
+    }
+    loop {
+      items.push(self.parse_value()?);
+      self.skip_whitespace()?;
+      match self.peek() {
+        Some(',') => self.position += 1,
+        Some(']') => { self.position += 1; return Ok(Json::Array(items)); },
+        _ => return Err(self.error("Expected ',' or ']'"))
+      }
+    }
+  }
+
+  /// Parses a JSON string
+  fn parse_string (&mut self) -> Result<String, String> {
+    if self.peek() != Some('"') {
+      return Err(self.error("Expected '\"'"));
+    }
+    self.position += 1;
+    let mut value = String::default();
+    loop {
+      match self.peek() {
+        None => return Err(self.error("Unterminated string")),
+        Some('"') => { self.position += 1; return Ok(value); },
+        Some('\\') => {
+          self.position += 1;
+          let escaped = match self.peek() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+              let hex: String = self.chars.iter().skip(self.position + 1).take(4).collect();
+              let code = u32::from_str_radix(hex.as_str(), 16).map_err(|_| self.error("Invalid unicode escape"))?;
+              self.position += 4;
+              char::from_u32(code).unwrap_or('\u{fffd}')
+            },
+            _ => return Err(self.error("Invalid escape sequence"))
+          };
+          value.push(escaped);
+          self.position += 1;
+        },
+        Some(c) => { value.push(c); self.position += 1; }
+      }
+    }
+  }
+
+  /// Parses a JSON number
+  fn parse_number (&mut self) -> Result<Json, String> {
+    let start = self.position;
+    while let Some(c) = self.peek() {
+      if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+        self.position += 1;
+      } else {
+        break;
+      }
+    }
+    let text: String = self.chars[start..self.position].iter().collect();
+    text.parse::<f64>().map(Json::Number).map_err(|_| { self.position = start; self.error("Invalid number") })
+  }
+
+}
@@ -0,0 +1,144 @@
+//! Manifest module
+//! 
+//! Loads task manifests (`aoc.json`) describing puzzle runs and their expected results
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::lib::json::*;
+
+/// ManifestTask struct
+/// 
+/// Describes a single task from a manifest: startup arguments of a puzzle run
+/// and the value the run is expected to produce
+pub struct ManifestTask {
+  pub name: String,
+  pub task_type: String,
+  pub args: Vec<String>,
+  pub input: Option<String>,
+  pub value: Option<String>
+}
+/// ManifestTask implementation
+impl ManifestTask {
+
+  /// Gets task type without the `SLOW:` marker
+  pub fn kind (&self) -> &str {
+    self.task_type.trim_start_matches("SLOW:")
+  }
+
+  /// Expands task's startup arguments
+  /// 
+  /// Only arguments following the `--` separator are used (if present) and
+  /// templates are expanded:
+  /// * `{{:value}}`            - Replaced with task's expected value
+  /// * `{{:input}}`            - Replaced with task's input
+  /// * `{{verbose??--verbose}}` - Replaced with `--verbose` if verbose, else removed
+  /// 
+  /// # Arguments
+  /// * verbose: If verbose output was requested
+  /// 
+  /// # Returns
+  /// Expanded startup arguments
+  pub fn expand_args (&self, verbose: bool) -> Vec<String> {
+    let args: Vec<&String> = match self.args.iter().position(|arg| arg == "--") {
+      Some(separator) => self.args.iter().skip(separator + 1).collect(),
+      None => self.args.iter().collect()
+    };
+    args.iter()
+      .filter_map(|arg| {
+        // Expand conditional template
+        if arg.starts_with("{{") && arg.ends_with("}}") && arg.contains("??") {
+          let template = &arg[2..arg.len() - 2];
+          let (condition, value) = template.split_once("??").unwrap();
+          return if condition == "verbose" && verbose { Some(value.to_string()) } else { None };
+        }
+        // Expand value templates
+        Some(arg
+          .replace("{{:value}}", self.value.as_deref().unwrap_or_default())
+          .replace("{{:input}}", self.input.as_deref().unwrap_or_default()))
+      })
+      .collect()
+  }
+
+}
+
+/// Manifest struct
+/// 
+/// Holds all tasks loaded from a manifest file
+pub struct Manifest {
+  pub path: PathBuf,
+  pub tasks: Vec<ManifestTask>
+}
+/// Manifest implementation
+impl Manifest {
+
+  /// Loads a manifest file
+  /// 
+  /// Tasks defining multiple `runs` are expanded into a task per run
+  /// 
+  /// # Arguments
+  /// * path: Path to the manifest file
+  /// 
+  /// # Returns
+  /// Loaded manifest, or a description of why loading failed
+  pub fn load (path: &str) -> Result<Manifest, String> {
+    let text = fs::read_to_string(path).map_err(|err| format!("Failed reading manifest file \"{}\": {}", path, err))?;
+    let json = Json::parse(text.as_str()).map_err(|err| format!("Failed parsing manifest file \"{}\": {}", path, err))?;
+    let tasks_json = json.get("tasks").and_then(|tasks| tasks.as_array()).ok_or(format!("Manifest file \"{}\" has no \"tasks\" array", path))?;
+    let mut tasks: Vec<ManifestTask> = vec![];
+    for task in tasks_json {
+      let name = task.get("name").and_then(|x| x.to_text()).unwrap_or_default();
+      let task_type = task.get("type").and_then(|x| x.to_text()).unwrap_or_default();
+      let args: Vec<String> = task.get("args").and_then(|x| x.as_array())
+        .ok_or(format!("Manifest task \"{}\" has no \"args\" array", name))?
+        .iter()
+        .map(|arg| arg.to_text().unwrap_or_default())
+        .collect();
+      match task.get("runs").and_then(|x| x.as_array()) {
+        // Expand a task per run
+        Some(runs) => {
+          for (i, run) in runs.iter().enumerate() {
+            tasks.push(ManifestTask {
+              name: format!("{}#{}", name, i + 1),
+              task_type: task_type.clone(),
+              args: args.clone(),
+              input: run.get("input").and_then(|x| x.to_text()),
+              value: run.get("value").and_then(|x| x.to_text())
+            });
+          }
+        },
+        // Single run task
+        None => {
+          tasks.push(ManifestTask {
+            name,
+            task_type,
+            args,
+            input: task.get("input").and_then(|x| x.to_text()),
+            value: task.get("value").and_then(|x| x.to_text())
+          });
+        }
+      }
+    }
+    Ok(Manifest {
+      path: PathBuf::from(path),
+      tasks
+    })
+  }
+
+  /// Resolves a path relative to the manifest file's directory
+  /// 
+  /// # Arguments
+  /// * path: Path to resolve (absolute paths are returned as they are)
+  pub fn resolve_path (&self, path: &str) -> String {
+    let relative = Path::new(path);
+    if path.is_empty() || relative.is_absolute() {
+      return path.to_string();
+    }
+    match self.path.parent() {
+      Some(dir) if !dir.as_os_str().is_empty() => dir.join(relative).to_string_lossy().to_string(),
+      _ => path.to_string()
+    }
+  }
+
+}
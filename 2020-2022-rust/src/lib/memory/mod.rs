@@ -0,0 +1,77 @@
+//! Memory module
+//! 
+//! Provides a global allocator enforcing an (optional) memory budget
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Global allocator instance
+#[global_allocator]
+pub static ALLOCATOR: BudgetAllocator = BudgetAllocator::new();
+
+/// BudgetAllocator struct
+/// 
+/// Wraps the system allocator, tracking allocated memory and refusing any
+/// allocation which would exceed the memory budget (if one was set). A refused
+/// allocation makes the process abort with a "memory allocation of N bytes failed"
+/// message, which is how a supervisor recognizes a puzzle having run out of memory.
+pub struct BudgetAllocator {
+  allocated: AtomicUsize,
+  limit: AtomicUsize
+}
+/// BudgetAllocator implementation
+impl BudgetAllocator {
+
+  /// Constructor
+  pub const fn new () -> BudgetAllocator {
+    BudgetAllocator {
+      allocated: AtomicUsize::new(0),
+      limit: AtomicUsize::new(0)
+    }
+  }
+
+  /// Sets memory budget
+  /// 
+  /// # Arguments
+  /// * bytes: Maximum number of bytes allowed to be allocated at any time (0 for no limit)
+  pub fn set_limit (&self, bytes: usize) {
+    self.limit.store(bytes, Ordering::SeqCst);
+  }
+
+  /// Checks if an additional allocation fits into the memory budget
+  fn fits (&self, bytes: usize) -> bool {
+    let limit = self.limit.load(Ordering::Relaxed);
+    limit == 0 || self.allocated.load(Ordering::Relaxed).saturating_add(bytes) <= limit
+  }
+
+}
+unsafe impl GlobalAlloc for BudgetAllocator {
+  unsafe fn alloc (&self, layout: Layout) -> *mut u8 {
+    if !self.fits(layout.size()) { return ptr::null_mut(); }
+    let allocated = System.alloc(layout);
+    if !allocated.is_null() { self.allocated.fetch_add(layout.size(), Ordering::Relaxed); }
+    allocated
+  }
+  unsafe fn alloc_zeroed (&self, layout: Layout) -> *mut u8 {
+    if !self.fits(layout.size()) { return ptr::null_mut(); }
+    let allocated = System.alloc_zeroed(layout);
+    if !allocated.is_null() { self.allocated.fetch_add(layout.size(), Ordering::Relaxed); }
+    allocated
+  }
+  unsafe fn dealloc (&self, allocated: *mut u8, layout: Layout) {
+    System.dealloc(allocated, layout);
+    self.allocated.fetch_sub(layout.size(), Ordering::Relaxed);
+  }
+  unsafe fn realloc (&self, allocated: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    if new_size > layout.size() && !self.fits(new_size - layout.size()) { return ptr::null_mut(); }
+    let reallocated = System.realloc(allocated, layout, new_size);
+    if !reallocated.is_null() {
+      self.allocated.fetch_add(new_size, Ordering::Relaxed);
+      self.allocated.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+    reallocated
+  }
+}
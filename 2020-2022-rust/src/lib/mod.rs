@@ -2,7 +2,18 @@
 // -----------------------------------------------------------------------------
 
 // Load child modules
+pub mod error;
 pub mod vargs;
 pub mod stdout;
 pub mod input;
 pub mod puzzle;
+pub mod benchmark;
+pub mod report;
+pub mod json;
+pub mod manifest;
+pub mod memory;
+pub mod supervisor;
+pub mod scaffold;
+pub mod context;
+pub mod playback;
+pub mod image;
@@ -0,0 +1,241 @@
+//! Playback module
+//! 
+//! Interactive terminal playback of visualization frames, as they are being emitted by a running puzzle
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+extern crate termion;
+use std::io::{self, Stdout, Write};
+use std::process;
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use termion::{clear, cursor};
+use termion::cursor::HideCursor;
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::screen::AlternateScreen;
+use crate::lib::stdout::*;
+
+/// Initial delay between frames, in milliseconds
+const PLAYBACK_DELAY: u64 = 100;
+/// Shortest and longest delay between frames, in milliseconds
+const PLAYBACK_DELAY_RANGE: (u64, u64) = (1, 6400);
+/// Listing of playback controls
+const PLAYBACK_CONTROLS: &str = "[space] pause, [n] step, [+/-] speed, [arrows/pgup/pgdn] scroll, [home] reset, [q] quit";
+
+/// Playback struct
+/// 
+/// Draws every frame over the entire terminal and holds the puzzle emitting the frames until
+/// it's time for the next frame, so that the puzzle only advances as fast as it's being watched
+pub struct Playback {
+  /// Terminal being played in (opened once the first frame is shown)
+  terminal: Option<HideCursor<AlternateScreen<RawTerminal<Stdout>>>>,
+  /// Frame currently being shown
+  frame: String,
+  /// Number of frames shown so far
+  count: usize,
+  /// Delay between frames, in milliseconds
+  delay: u64,
+  /// If playback is paused (advancing only when stepped)
+  paused: bool,
+  /// If playback has been quit (all further frames are skipped)
+  quit: bool,
+  /// If the puzzle has finished emitting frames
+  finished: bool,
+  /// Column and row of the frame shown in the top left corner of the terminal
+  viewport: (usize, usize)
+}
+/// Playback implementation
+impl Playback {
+
+  /// Constructor
+  pub fn new () -> Playback {
+    Playback {
+      terminal: None,
+      frame: String::default(),
+      count: 0,
+      delay: PLAYBACK_DELAY,
+      paused: false,
+      quit: false,
+      finished: false,
+      viewport: (0, 0)
+    }
+  }
+
+  /// Checks if playback can be played in the current terminal
+  pub fn is_supported () -> bool {
+    termion::is_tty(&io::stdin()) && termion::is_tty(&io::stdout())
+  }
+
+  /// Checks if playback is still playing (not having been quit)
+  pub fn is_playing (&self) -> bool {
+    !self.quit
+  }
+
+  /// Shows a frame and waits until it's time for the next frame
+  /// 
+  /// # Arguments
+  /// * frame: Frame to show
+  pub fn show (&mut self, frame: String) {
+    if self.quit {
+      return;
+    }
+    // Open terminal on first frame
+    if self.terminal.is_none() && !self.open() {
+      self.quit = true;
+      return;
+    }
+    // Show frame
+    self.frame = frame;
+    self.count += 1;
+    self.draw();
+    // Wait for next frame, or for being stepped if paused
+    let deadline = Instant::now() + Duration::from_millis(self.delay);
+    let keys = Playback::keys().lock().unwrap();
+    while !self.quit {
+      let timeout = if self.paused { Duration::from_millis(50) } else { deadline.saturating_duration_since(Instant::now()) };
+      match keys.recv_timeout(timeout) {
+        Ok(key) => {
+          if self.control(key) { break; }
+          self.draw();
+        },
+        Err(mpsc::RecvTimeoutError::Timeout) => if !self.paused { break; },
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+          thread::sleep(timeout);
+          break;
+        }
+      }
+    }
+  }
+
+  /// Keeps showing the last frame until playback is quit, then closes the terminal
+  pub fn finish (&mut self) {
+    if self.terminal.is_some() && !self.quit {
+      self.finished = true;
+      self.paused = true;
+      self.draw();
+      let keys = Playback::keys().lock().unwrap();
+      while !self.quit {
+        match keys.recv() {
+          Ok(key) => {
+            self.control(key);
+            self.draw();
+          },
+          Err(_) => break
+        }
+      }
+    }
+    self.terminal = None;
+  }
+
+  /// Opens the terminal for playback (raw mode, alternate screen, hidden cursor)
+  /// 
+  /// # Returns
+  /// If terminal was opened
+  fn open (&mut self) -> bool {
+    let terminal = match io::stdout().into_raw_mode() {
+      Ok(terminal) => terminal,
+      Err(_) => return false
+    };
+    self.terminal = Some(HideCursor::from(AlternateScreen::from(terminal)));
+    // Discard any keys pressed before playback started
+    while Playback::keys().lock().unwrap().try_recv().is_ok() {}
+    true
+  }
+
+  /// Applies a key press to the playback
+  /// 
+  /// # Arguments
+  /// * key: Pressed key
+  /// 
+  /// # Returns
+  /// If playback should step to the next frame
+  fn control (&mut self, key: Key) -> bool {
+    let (_, height) = Playback::size();
+    match key {
+      Key::Char(' ') => self.paused = !self.paused,
+      Key::Char('n') | Key::Char('.') => {
+        self.paused = true;
+        return !self.finished;
+      },
+      Key::Char('+') | Key::Char('=') => self.delay = (self.delay / 2).max(PLAYBACK_DELAY_RANGE.0),
+      Key::Char('-') => self.delay = (self.delay * 2).min(PLAYBACK_DELAY_RANGE.1),
+      Key::Left => self.viewport.0 = self.viewport.0.saturating_sub(1),
+      Key::Right => self.viewport.0 += 1,
+      Key::Up => self.viewport.1 = self.viewport.1.saturating_sub(1),
+      Key::Down => self.viewport.1 += 1,
+      Key::PageUp => self.viewport.1 = self.viewport.1.saturating_sub(height),
+      Key::PageDown => self.viewport.1 += height,
+      Key::Home => self.viewport = (0, 0),
+      Key::Char('q') | Key::Esc => self.quit = true,
+      // Restore terminal and exit as if interrupted (raw mode doesn't raise an interrupt on its own)
+      Key::Ctrl('c') => {
+        self.terminal = None;
+        process::exit(130);
+      },
+      _ => ()
+    }
+    false
+  }
+
+  /// Draws the viewport of the current frame, followed by a status line
+  fn draw (&mut self) {
+    let (width, height) = Playback::size();
+    // Keep viewport within the frame
+    let lines: Vec<&str> = self.frame.lines().collect();
+    let frame_width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+    self.viewport = (
+      self.viewport.0.min(frame_width.saturating_sub(width)),
+      self.viewport.1.min(lines.len().saturating_sub(height))
+    );
+    // Compose viewport
+    let mut output = format!("{}{}", clear::All, cursor::Goto(1, 1));
+    for line in lines.iter().skip(self.viewport.1).take(height) {
+      output.push_str(&line.chars().skip(self.viewport.0).take(width).collect::<String>());
+      output.push_str("\r\n");
+    }
+    // Compose status line
+    let state = if self.finished { "finished" } else if self.paused { "paused" } else { "playing" };
+    let status = format!(
+      " Frame {} ({}) | {}ms per frame | {}x{} frame at {},{} | {}",
+      self.count, state, self.delay, frame_width, lines.len(), self.viewport.0, self.viewport.1, PLAYBACK_CONTROLS
+    );
+    let coloring = if self.paused { StdOutColoring::UNKNOWN } else { StdOutColoring::VALID };
+    output.push_str(&format!(
+      "{}{}{:width$}{}",
+      cursor::Goto(1, height as u16 + 1),
+      StdOut::style(Some(coloring)),
+      status.chars().take(width).collect::<String>(),
+      StdOut::style(None),
+      width = width
+    ));
+    // Output
+    if let Some(terminal) = self.terminal.as_mut() {
+      write!(terminal, "{}", output).ok();
+      terminal.flush().ok();
+    }
+  }
+
+  /// Gets size of the terminal area available to frames (excluding the status line)
+  fn size () -> (usize, usize) {
+    let (width, height) = termion::terminal_size().unwrap_or((80, 24));
+    (width as usize, (height as usize).saturating_sub(1).max(1))
+  }
+
+  /// Gets key presses, read from STDIN on a background thread shared by all playbacks
+  fn keys () -> &'static Mutex<mpsc::Receiver<Key>> {
+    static KEYS: OnceLock<Mutex<mpsc::Receiver<Key>>> = OnceLock::new();
+    KEYS.get_or_init(|| {
+      let (sender, receiver) = mpsc::channel();
+      thread::spawn(move || {
+        for key in io::stdin().keys().flatten() {
+          if sender.send(key).is_err() { break; }
+        }
+      });
+      Mutex::new(receiver)
+    })
+  }
+
+}
@@ -4,16 +4,28 @@
 // -----------------------------------------------------------------------------
 
 // Include dependencies
-use std::cmp::Eq;
-use std::collections::HashMap;
+use std::cmp::{Eq, Ordering};
+use std::collections::BTreeMap;
+use std::ops::{Range, RangeInclusive};
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::mpsc;
+use std::thread;
 use std::time::Instant;
 use crate::lib::vargs::*;
+use crate::lib::input::*;
+use crate::lib::error::*;
+use crate::lib::supervisor::*;
 use crate::lib::stdout::*;
+use crate::lib::benchmark::*;
+use crate::lib::report::*;
+use crate::lib::context::*;
+use crate::lib::playback::*;
+use crate::lib::image::*;
 
 /// PuzzleInfo struct
 /// 
 /// Describes puzzle by year/day/index/type
-#[derive(Hash)]
 pub struct PuzzleInfo {  
   pub year: u32,
   pub day: u32,
@@ -21,16 +33,30 @@ pub struct PuzzleInfo {
   pub tag: String,
 }
 /// PuzzleInfo implementation
-/// 
-/// Implements PartialEq and Eq traits
 impl PuzzleInfo {
-  pub fn matches(&self, other: &Self) -> bool {
-    (self.year == 0 || other.year == 0 || self.year == other.year)
-    && (self.day == 0 || other.day == 0 || self.day == other.day)
-    && (self.index == 0 || other.index == 0 || self.index == other.index)
-    && (self.tag.is_empty() || other.tag.is_empty() || self.tag == other.tag)
+
+  /// Constructor
+  /// 
+  /// Derives year and day from the path of the module implementing the puzzle (`..::yearYYYY::dayDD`)
+  /// 
+  /// # Arguments
+  /// * module: Path of the module implementing the puzzle, as returned by `module_path!()`
+  /// * index:  Index of the puzzle (part) within the day
+  /// * tag:    Puzzle tag
+  pub fn from_module (module: &str, index: u32, tag: &str) -> PuzzleInfo {
+    let number = |prefix: &str| module.split("::")
+      .find_map(|segment| segment.strip_prefix(prefix).and_then(|n| n.parse::<u32>().ok()))
+      .unwrap_or_else(|| panic!("Module \"{}\" needs to be nested as \"..::yearYYYY::dayDD\"!", module));
+    PuzzleInfo {
+      year: number("year"),
+      day: number("day"),
+      index,
+      tag: String::from(tag)
+    }
   }
+
 }
+/// Implements PartialEq, Eq, PartialOrd and Ord traits
 impl Eq for PuzzleInfo {}
 impl PartialEq for PuzzleInfo {
   fn eq(&self, other: &Self) -> bool {
@@ -40,13 +66,222 @@ impl PartialEq for PuzzleInfo {
     && self.tag == other.tag
   }
 }
+impl Ord for PuzzleInfo {
+  /// Orders puzzles by year, day, index and tag (in that order of precedence)
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.year.cmp(&other.year)
+      .then(self.day.cmp(&other.day))
+      .then(self.index.cmp(&other.index))
+      .then(self.tag.cmp(&other.tag))
+  }
+}
+impl PartialOrd for PuzzleInfo {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// PuzzleFilter struct
+/// 
+/// Describes a selection of puzzles by year/day/index ranges and included/excluded tags.
+/// A `None` field doesn't restrict selection, while an empty list selects nothing.
+#[derive(Clone, Debug, Default)]
+pub struct PuzzleFilter {
+  pub years: Option<Vec<RangeInclusive<u32>>>,
+  pub days: Option<Vec<RangeInclusive<u32>>>,
+  pub indices: Option<Vec<RangeInclusive<u32>>>,
+  pub tags: Option<Vec<String>>,
+  pub exclude_tags: Vec<String>
+}
+/// PuzzleFilter implementation
+/// 
+/// Provides parsing and matching of puzzle selections
+impl PuzzleFilter {
+
+  /// Parses a numeric selection
+  /// 
+  /// # Arguments
+  /// * value: Comma separated list of numbers (`3,7,19`) and/or inclusive ranges (`1-10`), or a lone `0` selecting all
+  /// 
+  /// # Returns
+  /// Parsed selection (`None` if not restricting selection), or a description of expected syntax
+  pub fn parse_numbers (value: &str) -> Result<Option<Vec<RangeInclusive<u32>>>, String> {
+    let expected = String::from("a positive, whole number, a range (1-10) or a list (3,7,19)");
+    // Selecting a lone 0 selects all
+    if value.trim() == "0" {
+      return Ok(None);
+    }
+    let mut ranges: Vec<RangeInclusive<u32>> = vec![];
+    for part in value.split(',').map(|part| part.trim()) {
+      let range = match part.split_once('-') {
+        Some((start, end)) => match (start.trim().parse::<u32>(), end.trim().parse::<u32>()) {
+          (Ok(start), Ok(end)) if start <= end => start..=end,
+          _ => return Err(expected)
+        },
+        None => match part.parse::<u32>() {
+          Ok(n) => n..=n,
+          Err(_) => return Err(expected)
+        }
+      };
+      // Selecting 0 within a list or a range is ambiguous
+      if range.contains(&0) {
+        return Err(expected);
+      }
+      ranges.push(range);
+    }
+    Ok(Some(ranges))
+  }
+
+  /// Parses a tag selection
+  /// 
+  /// # Arguments
+  /// * value: Comma separated list of tags (empty selecting all)
+  pub fn parse_tags (value: &str) -> Vec<String> {
+    value.split(',').map(|tag| tag.trim().to_string()).filter(|tag| !tag.is_empty()).collect()
+  }
+
+  /// Composes a selection of a single puzzle
+  /// 
+  /// # Arguments
+  /// * info: Information of the puzzle to select
+  pub fn only (info: &PuzzleInfo) -> PuzzleFilter {
+    PuzzleFilter {
+      years: Some(vec![info.year..=info.year]),
+      days: Some(vec![info.day..=info.day]),
+      indices: Some(vec![info.index..=info.index]),
+      tags: Some(vec![info.tag.clone()]),
+      exclude_tags: vec![]
+    }
+  }
+
+  /// Checks if a puzzle is selected
+  /// 
+  /// # Arguments
+  /// * info: Information of the puzzle to check
+  pub fn matches (&self, info: &PuzzleInfo) -> bool {
+    PuzzleFilter::matches_number(&self.years, info.year)
+    && PuzzleFilter::matches_number(&self.days, info.day)
+    && PuzzleFilter::matches_number(&self.indices, info.index)
+    && self.tags.as_ref().is_none_or(|tags| tags.contains(&info.tag))
+    && !self.exclude_tags.contains(&info.tag)
+  }
+
+  /// Checks if any puzzle of a year can be selected
+  /// 
+  /// # Arguments
+  /// * year: Year to check
+  pub fn includes_year (&self, year: u32) -> bool {
+    PuzzleFilter::matches_number(&self.years, year)
+  }
+
+  /// Checks if selection isn't restricted in any way
+  pub fn is_unrestricted (&self) -> bool {
+    self.years.is_none() && self.days.is_none() && self.indices.is_none() && self.tags.is_none() && self.exclude_tags.is_empty()
+  }
+
+  /// Composes a selection of puzzles selected by both this and another selection
+  /// 
+  /// # Arguments
+  /// * other: Other selection to intersect with
+  pub fn intersect (&self, other: &PuzzleFilter) -> PuzzleFilter {
+    PuzzleFilter {
+      years: PuzzleFilter::intersect_numbers(&self.years, &other.years),
+      days: PuzzleFilter::intersect_numbers(&self.days, &other.days),
+      indices: PuzzleFilter::intersect_numbers(&self.indices, &other.indices),
+      tags: match (&self.tags, &other.tags) {
+        (Some(a), Some(b)) => Some(a.iter().filter(|tag| b.contains(tag)).cloned().collect()),
+        (Some(a), None) => Some(a.clone()),
+        (None, b) => b.clone()
+      },
+      exclude_tags: [self.exclude_tags.clone(), other.exclude_tags.clone()].concat()
+    }
+  }
+
+  /// Checks if a number is selected by a numeric selection
+  fn matches_number (ranges: &Option<Vec<RangeInclusive<u32>>>, n: u32) -> bool {
+    ranges.as_ref().is_none_or(|ranges| ranges.iter().any(|range| range.contains(&n)))
+  }
+
+  /// Intersects two numeric selections
+  fn intersect_numbers (a: &Option<Vec<RangeInclusive<u32>>>, b: &Option<Vec<RangeInclusive<u32>>>) -> Option<Vec<RangeInclusive<u32>>> {
+    match (a, b) {
+      (Some(a), Some(b)) => Some(
+        a.iter()
+          .flat_map(|x| b.iter().map(move |y| *x.start().max(y.start())..=*x.end().min(y.end())))
+          .filter(|range| !range.is_empty())
+          .collect()
+      ),
+      (Some(a), None) => Some(a.clone()),
+      (None, b) => b.clone()
+    }
+  }
+
+}
+
+/// Puzzle implementation
+/// 
+/// Holds either an infallible, a fallible or a contextual puzzle implementation function
+pub enum PuzzleImplementation {
+  /// Infallible implementation function `fn(data: String) -> String`
+  Infallible(fn(data: String) -> String),
+  /// Fallible implementation function `fn(data: &str) -> Result<String, PuzzleError>`
+  Fallible(fn(data: &str) -> Result<String, PuzzleError>),
+  /// Fallible implementation function, with access to the execution context, `fn(data: &str, context: &PuzzleContext) -> Result<String, PuzzleError>`
+  Contextual(fn(data: &str, context: &PuzzleContext) -> Result<String, PuzzleError>)
+}
+/// Puzzle implementation implementation
+impl PuzzleImplementation {
+
+  /// Calls the implementation function, catching any panic as a `PuzzleError::Panic`
+  /// 
+  /// # Arguments
+  /// * data:    Puzzle input data
+  /// * context: Execution context (only passed on to contextual implementations)
+  /// 
+  /// # Returns
+  /// Puzzle result, or an error if implementation failed or panicked
+  pub fn call (&self, data: String, context: &PuzzleContext) -> Result<String, PuzzleError> {
+    panic::catch_unwind(panic::AssertUnwindSafe(|| {
+      match self {
+        PuzzleImplementation::Infallible(f) => Ok(f(data)),
+        PuzzleImplementation::Fallible(f) => f(data.as_str()),
+        PuzzleImplementation::Contextual(f) => f(data.as_str(), context)
+      }
+    }))
+    .unwrap_or_else(|payload| {
+      let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+      } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+      } else {
+        String::from("Unknown panic")
+      };
+      Err(PuzzleError::Panic(message))
+    })
+  }
+
+}
+
+/// PuzzleExecution struct
+/// 
+/// Outcome of (repeated) execution of a puzzle, before it's checked and output
+pub struct PuzzleExecution {
+  pub input_elapsed: f64,
+  pub samples: Vec<f64>,
+  pub result: Option<String>,
+  pub mismatch: Option<(usize, String)>,
+  pub error: Option<PuzzleError>,
+  /// Traces emitted by the puzzle and kept until the execution concludes
+  pub traces: Vec<Trace>
+}
 
 /// PuzzleRegistry struct
 /// 
 /// Keeps a registry of all puzzle and provides functionality required for
-/// registering and running puzzles
+/// registering and running puzzles. Puzzles are kept ordered by year, day,
+/// index and tag, so iterating the registry always runs them in the same order
 pub struct PuzzleRegistry {
-  pub puzzles: HashMap<PuzzleInfo, Box<fn(data: String) -> String>>
+  pub puzzles: BTreeMap<PuzzleInfo, PuzzleImplementation>
 }
 /// PuzzleRegistry implementation
 /// 
@@ -54,42 +289,376 @@ pub struct PuzzleRegistry {
 /// registering and running puzzles
 impl PuzzleRegistry {
 
-  /// Executes a puzzle
+  /// Composes path to a puzzle's input data file
+  /// 
+  /// # Arguments
+  /// * info: Information of the puzzle to compose the path for
+  /// * path: Path to the input data file, allowing [:year], [:day], [:index] and [:tag] interpolation
+  /// 
+  /// # Returns
+  /// Interpolated path
+  pub fn input_path (info: &PuzzleInfo, path: &str) -> String {
+    let year  = format!("{:0>4}", info.year);
+    let day   = format!("{:0>2}", info.day);
+    let index = format!("{:0>2}", info.index);
+    let tag   = info.tag.as_str();
+    path
+      .replace("[:year]",  year.as_str())
+      .replace("[:day]",   day.as_str())
+      .replace("[:index]", index.as_str())
+      .replace("[:tag]",   tag)
+  }
+
+  /// Loads a puzzle's input data
+  /// 
+  /// # Arguments
+  /// * info: Information of the puzzle to load input data for
+  /// * args: Startup arguments
+  /// 
+  /// # Returns
+  /// Puzzle input data, or an error if input file couldn't be read
+  pub fn load_input (info: &PuzzleInfo, args: &VArgs) -> Result<String, CliError> {
+    if !args.input_value.is_empty() {
+      Ok(args.input_value.clone())
+    }
+    else if !args.input_file.is_empty() {
+      Input::read_file(&PuzzleRegistry::input_path(info, &args.input_file))
+    }
+    else {
+      Ok(String::default())
+    }
+  }
+
+  /// Loads a puzzle's expected result
+  /// 
+  /// Unless explicitly set, expected result is read from the answer file stored next
+  /// to the input data file (see `Input::answer_path`)
+  /// 
+  /// # Arguments
+  /// * info: Information of the puzzle to load expected result for
+  /// * args: Startup arguments
+  /// 
+  /// # Returns
+  /// Expected result (empty if no expected result is known)
+  pub fn load_expected (info: &PuzzleInfo, args: &VArgs) -> String {
+    if !args.expect.is_empty() {
+      args.expect.trim().to_string()
+    }
+    else if args.input_value.is_empty() && !args.input_file.is_empty() {
+      Input::read_answer(&PuzzleRegistry::input_path(info, &args.input_file), info.index).unwrap_or_default()
+    }
+    else {
+      String::default()
+    }
+  }
+
+  /// Times (repeated) execution of a puzzle
   /// 
   /// # Arguments
-  /// * info:           Information of the puzzle being executed
   /// * start_instant:  Instant of puzzle started reading input data
-  /// * f:              Puzzle implementation function
+  /// * f:              Puzzle implementation (failures and panics are recorded as errors)
   /// * input:          Puzzle input data
-  /// * args:           Startup arguments
-  pub fn execute (info: &PuzzleInfo, start_instant: Instant, f: &Box<fn(data: String) -> String>, input: String, args: &VArgs) {
-    // Time function execution
-    let processing_instant = Instant::now();
-    let result = f(input);
-    let start_elapsed = start_instant.elapsed().as_secs_f32();
-    let processing_elapsed = processing_instant.elapsed().as_secs_f32();
+  /// * repeat:         Number of times to execute the puzzle
+  /// * context:        Execution context (only the first execution emits any traces)
+  /// 
+  /// # Returns
+  /// Outcome of the execution
+  pub fn measure (start_instant: Instant, f: &PuzzleImplementation, input: String, repeat: usize, context: &PuzzleContext) -> PuzzleExecution {
+    // Time input fetching
+    let input_elapsed = start_instant.elapsed().as_secs_f64();
+    // Time function execution (repeatedly if requested)
+    let repeat = if repeat > 0 { repeat } else { 1 };
+    let mut samples: Vec<f64> = Vec::with_capacity(repeat);
+    let mut result: Option<String> = None;
+    let mut mismatch: Option<(usize, String)> = None;
+    let mut error: Option<PuzzleError> = None;
+    let silent = PuzzleContext::silent();
+    for i in 0..repeat {
+      let data = input.clone();
+      let processing_instant = Instant::now();
+      let run_result = f.call(data, if i == 0 { context } else { &silent });
+      samples.push(processing_instant.elapsed().as_secs_f64());
+      // Stop on failed execution
+      let run_result = match run_result {
+        Ok(run_result) => run_result,
+        Err(err) => {
+          error = Some(err);
+          break;
+        }
+      };
+      // Check result is same as on all previous runs
+      match &result {
+        None => result = Some(run_result),
+        Some(first_result) => if mismatch.is_none() && first_result != &run_result {
+          mismatch = Some((i + 1, run_result));
+        }
+      }
+    }
+    // Finish any interactive playback of the (first) execution
+    context.finish();
+    PuzzleExecution {
+      input_elapsed,
+      samples,
+      result,
+      mismatch,
+      error,
+      traces: context.take()
+    }
+  }
+
+  /// Checks and outputs outcome of a puzzle execution
+  /// 
+  /// # Arguments
+  /// * info:       Information of the executed puzzle
+  /// * execution:  Outcome of the execution
+  /// * args:       Startup arguments
+  /// 
+  /// # Returns
+  /// Record of the execution (only output directly when using `text` output format)
+  pub fn conclude (info: &PuzzleInfo, execution: PuzzleExecution, args: &VArgs) -> PuzzleReport {
+    let PuzzleExecution { input_elapsed, samples, result, mismatch, error, traces } = execution;
+    let result = result.unwrap_or_default();
+    let benchmark = Benchmark::new(samples);
     // Check result
-    let valid: Option<bool> = if args.expect.is_empty() { None } else { Some(result.trim() == args.expect.trim()) };
+    let expect = PuzzleRegistry::load_expected(info, args);
+    let valid: Option<bool> = if error.is_some() || mismatch.is_some() { Some(false) } else if expect.is_empty() { None } else { Some(result.trim() == expect) };
     // Obfuscate result
     let output = if !args.obfuscate { result } else { String::from("*****") };
+    let mismatch = mismatch.map(|(run, run_result)| (run, if !args.obfuscate { run_result } else { String::from("*****") }));
+    // Compose execution record
+    let record = PuzzleReport {
+      year: info.year,
+      day: info.day,
+      index: info.index,
+      tag: info.tag.clone(),
+      result: output.clone(),
+      expected: if expect.is_empty() { None } else { Some(expect.clone()) },
+      valid,
+      message: match (&error, &mismatch, valid) {
+        (Some(err), _, _) => Some(err.to_string()),
+        (None, Some((run, run_result)), _) => Some(format!("Nondeterministic result: run #{} returned \"{}\" instead of \"{}\"", run, run_result, output)),
+        (None, None, Some(false)) => Some(format!("Expected \"{}\" but got \"{}\"", expect, output)),
+        _ => None
+      },
+      processing_time: benchmark.mean(),
+      total_time: input_elapsed + benchmark.mean()
+    };
+    // Structured output formats are rendered once all puzzles have executed (with any traces output to STDERR meanwhile)
+    if args.format != ReportFormat::Text {
+      traces.iter().for_each(|trace| trace.output(true));
+      return record;
+    }
     // Output execution value
     if args.verbose {
       StdOut::println(String::default(), None);
       StdOut::println(format!("Executing puzzle {:04}/{:02}.{} ({}):", info.year, info.day, info.index, info.tag), None);
-      StdOut::println(format!("Executed in {}ms ({}ms with input fetching) with result:", (processing_elapsed * 1000.0), (start_elapsed * 1000.0)), None);
     }
-    match valid {
-      None => StdOut::println(format!("{}", output), Some(StdOutColoring::UNKNOWN)),
-      Some(true) => StdOut::println(format!("{}", output), Some(StdOutColoring::VALID)),
-      Some(false) => StdOut::println(format!("{}", output), Some(StdOutColoring::INVALID)),
+    traces.iter().for_each(|trace| trace.output(false));
+    if args.verbose {
+      StdOut::println(format!("Executed in {}ms ({}ms with input fetching) with result:", (record.processing_time * 1000.0), (record.total_time * 1000.0)), None);
     }
-    
-}
+    if benchmark.count() > 1 {
+      StdOut::println(format!(
+        "Executed {} times: min {:.3}ms, mean {:.3}ms, median {:.3}ms, p95 {:.3}ms, stddev {:.3}ms",
+        benchmark.count(),
+        benchmark.min() * 1000.0,
+        benchmark.mean() * 1000.0,
+        benchmark.median() * 1000.0,
+        benchmark.percentile(95.0) * 1000.0,
+        benchmark.stddev() * 1000.0
+      ), None);
+    }
+    if error.is_some() || mismatch.is_some() {
+      StdOut::println(record.message.clone().unwrap(), Some(StdOutColoring::INVALID));
+    }
+    if error.is_none() {
+      match valid {
+        None => StdOut::println(format!("{}", output), Some(StdOutColoring::UNKNOWN)),
+        Some(true) => StdOut::println(format!("{}", output), Some(StdOutColoring::VALID)),
+        Some(false) => StdOut::println(format!("{}", output), Some(StdOutColoring::INVALID)),
+      }
+    }
+    record
+  }
+
+  /// Times (repeated) execution of a puzzle, in a supervised worker process if
+  /// a time limit or a memory budget was set
+  /// 
+  /// # Arguments
+  /// * info:           Information of the puzzle being executed
+  /// * start_instant:  Instant of puzzle started reading input data
+  /// * f:              Puzzle implementation
+  /// * input:          Puzzle input data
+  /// * args:           Startup arguments (`args.verbose` and `args.visualize` select traces to collect)
+  /// * buffered:       If traces should be kept until the execution concludes, instead of being streamed as emitted
+  ///   (traces from a supervised worker process are always kept)
+  /// 
+  /// With `args.playback` puzzle is always executed in process, playing back its visualization frames interactively
+  /// 
+  /// # Returns
+  /// Outcome of the execution
+  pub fn perform (info: &PuzzleInfo, start_instant: Instant, f: &PuzzleImplementation, input: String, args: &VArgs, buffered: bool) -> PuzzleExecution {
+    if args.playback {
+      let context = PuzzleRegistry::context(info, args, buffered).with_playback(Playback::new());
+      PuzzleRegistry::measure(start_instant, f, input, args.repeat, &context)
+    } else if args.timeout > 0 || args.memory_limit > 0 {
+      Supervisor::execute(info, start_instant, input, args)
+    } else {
+      PuzzleRegistry::measure(start_instant, f, input, args.repeat, &PuzzleRegistry::context(info, args, buffered))
+    }
+  }
+
+  /// Composes the context a puzzle is executed with
+  /// 
+  /// # Arguments
+  /// * info:     Information of the puzzle being executed
+  /// * args:     Startup arguments (`args.verbose`, `args.visualize` and `args.playback` select traces to collect,
+  ///   `args.export` sets a path to save visualization frames to)
+  /// * buffered: If traces should be kept until the execution concludes, instead of being streamed as emitted
+  pub fn context (info: &PuzzleInfo, args: &VArgs, buffered: bool) -> PuzzleContext {
+    let context = PuzzleContext::new(args.verbose, args.visualize || args.playback, buffered);
+    if args.export.is_empty() {
+      context
+    } else {
+      context.with_export(ImageSequence::new(&PuzzleRegistry::input_path(info, &args.export), args.palette.clone(), args.scale))
+    }
+  }
+
+  /// Runs all registered puzzles matching startup arguments (in year/day/index/tag order)
+  /// 
+  /// With `args.jobs` > 1 puzzles are executed on a pool of worker threads, while
+  /// their outcomes are still output in year/day/index/tag order (unless playing
+  /// back visualizations, which can only be watched one puzzle at a time)
+  /// 
+  /// # Arguments
+  /// * args:   Startup arguments
+  /// * report: Report to add records of all puzzle executions to
+  /// 
+  /// # Returns
+  /// Number of executed puzzles, or an error if a puzzle's input couldn't be loaded
+  pub fn run (&self, args: &VArgs, report: &mut Report) -> Result<usize, CliError> {
+    self.run_all(std::slice::from_ref(args), args.jobs, report, |_| ()).remove(0).map(|records| records.len())
+  }
+
+  /// Runs a batch of runs, each running all registered puzzles matching its own startup arguments
+  /// 
+  /// With `jobs` > 1 puzzles of all runs are executed on a single pool of worker threads, while
+  /// their outcomes are still output in order of runs (and in year/day/index/tag order within
+  /// each run), unless any of the runs is playing back visualizations
+  /// 
+  /// # Arguments
+  /// * runs:     Startup arguments of each run
+  /// * jobs:     Number of puzzles to execute in parallel
+  /// * report:   Report to add records of all puzzle executions to
+  /// * announce: Callback called with index of each run, before any of the run's outcomes are output
+  /// 
+  /// # Returns
+  /// Range of records each run added to the report, or an error if one of the run's puzzles' input
+  /// couldn't be loaded (skipping the rest of the run's puzzles)
+  pub fn run_all (&self, runs: &[VArgs], jobs: usize, report: &mut Report, mut announce: impl FnMut(usize)) -> Vec<Result<Range<usize>, CliError>> {
+    // Select puzzles matching each run's startup criteria
+    let selected: Vec<(usize, &PuzzleInfo, &PuzzleImplementation)> = runs.iter().enumerate()
+      .flat_map(|(run, args)| self.puzzles.iter().filter(move |(info, _)| args.puzzle.matches(info)).map(move |(info, f)| (run, info, f)))
+      .collect();
+    // Output outcome of a puzzle execution (announcing any runs up to the puzzle's run first, and
+    // skipping puzzles of runs which already failed)
+    let mut outcomes: Vec<Result<Range<usize>, CliError>> = Vec::with_capacity(runs.len());
+    let mut output = |run: usize, info: &PuzzleInfo, execution: &mut dyn FnMut() -> Option<Result<PuzzleExecution, CliError>>| -> bool {
+      while outcomes.len() <= run {
+        announce(outcomes.len());
+        outcomes.push(Ok(report.records.len()..report.records.len()));
+      }
+      if outcomes[run].is_err() {
+        return false;
+      }
+      match execution() {
+        Some(Ok(execution)) => {
+          report.push(PuzzleRegistry::conclude(info, execution, &runs[run]));
+          outcomes[run] = Ok(outcomes[run].as_ref().unwrap().start..report.records.len());
+        },
+        Some(Err(err)) => outcomes[run] = Err(err),
+        None => ()
+      }
+      outcomes[run].is_ok()
+    };
+    // Run puzzles one after another
+    if jobs <= 1 || selected.len() <= 1 || runs.iter().any(|args| args.playback) {
+      for (run, info, f) in selected.iter() {
+        let args = &runs[*run];
+        output(*run, info, &mut || {
+          // Time function execution
+          let start_input = Instant::now();
+          // Load puzzle input and run puzzle
+          Some(PuzzleRegistry::load_input(info, args)
+            .map(|input| PuzzleRegistry::perform(info, start_input, f, input, args, args.format != ReportFormat::Text)))
+        });
+      }
+    }
+    // Run puzzles on a pool of worker threads
+    else {
+      let next = AtomicUsize::new(0);
+      let failed: Vec<AtomicBool> = runs.iter().map(|_| AtomicBool::new(false)).collect();
+      let (sender, receiver) = mpsc::channel::<(usize, Option<Result<PuzzleExecution, CliError>>)>();
+      thread::scope(|scope| {
+        for _ in 0..jobs.min(selected.len()) {
+          let sender = sender.clone();
+          let (selected, next, failed) = (&selected, &next, &failed);
+          scope.spawn(move || {
+            loop {
+              // Pick up next puzzle to execute (skipping puzzles of runs which already failed)
+              let i = next.fetch_add(1, AtomicOrdering::SeqCst);
+              if i >= selected.len() { break; }
+              let (run, info, f) = selected[i];
+              if failed[run].load(AtomicOrdering::SeqCst) {
+                if sender.send((i, None)).is_err() { break; }
+                continue;
+              }
+              // Load puzzle input and run puzzle
+              let args = &runs[run];
+              let start_input = Instant::now();
+              let execution = PuzzleRegistry::load_input(info, args)
+                .map(|input| PuzzleRegistry::perform(info, start_input, f, input, args, true));
+              if sender.send((i, Some(execution))).is_err() { break; }
+            }
+          });
+        }
+        drop(sender);
+        // Output outcomes in order, as soon as all preceding puzzles have been output
+        let mut executions: Vec<Option<Option<Result<PuzzleExecution, CliError>>>> = (0..selected.len()).map(|_| None).collect();
+        let mut next_output: usize = 0;
+        for (i, execution) in receiver.iter() {
+          executions[i] = Some(execution);
+          while next_output < selected.len() && executions[next_output].is_some() {
+            let (run, info, _) = selected[next_output];
+            let mut execution = executions[next_output].take();
+            if !output(run, info, &mut || execution.take().unwrap()) {
+              failed[run].store(true, AtomicOrdering::SeqCst);
+            }
+            next_output += 1;
+          }
+        }
+      });
+    }
+    // Announce any remaining runs without any matching puzzles
+    while outcomes.len() < runs.len() {
+      announce(outcomes.len());
+      outcomes.push(Ok(report.records.len()..report.records.len()));
+    }
+    outcomes
+  }
+
+  /// Counts registered puzzles matching a selection
+  /// 
+  /// # Arguments
+  /// * filter: Puzzle selection
+  pub fn count (&self, filter: &PuzzleFilter) -> usize {
+    self.puzzles.keys().filter(|info| filter.matches(info)).count()
+  }
 
   /// Constructor
   pub fn new () -> PuzzleRegistry {
     PuzzleRegistry {
-      puzzles: HashMap::new()
+      puzzles: BTreeMap::new()
     }
   }
 
@@ -101,7 +670,108 @@ impl PuzzleRegistry {
   /// * info: Puzzle information
   /// * f:    Puzzle implementation function `fn(data: String) -> String)`
   pub fn register(&mut self, info: PuzzleInfo, f: fn(data: String) -> String) {
-    self.puzzles.insert(info, Box::new(f));
+    self.puzzles.insert(info, PuzzleImplementation::Infallible(f));
+  }
+
+  /// Registers a fallible puzzle
+  /// 
+  /// Registers a puzzle's implementation, which can fail with a typed error
+  /// instead of panicking, associated to the puzzle's information
+  /// 
+  /// # Arguments
+  /// * info: Puzzle information
+  /// * f:    Puzzle implementation function `fn(data: &str) -> Result<String, PuzzleError>`
+  pub fn register_fallible(&mut self, info: PuzzleInfo, f: fn(data: &str) -> Result<String, PuzzleError>) {
+    self.puzzles.insert(info, PuzzleImplementation::Fallible(f));
+  }
+
+  /// Registers a contextual puzzle
+  /// 
+  /// Registers a puzzle's implementation, which can fail with a typed error and
+  /// can emit log messages and visualization frames through its execution context,
+  /// associated to the puzzle's information
+  /// 
+  /// # Arguments
+  /// * info: Puzzle information
+  /// * f:    Puzzle implementation function `fn(data: &str, context: &PuzzleContext) -> Result<String, PuzzleError>`
+  pub fn register_contextual(&mut self, info: PuzzleInfo, f: fn(data: &str, context: &PuzzleContext) -> Result<String, PuzzleError>) {
+    self.puzzles.insert(info, PuzzleImplementation::Contextual(f));
   }
 }
 
+/// Declares puzzles of a day, generating the day's `init` function
+/// 
+/// Year and day are derived from the module (`..::yearYYYY::dayDD`) the macro is used in, each puzzle
+/// being declared by its index (and optionally a tag, `"puzzle"` by default) and its implementation:
+/// 
+/// ```ignore
+/// puzzles! {
+///   1 => |data: String| { ... },
+///   2 ("puzzle") => |data: &str| -> Result<String, PuzzleError> { ... },
+///   3 ("visual") => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> { ... }
+/// }
+/// ```
+macro_rules! puzzles {
+  // Registers no more puzzles
+  (@register $registry:ident;) => {};
+  // Registers a puzzle with default tag
+  (@register $registry:ident; $index:literal => $($rest:tt)*) => {
+    puzzles!(@register $registry; $index ("puzzle") => $($rest)*);
+  };
+  // Registers an infallible puzzle
+  (@register $registry:ident; $index:literal ($tag:literal) => |$data:tt: String| { $($body:tt)* } $(, $($rest:tt)*)?) => {
+    $registry.register(
+      $crate::lib::puzzle::PuzzleInfo::from_module(module_path!(), $index, $tag),
+      |$data: String| { $($body)* }
+    );
+    puzzles!(@register $registry; $($($rest)*)?);
+  };
+  // Registers a fallible puzzle
+  (@register $registry:ident; $index:literal ($tag:literal) => |$data:tt: &str| -> Result<String, PuzzleError> { $($body:tt)* } $(, $($rest:tt)*)?) => {
+    $registry.register_fallible(
+      $crate::lib::puzzle::PuzzleInfo::from_module(module_path!(), $index, $tag),
+      |$data: &str| -> Result<String, $crate::lib::error::PuzzleError> { $($body)* }
+    );
+    puzzles!(@register $registry; $($($rest)*)?);
+  };
+  // Registers a contextual puzzle
+  (@register $registry:ident; $index:literal ($tag:literal) => |$data:tt: &str, $context:tt: &PuzzleContext| -> Result<String, PuzzleError> { $($body:tt)* } $(, $($rest:tt)*)?) => {
+    $registry.register_contextual(
+      $crate::lib::puzzle::PuzzleInfo::from_module(module_path!(), $index, $tag),
+      |$data: &str, $context: &$crate::lib::context::PuzzleContext| -> Result<String, $crate::lib::error::PuzzleError> { $($body)* }
+    );
+    puzzles!(@register $registry; $($($rest)*)?);
+  };
+  // Generates the day's init function
+  ($($puzzles:tt)*) => {
+    /// Registers puzzles for the day
+    pub fn init (mut registry: $crate::lib::puzzle::PuzzleRegistry) -> $crate::lib::puzzle::PuzzleRegistry {
+      puzzles!(@register registry; $($puzzles)*);
+      registry
+    }
+  };
+}
+pub(crate) use puzzles;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_numeric_selections () {
+    assert_eq!(PuzzleFilter::parse_numbers("0"), Ok(None));
+    assert_eq!(PuzzleFilter::parse_numbers(" 0 "), Ok(None));
+    assert_eq!(PuzzleFilter::parse_numbers("5"), Ok(Some(vec![5..=5])));
+    assert_eq!(PuzzleFilter::parse_numbers("3, 7-9,19"), Ok(Some(vec![3..=3, 7..=9, 19..=19])));
+    assert!(PuzzleFilter::parse_numbers("9-7").is_err());
+    assert!(PuzzleFilter::parse_numbers("x").is_err());
+  }
+
+  #[test]
+  fn rejects_zero_within_lists_and_ranges () {
+    assert!(PuzzleFilter::parse_numbers("0,5").is_err());
+    assert!(PuzzleFilter::parse_numbers("5,0").is_err());
+    assert!(PuzzleFilter::parse_numbers("0-3").is_err());
+  }
+
+}
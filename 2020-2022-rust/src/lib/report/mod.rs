@@ -0,0 +1,206 @@
+//! Report module
+//! 
+//! Provides structured (JSON / JUnit XML / TAP) reporting of puzzle executions
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use crate::lib::json::*;
+
+/// Enumerates report output formats
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReportFormat {
+  Text,
+  Json,
+  Junit,
+  Tap
+}
+/// ReportFormat implementation
+impl ReportFormat {
+
+  /// Parses report format from its name
+  /// 
+  /// # Arguments
+  /// * name: Name of the format (`text`, `json`, `junit` or `tap`)
+  /// 
+  /// # Returns
+  /// Parsed report format, if name was recognized
+  pub fn parse (name: &str) -> Option<ReportFormat> {
+    match name.trim().to_lowercase().as_str() {
+      "text" => Some(ReportFormat::Text),
+      "json" => Some(ReportFormat::Json),
+      "junit" => Some(ReportFormat::Junit),
+      "tap" => Some(ReportFormat::Tap),
+      _ => None
+    }
+  }
+
+}
+
+/// PuzzleReport struct
+/// 
+/// Record of a single puzzle execution
+pub struct PuzzleReport {
+  pub year: u32,
+  pub day: u32,
+  pub index: u32,
+  pub tag: String,
+  pub result: String,
+  pub expected: Option<String>,
+  pub valid: Option<bool>,
+  pub message: Option<String>,
+  pub processing_time: f64,
+  pub total_time: f64
+}
+/// PuzzleReport implementation
+impl PuzzleReport {
+
+  /// Gets a human readable puzzle name
+  pub fn name (&self) -> String {
+    format!("{:04}/{:02}.{} ({})", self.year, self.day, self.index, self.tag)
+  }
+
+  /// Checks if execution should be considered failed
+  pub fn failed (&self) -> bool {
+    self.valid == Some(false)
+  }
+
+}
+
+/// Report struct
+/// 
+/// Collects puzzle execution records and renders them in a structured format
+pub struct Report {
+  pub format: ReportFormat,
+  pub records: Vec<PuzzleReport>
+}
+/// Report implementation
+/// 
+/// Collects puzzle execution records and renders them in a structured format
+impl Report {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * format: Format to render the report in
+  pub fn new (format: ReportFormat) -> Report {
+    Report {
+      format,
+      records: vec![]
+    }
+  }
+
+  /// Adds a puzzle execution record to the report
+  /// 
+  /// # Arguments
+  /// * record: Puzzle execution record
+  pub fn push (&mut self, record: PuzzleReport) {
+    self.records.push(record);
+  }
+
+  /// Renders the report
+  /// 
+  /// # Returns
+  /// Rendered report (empty for `Text` format, which is output as puzzles execute)
+  pub fn render (&self) -> String {
+    match self.format {
+      ReportFormat::Text => String::default(),
+      ReportFormat::Json => self.render_json(),
+      ReportFormat::Junit => self.render_junit(),
+      ReportFormat::Tap => self.render_tap()
+    }
+  }
+
+  /// Prints out the rendered report (if not empty)
+  pub fn print (&self) {
+    let rendered = self.render();
+    if !rendered.is_empty() {
+      println!("{}", rendered);
+    }
+  }
+
+  /// Renders the report as a JSON array of records
+  fn render_json (&self) -> String {
+    let records: Vec<String> = self.records.iter().map(|record| {
+      format!(
+        "  {{ \"year\": {}, \"day\": {}, \"index\": {}, \"tag\": {}, \"result\": {}, \"expected\": {}, \"passed\": {}, \"message\": {}, \"processing_ms\": {}, \"total_ms\": {} }}",
+        record.year,
+        record.day,
+        record.index,
+        Json::quote(&record.tag),
+        Json::quote(&record.result),
+        record.expected.as_ref().map_or(String::from("null"), |x| Json::quote(x)),
+        record.valid.map_or(String::from("null"), |x| x.to_string()),
+        record.message.as_ref().map_or(String::from("null"), |x| Json::quote(x)),
+        record.processing_time * 1000.0,
+        record.total_time * 1000.0
+      )
+    }).collect();
+    if records.is_empty() { String::from("[]") } else { format!("[\n{}\n]", records.join(",\n")) }
+  }
+
+  /// Renders the report as a JUnit XML test suite
+  fn render_junit (&self) -> String {
+    let failures = self.records.iter().filter(|record| record.failed()).count();
+    let time: f64 = self.records.iter().map(|record| record.total_time).sum();
+    let mut lines: Vec<String> = vec![];
+    lines.push(String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    lines.push(format!("<testsuites tests=\"{}\" failures=\"{}\" time=\"{}\">", self.records.len(), failures, time));
+    lines.push(format!("  <testsuite name=\"adventofcode\" tests=\"{}\" failures=\"{}\" time=\"{}\">", self.records.len(), failures, time));
+    for record in &self.records {
+      lines.push(format!(
+        "    <testcase classname=\"adventofcode.{:04}.day{:02}\" name=\"{}\" time=\"{}\">",
+        record.year, record.day, xml_string(&record.name()), record.processing_time
+      ));
+      lines.push(String::from("      <properties>"));
+      if let Some(expected) = &record.expected {
+        lines.push(format!("        <property name=\"expected\" value=\"{}\"/>", xml_string(expected)));
+      }
+      lines.push(format!("        <property name=\"total_time\" value=\"{}\"/>", record.total_time));
+      lines.push(String::from("      </properties>"));
+      if record.failed() {
+        lines.push(format!("      <failure message=\"{}\"/>", xml_string(&record.message.clone().unwrap_or_default())));
+      }
+      lines.push(format!("      <system-out>{}</system-out>", xml_string(&record.result)));
+      lines.push(String::from("    </testcase>"));
+    }
+    lines.push(String::from("  </testsuite>"));
+    lines.push(String::from("</testsuites>"));
+    lines.join("\n")
+  }
+
+  /// Renders the report as a TAP (version 13) stream
+  fn render_tap (&self) -> String {
+    let mut lines: Vec<String> = vec![];
+    lines.push(String::from("TAP version 13"));
+    lines.push(format!("1..{}", self.records.len()));
+    for (i, record) in self.records.iter().enumerate() {
+      lines.push(format!("{} {} - {}", if record.failed() { "not ok" } else { "ok" }, i + 1, record.name()));
+      lines.push(String::from("  ---"));
+      lines.push(format!("  result: {}", Json::quote(&record.result)));
+      if let Some(expected) = &record.expected {
+        lines.push(format!("  expected: {}", Json::quote(expected)));
+      }
+      if let Some(message) = &record.message {
+        lines.push(format!("  message: {}", Json::quote(message)));
+      }
+      lines.push(format!("  processing_ms: {}", record.processing_time * 1000.0));
+      lines.push(format!("  total_ms: {}", record.total_time * 1000.0));
+      lines.push(String::from("  ..."));
+    }
+    lines.join("\n")
+  }
+
+}
+
+/// Escapes a string for use in XML text and attribute values
+/// 
+/// # Arguments
+/// * value: String to escape
+fn xml_string (value: &str) -> String {
+  value
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&apos;")
+}
@@ -0,0 +1,179 @@
+//! Scaffold module
+//! 
+//! Generates a new day's puzzle module, input data directory and manifest tasks
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::fs;
+use std::path::Path;
+
+/// Template of a day's puzzle module
+const DAY_TEMPLATE: &str = include_str!("./day.rs.template");
+/// Template of a year's module
+const YEAR_TEMPLATE: &str = include_str!("./year.rs.template");
+/// Template of a year's library module
+const YEAR_LIB_TEMPLATE: &str = include_str!("./year_lib.rs.template");
+
+/// Scaffold struct
+/// 
+/// Describes a day to scaffold and where to scaffold it
+pub struct Scaffold {
+  pub year: u32,
+  pub day: u32,
+  /// Path to the source directory (holding `./yearYYYY` directories)
+  pub src: String,
+  /// Path to the task manifest to add the day's tasks to
+  pub manifest: String
+}
+/// Scaffold implementation
+/// 
+/// Never overwrites anything: each file (or manifest task) which already exists is skipped
+impl Scaffold {
+
+  /// Generates all files of the day
+  /// 
+  /// # Returns
+  /// Description of every generated or skipped file, or a description of the first file failing to generate
+  pub fn generate (&self) -> Result<Vec<String>, String> {
+    let year_dir = Path::new(&self.src).join(format!("year{}", self.year));
+    let data_dir = year_dir.join("data").join(format!("day{:02}", self.day));
+    let mut log: Vec<String> = vec![];
+
+    // Generate year module and its (own) library module, when starting a new year
+    log.push(Scaffold::create(&year_dir.join("mod.rs"), &self.interpolate(YEAR_TEMPLATE))?);
+    log.push(Scaffold::create(&year_dir.join("lib").join("mod.rs"), &self.interpolate(YEAR_LIB_TEMPLATE))?);
+    // Generate day module (picked up and registered automatically at build time)
+    log.push(Scaffold::create(&year_dir.join(format!("day{:02}.rs", self.day)), &self.interpolate(DAY_TEMPLATE))?);
+    // Generate empty input data files
+    log.push(Scaffold::create(&data_dir.join("input-test.txt"), "")?);
+    log.push(Scaffold::create(&data_dir.join("input.txt"), "")?);
+    // Add tasks to the manifest
+    log.push(self.add_manifest_tasks()?);
+
+    Ok(log)
+  }
+
+  /// Replaces `[:year]` and `[:day]` placeholders in a template
+  /// 
+  /// # Arguments
+  /// * template: Template to interpolate
+  fn interpolate (&self, template: &str) -> String {
+    template
+      .replace("[:year]", &self.year.to_string())
+      .replace("[:day]", &self.day.to_string())
+  }
+
+  /// Creates a file (and any missing parent directories), unless it already exists
+  /// 
+  /// # Arguments
+  /// * path:    Path of the file to create
+  /// * content: Content of the file
+  /// 
+  /// # Returns
+  /// Description of what was done
+  fn create (path: &Path, content: &str) -> Result<String, String> {
+    if path.exists() {
+      return Ok(format!("Skipped \"{}\" (already exists)", path.display()));
+    }
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir).map_err(|err| format!("Failed creating directory \"{}\": {}", dir.display(), err))?;
+    }
+    fs::write(path, content).map_err(|err| format!("Failed writing \"{}\": {}", path.display(), err))?;
+    Ok(format!("Created \"{}\"", path.display()))
+  }
+
+  /// Adds test and solution tasks for both parts of the day to the manifest, within a `// #region Day DD`
+  /// block, placed in order of days within the `// #region YEAR YYYY` block (which is added if missing)
+  /// 
+  /// # Returns
+  /// Description of what was done
+  fn add_manifest_tasks (&self) -> Result<String, String> {
+    // Read manifest
+    let manifest = fs::read_to_string(&self.manifest).map_err(|err| format!("Failed reading manifest \"{}\": {}", self.manifest, err))?;
+    if manifest.contains(&format!("\"name\": \"{}-{:02}-01-T\"", self.year, self.day)) {
+      return Ok(format!("Skipped manifest \"{}\" tasks (already exist)", self.manifest));
+    }
+    let mut lines: Vec<String> = manifest.lines().map(|line| line.to_string()).collect();
+
+    // Find year region
+    let year_region = format!("    // #region YEAR {}", self.year);
+    let day_region = format!("    // #region Day {:02}", self.day);
+    let (position, mut block) = match lines.iter().position(|line| line.trim_end() == year_region) {
+      // Place day region before the first later day region, or at the end of the year region
+      Some(year_start) => {
+        let mut depth = 0;
+        let mut position = year_start + 1;
+        while position < lines.len() {
+          let line = lines[position].trim();
+          if line.starts_with("// #region") {
+            if depth == 0 && lines[position].trim_end() > day_region.as_str() { break; }
+            depth += 1;
+          } else if line.starts_with("// #endregion") {
+            if depth == 0 { break; }
+            depth -= 1;
+          }
+          position += 1;
+        }
+        (position, vec![])
+      },
+      // Place a new year region, containing the day region, before the end of the tasks list
+      None => {
+        let position = lines.iter().rposition(|line| line.trim() == "]")
+          .ok_or_else(|| format!("Failed finding end of tasks in manifest \"{}\"", self.manifest))?;
+        (position, vec![year_region.clone()])
+      }
+    };
+
+    // Compose tasks
+    block.push(day_region);
+    let mut tasks: Vec<String> = vec![];
+    for index in 1..3 {
+      for (task_type, input) in [("test", "input-test"), ("solution", "input")].iter() {
+        tasks.push(self.manifest_task(index, task_type, input));
+      }
+    }
+    block.push(tasks.join(",\n"));
+    block.push(String::from("    // #endregion"));
+    if block[0].starts_with("    // #region YEAR") {
+      block.push(String::from("    // #endregion"));
+    }
+
+    // Separate tasks from any preceding and following tasks with commas
+    let is_comment = |line: &String| line.trim().is_empty() || line.trim().starts_with("//");
+    if let Some(preceding) = lines[..position].iter_mut().rev().find(|line| !is_comment(line)) {
+      if preceding.trim_end() == "    }" {
+        preceding.push(',');
+      }
+    }
+    if lines[position..].iter().find(|line| !is_comment(line)).is_some_and(|following| following.trim_end() == "    {") {
+      let tasks = block.iter().rposition(|line| !line.trim().starts_with("//")).unwrap();
+      block[tasks].push(',');
+    }
+
+    // Write manifest
+    lines.splice(position..position, block);
+    fs::write(&self.manifest, lines.join("\n") + "\n").map_err(|err| format!("Failed writing manifest \"{}\": {}", self.manifest, err))?;
+    Ok(format!("Added 4 tasks to manifest \"{}\"", self.manifest))
+  }
+
+  /// Composes a single manifest task, in the manifest's own formatting
+  /// 
+  /// # Arguments
+  /// * index:     Index of the puzzle (part) within the day
+  /// * task_type: Type of the task (`test` or `solution`)
+  /// * input:     Name of the input data file (without extension)
+  fn manifest_task (&self, index: u32, task_type: &str, input: &str) -> String {
+    let name = format!("{}-{:02}-{:02}-{}", self.year, self.day, index, if task_type == "test" { "T" } else { "S" });
+    let path = format!("./src/year{}/data/day{:02}/{}.txt", self.year, self.day, input);
+    let args = [
+      "run", "--release", "--",
+      "--year", &self.year.to_string(), "--day", &self.day.to_string(), "--index", &index.to_string(),
+      "--input-file", &path,
+      "--expect", "{{:value}}",
+      "{{verbose??--verbose}}"
+    ];
+    let args = args.iter().map(|arg| format!("        \"{}\"", arg)).collect::<Vec<String>>().join(",\n");
+    format!("    {{\n      \"name\": \"{}\",\n      \"type\": \"{}\",\n      \"command\": \"cargo\",\n      \"args\": [\n{}\n      ]\n    }}", name, task_type, args)
+  }
+
+}
@@ -56,15 +56,21 @@ impl StdOut {
   /// * text:     Text to print out
   /// * coloring: Text coloring
   pub fn print (text: String, coloring: Option<StdOutColoring>) {
-    let reset = format!("{}{}", STDOUT_RESET.foreground, STDOUT_RESET.background);
-    let style = match coloring {
+    print!("{}{}{}", StdOut::style(coloring), text, StdOut::style(None));
+  }
+
+  /// Composes escape codes switching to a text coloring
+  /// 
+  /// # Arguments
+  /// * coloring: Text coloring (`None` resetting coloring)
+  pub fn style (coloring: Option<StdOutColoring>) -> String {
+    match coloring {
       None => format!("{}{}", STDOUT_RESET.foreground, STDOUT_RESET.background),
       Some(StdOutColoring::RESET) => format!("{}{}", STDOUT_RESET.foreground, STDOUT_RESET.background),
       Some(StdOutColoring::UNKNOWN) => format!("{}{}", STDOUT_UNKNOWN.foreground, STDOUT_UNKNOWN.background),
       Some(StdOutColoring::VALID) => format!("{}{}", STDOUT_VALID.foreground, STDOUT_VALID.background),
       Some(StdOutColoring::INVALID) => format!("{}{}", STDOUT_INVALID.foreground, STDOUT_INVALID.background)
-    };
-    print!("{}{}{}", style, text, reset);
+    }
   }
 
   /// Prints a line of text to STDOUT
@@ -0,0 +1,229 @@
+//! Supervisor module
+//! 
+//! Runs puzzles in supervised worker processes, enforcing time and memory limits
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::env;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::lib::context::*;
+use crate::lib::error::*;
+use crate::lib::json::*;
+use crate::lib::memory::*;
+use crate::lib::puzzle::*;
+use crate::lib::report::*;
+use crate::lib::vargs::*;
+
+/// Line separating any output of the puzzle itself from the worker's execution outcome
+const WORKER_OUTCOME_MARKER: &str = "--- adventofcode worker outcome ---";
+
+/// Supervisor struct
+pub struct Supervisor {}
+/// Supervisor implementation
+/// 
+/// Runs puzzles in supervised worker processes, enforcing time and memory limits
+impl Supervisor {
+
+  /// Executes a puzzle in a supervised worker process
+  /// 
+  /// Worker is a child process of the same executable, started with `--worker`, which
+  /// receives puzzle input data over STDIN and reports the execution outcome (including any
+  /// traces the puzzle emitted) over STDOUT.
+  /// Worker is killed if it doesn't finish within `args.timeout` milliseconds and
+  /// refuses to allocate more than `args.memory_limit` megabytes of memory.
+  /// 
+  /// # Arguments
+  /// * info:           Information of the puzzle to execute
+  /// * start_instant:  Instant of puzzle started reading input data
+  /// * input:          Puzzle input data
+  /// * args:           Startup arguments
+  /// 
+  /// # Returns
+  /// Outcome of the execution
+  pub fn execute (info: &PuzzleInfo, start_instant: Instant, input: String, args: &VArgs) -> PuzzleExecution {
+    // Time input fetching
+    let input_elapsed = start_instant.elapsed().as_secs_f64();
+    // Start worker
+    let mut command = Command::new(match env::current_exe() {
+      Ok(path) => path,
+      Err(err) => return Supervisor::failed(input_elapsed, 0.0, PuzzleError::Worker(err.to_string()))
+    });
+    command
+      .args(["--worker", "--year", &info.year.to_string(), "--day", &info.day.to_string(), "--index", &info.index.to_string()])
+      .args(["--tag", &info.tag, "--repeat", &args.repeat.to_string(), "--memory-limit", &args.memory_limit.to_string()])
+      .args([("--verbose", args.verbose), ("--visualize", args.visualize)].iter().filter(|(_, set)| *set).map(|(flag, _)| flag))
+      .args(if args.export.is_empty() { vec![] } else {
+        vec![String::from("--export"), PuzzleRegistry::input_path(info, &args.export), String::from("--palette"), args.palette.to_string(), String::from("--scale"), args.scale.to_string()]
+      })
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped());
+    let processing_instant = Instant::now();
+    let mut child = match command.spawn() {
+      Ok(child) => child,
+      Err(err) => return Supervisor::failed(input_elapsed, 0.0, PuzzleError::Worker(format!("Failed starting worker: {}", err)))
+    };
+    // Feed input data and collect output on separate threads (so that neither pipe can fill up and block the worker)
+    let mut stdin = child.stdin.take().unwrap();
+    thread::spawn(move || { stdin.write_all(input.as_bytes()).ok(); });
+    let mut stdout = child.stdout.take().unwrap();
+    let stdout_reader = thread::spawn(move || { let mut output = String::default(); stdout.read_to_string(&mut output).ok(); output });
+    let mut stderr = child.stderr.take().unwrap();
+    let stderr_reader = thread::spawn(move || { let mut output = String::default(); stderr.read_to_string(&mut output).ok(); output });
+    // Wait for worker to finish (or kill it once out of time)
+    let status = loop {
+      match child.try_wait() {
+        Ok(Some(status)) => break Some(status),
+        Ok(None) => if args.timeout > 0 && processing_instant.elapsed() >= Duration::from_millis(args.timeout) {
+          child.kill().ok();
+          child.wait().ok();
+          break None;
+        } else {
+          thread::sleep(Duration::from_millis(1));
+        },
+        Err(err) => return Supervisor::failed(input_elapsed, processing_instant.elapsed().as_secs_f64(), PuzzleError::Worker(err.to_string()))
+      }
+    };
+    let processing_elapsed = processing_instant.elapsed().as_secs_f64();
+    let stdout = stdout_reader.join().unwrap_or_default();
+    let stderr = stderr_reader.join().unwrap_or_default();
+    // Check if worker ran out of time
+    let status = match status {
+      Some(status) => status,
+      None => return Supervisor::failed(input_elapsed, processing_elapsed, PuzzleError::Timeout(args.timeout))
+    };
+    // Pass through any output of the puzzle itself (to STDERR, if STDOUT is reserved for a structured report)
+    let (output, outcome) = match stdout.rfind(WORKER_OUTCOME_MARKER) {
+      Some(position) => (stdout[..position].strip_suffix('\n').unwrap_or(&stdout[..position]), Some(&stdout[position + WORKER_OUTCOME_MARKER.len()..])),
+      None => (stdout.as_str(), None)
+    };
+    match args.format {
+      ReportFormat::Text => print!("{}", output),
+      _ => eprint!("{}", output)
+    }
+    // Parse worker's execution outcome
+    match outcome.map(|outcome| Supervisor::decode(outcome.trim())) {
+      Some(Some(mut execution)) => {
+        eprint!("{}", stderr);
+        execution.input_elapsed = input_elapsed;
+        execution
+      },
+      // Check if worker ran out of memory
+      _ if stderr.contains("memory allocation of") => Supervisor::failed(input_elapsed, processing_elapsed, PuzzleError::OutOfMemory(args.memory_limit)),
+      _ => {
+        let message = stderr.lines().rfind(|line| !line.trim().is_empty()).unwrap_or("no output");
+        Supervisor::failed(input_elapsed, processing_elapsed, PuzzleError::Worker(format!("Worker exited with {}: {}", status, message)))
+      }
+    }
+  }
+
+  /// Runs as a worker: executes a single puzzle, with input data read from STDIN,
+  /// and outputs the execution outcome to STDOUT
+  /// 
+  /// # Arguments
+  /// * registry: Registry of all puzzles
+  /// * args:     Startup arguments selecting a single puzzle
+  /// 
+  /// # Returns
+  /// Process exit code
+  pub fn serve (registry: &PuzzleRegistry, args: &VArgs) -> i32 {
+    // Find puzzle
+    let (info, f) = match registry.puzzles.iter().find(|(info, _)| args.puzzle.matches(info)) {
+      Some(puzzle) => puzzle,
+      None => return ExitCode::NO_MATCH
+    };
+    // Read input data
+    let start_instant = Instant::now();
+    let mut input = String::default();
+    if io::stdin().read_to_string(&mut input).is_err() {
+      return ExitCode::INPUT;
+    }
+    // Execute puzzle within memory budget
+    ALLOCATOR.set_limit(args.memory_limit * 1024 * 1024);
+    let execution = PuzzleRegistry::measure(start_instant, f, input, args.repeat, &PuzzleRegistry::context(info, args, true));
+    ALLOCATOR.set_limit(0);
+    // Output execution outcome
+    println!();
+    println!("{}", WORKER_OUTCOME_MARKER);
+    println!("{}", Supervisor::encode(&execution));
+    ExitCode::SUCCESS
+  }
+
+  /// Composes outcome of a failed execution
+  fn failed (input_elapsed: f64, processing_elapsed: f64, error: PuzzleError) -> PuzzleExecution {
+    PuzzleExecution {
+      input_elapsed,
+      samples: vec![processing_elapsed],
+      result: None,
+      mismatch: None,
+      error: Some(error),
+      traces: vec![]
+    }
+  }
+
+  /// Encodes an execution outcome as JSON
+  fn encode (execution: &PuzzleExecution) -> String {
+    let text = |value: &str| Json::String(value.to_string());
+    Json::Object(vec![
+      (String::from("samples"), Json::Array(execution.samples.iter().map(|sample| Json::Number(*sample)).collect())),
+      (String::from("result"), execution.result.as_ref().map_or(Json::Null, |result| text(result))),
+      (String::from("mismatch"), execution.mismatch.as_ref().map_or(Json::Null, |(run, result)| Json::Array(vec![Json::Number(*run as f64), text(result)]))),
+      (String::from("error"), execution.error.as_ref().map_or(Json::Null, |error| {
+        let (kind, message) = match error {
+          PuzzleError::Parse(message) => ("parse", message.clone()),
+          PuzzleError::InvalidInput(message) => ("invalid", message.clone()),
+          PuzzleError::NoSolution(message) => ("nosolution", message.clone()),
+          PuzzleError::Panic(message) => ("panic", message.clone()),
+          PuzzleError::Timeout(ms) => ("timeout", ms.to_string()),
+          PuzzleError::OutOfMemory(mb) => ("oom", mb.to_string()),
+          PuzzleError::Worker(message) => ("worker", message.clone())
+        };
+        Json::Array(vec![text(kind), text(message.as_str())])
+      })),
+      (String::from("traces"), Json::Array(execution.traces.iter().map(|trace| match trace {
+        Trace::Log(message) => Json::Array(vec![text("log"), text(message)]),
+        Trace::Frame(frame) => Json::Array(vec![text("frame"), text(frame)])
+      }).collect()))
+    ]).stringify()
+  }
+
+  /// Decodes an execution outcome from JSON
+  fn decode (encoded: &str) -> Option<PuzzleExecution> {
+    let json = Json::parse(encoded).ok()?;
+    let samples = json.get("samples")?.as_array()?.iter().map(|sample| sample.as_number().unwrap_or(0.0)).collect();
+    let result = json.get("result").and_then(|result| result.to_text());
+    let mismatch = json.get("mismatch").and_then(|mismatch| mismatch.as_array()).and_then(|mismatch| {
+      Some((mismatch.first()?.as_number()? as usize, mismatch.get(1)?.to_text()?))
+    });
+    let error = json.get("error").and_then(|error| error.as_array()).and_then(|error| {
+      let kind = error.first()?.to_text()?;
+      let message = error.get(1)?.to_text()?;
+      Some(match kind.as_str() {
+        "parse" => PuzzleError::Parse(message),
+        "invalid" => PuzzleError::InvalidInput(message),
+        "nosolution" => PuzzleError::NoSolution(message),
+        "panic" => PuzzleError::Panic(message),
+        "timeout" => PuzzleError::Timeout(message.parse().unwrap_or(0)),
+        "oom" => PuzzleError::OutOfMemory(message.parse().unwrap_or(0)),
+        _ => PuzzleError::Worker(message)
+      })
+    });
+    let traces = json.get("traces").and_then(|traces| traces.as_array()).map_or(vec![], |traces| traces.iter().filter_map(|trace| {
+      let trace = trace.as_array()?;
+      let text = trace.get(1)?.to_text()?;
+      Some(if trace.first()?.to_text()? == "frame" { Trace::Frame(text) } else { Trace::Log(text) })
+    }).collect());
+    Some(PuzzleExecution {
+      input_elapsed: 0.0,
+      samples,
+      result,
+      mismatch,
+      error,
+      traces
+    })
+  }
+
+}
@@ -4,18 +4,70 @@
 // -----------------------------------------------------------------------------
 
 // Include dependencies
-use crate::PuzzleInfo;
+use crate::lib::puzzle::*;
+use crate::lib::error::*;
+use crate::lib::report::*;
+use crate::lib::image::*;
+use std::ops::RangeInclusive;
+use std::thread;
+
+/// Describes all supported startup arguments as (syntax, description) pairs
+const VARGS_USAGE: [(&str, &str); 26] = [
+  ("scaffold --year <year> --day <day>", "Generates a new day's puzzle module, input data directory and manifest tasks (skipping existing files)"),
+  ("--year <years>",          "Only executes puzzles marked with selected years, e.g. 2022, 2021-2022 (Set 0 or omit for all years)"),
+  ("--day <days>",            "Only executes puzzles marked with selected days, e.g. 5, 1-10 or 3,7,19 (Set 0 or omit for all days)"),
+  ("--index <indices>",       "Only executes puzzles marked with selected indices, e.g. 1 or 1,2 (Set 0 or omit for all indices)"),
+  ("--tag <tags>",            "Only executes puzzles marked with selected tags, e.g. puzzle,demo (Leave empty or omit for all tags)"),
+  ("--exclude-tag <tags>",    "Doesn't execute puzzles marked with any of the tags, e.g. demo"),
+  ("--input-file <path>",     "Path to the input data file, allows [:year], [:day], [:index] and [:tag] interpolation"),
+  ("--input-value <value>",   "Explicit input data (Leave empty if you want to use data from the input file instead)"),
+  ("--expect <value>",        "Expected result for the puzzle to output (Omit to use answer stored next to the input file, if any)"),
+  ("--repeat <count>",        "Number of times to run each puzzle to get more precise timing (Set 1 or omit for a single run)"),
+  ("--jobs <count>",          "Number of puzzles to execute in parallel (Set 0 for number of CPU cores, omit for 1)"),
+  ("--timeout <ms>",          "Reports a puzzle as TIMEOUT if not finished within the time limit (Omit for no limit)"),
+  ("--memory-limit <mb>",     "Reports a puzzle as OOM if it exceeds the memory budget (Omit for no limit)"),
+  ("--format <format>",       "Output format: text, json, junit or tap (Omit for text)"),
+  ("--manifest <path>",       "Path to a task manifest (aoc.json) to run all (matching) tasks from"),
+  ("--manifest-type <type>",  "Only runs manifest tasks of same type, e.g. test or solution (Omit for all types)"),
+  ("--verify",                "Runs (matching) puzzles against all inputs with expected answers stored next to them"),
+  ("--verbose",               "Outputs executing output of the puzzle to the console"),
+  ("--visualize",             "Outputs visualization frames emitted by puzzles supporting visualization"),
+  ("--playback",              "Plays visualization frames back in the terminal ([space] pause, [n] step, [+/-] speed, arrows scroll, [q] quit)"),
+  ("--export <path>",         "Saves visualization frames as numbered .png, .ppm or .svg images, allows [:year], [:day], [:index], [:tag] and [:frame] interpolation"),
+  ("--palette <colors>",      "Colors of exported frames' characters, e.g. background=0f0f23,#=ffffff,o=c2b280 (Omit for default colors)"),
+  ("--scale <pixels>",        "Size of each exported frame character, in pixels (Omit for 4)"),
+  ("--obfuscate",             "Obfuscates the final result"),
+  ("--help",                  "Outputs this help listing"),
+  ("",                        "Exit codes: 0 success, 1 result mismatch, 2 usage error, 3 no puzzle matched, 4 unreadable input")
+];
 
 /// VArgs struct
 /// 
 /// Describes puzzle by year/day/index/type
+#[derive(Clone)]
 pub struct VArgs {
-  pub puzzle: PuzzleInfo,
+  pub puzzle: PuzzleFilter,
   pub input_file: String,
   pub input_value: String,
   pub expect: String,
+  pub repeat: usize,
+  pub jobs: usize,
+  pub timeout: u64,
+  pub memory_limit: usize,
+  pub format: ReportFormat,
+  pub manifest: String,
+  pub manifest_type: String,
+  pub verify: bool,
   pub verbose: bool,
-  pub obfuscate: bool
+  pub visualize: bool,
+  pub playback: bool,
+  pub export: String,
+  pub palette: Palette,
+  pub scale: usize,
+  pub obfuscate: bool,
+  pub help: bool,
+  pub worker: bool,
+  pub scaffold: bool
 }
 /// VArgs implementation
 /// 
@@ -24,83 +76,273 @@ impl VArgs {
   /// Constructor
   /// 
   /// Processes startup arguments
-  /// * `--year`        - Only executes puzzles marked with same year  (Set 0 or omit for all years)
-  /// * `--day`         - Only executes puzzles marked with same day   (Set 0 or omit for all days)
-  /// * `--index`       - Only executes puzzles marked with same index (Set 0 or omit for all indices)
-  /// * `--tag`         - Only executes puzzles marked with same tag  (Leave empty or omit for all tags)
+  /// * `--year`        - Only executes puzzles marked with selected years   (Set 0 or omit for all years)
+  /// * `--day`         - Only executes puzzles marked with selected days    (Set 0 or omit for all days)
+  /// * `--index`       - Only executes puzzles marked with selected indices (Set 0 or omit for all indices)
+  /// * `--tag`         - Only executes puzzles marked with selected tags    (Leave empty or omit for all tags)
+  /// * `--exclude-tag` - Doesn't execute puzzles marked with any of selected tags
+  /// 
+  /// Years, days and indices can be selected as a single number (`5`), an inclusive
+  /// range (`1-10`) or a comma separated list of both (`1-3,7,19`), tags as a comma
+  /// separated list (`puzzle,demo`)
   /// * `--input-file`  - Path to the input data file (Leave empty if you want to use explicit input value)
   /// * `--input-value` - Explicit input data (Leave empty if you want to use data from the input file instead)
   /// * `--expect`      - Expected result for the puzzle to output (Leave empty or omit for no expected result)
+  /// * `--repeat`      - Number of times to run each puzzle to get more precise timing (Set 1 or omit for a single run)
+  /// * `--jobs`        - Number of puzzles to execute in parallel (Set 0 for number of CPU cores, omit for 1)
+  /// * `--timeout`     - Time limit for each puzzle in milliseconds (Omit for no limit)
+  /// * `--memory-limit` - Memory budget for each puzzle in megabytes (Omit for no limit)
+  /// * `--format`      - Output format: `text`, `json`, `junit` or `tap` (Omit for `text`)
+  /// * `--manifest`    - Path to a task manifest (`aoc.json`) to run all (matching) tasks from
+  /// * `--manifest-type` - Only runs manifest tasks of same type, e.g. `test` or `solution` (Omit for all types)
+  /// * `--verify`      - Runs puzzles against all inputs with expected answers stored next to them
   /// * `--verbose`     - Outputs executing output of the puzzle to the console
+  /// * `--visualize`   - Outputs visualization frames emitted by puzzles supporting visualization
+  /// * `--playback`    - Plays visualization frames back in the terminal, one puzzle at a time
+  /// * `--export`      - Saves visualization frames as numbered `.png`, `.ppm` or `.svg` images
+  /// * `--palette`     - Colors of exported frames' characters, as `<character>=<hex color>` pairs (Omit for default colors)
+  /// * `--scale`       - Size of each exported frame character, in pixels (Omit for 4)
   /// * `--obfuscate`   - Outputs executing output of the puzzle to the console while obfuscating "spoilers"
+  /// * `--help`        - Outputs help listing of all startup arguments
+  /// * `--worker`      - Runs as a supervised worker process (used internally with time or memory limits)
+  /// 
+  /// ... or runs a subcommand (passed as the first argument):
+  /// * `scaffold`      - Generates a new day selected by `--year` and `--day`
   /// 
   /// # Arguments
-  /// * argv: Process startup arguments
-  pub fn new (args: Vec<String>) -> VArgs {
+  /// * argv: Process startup arguments (first argument being the executable)
+  /// 
+  /// # Returns
+  /// Processed startup arguments, or an error describing the first invalid argument
+  pub fn new (args: Vec<String>) -> Result<VArgs, CliError> {
       // Initialize arguments
-      let mut year: u32           = 0;
-      let mut day:u32             = 0;
-      let mut index: u32          = 0;
-      let mut tag: String         = String::default();
+      let mut puzzle: PuzzleFilter = PuzzleFilter::default();
       let mut input_file: String  = String::default();
       let mut input_value: String = String::default();
       let mut expect: String      = String::default();
+      let mut repeat: usize       = 1;
+      let mut jobs: usize         = 1;
+      let mut timeout: u64        = 0;
+      let mut memory_limit: usize = 0;
+      let mut format: ReportFormat = ReportFormat::Text;
+      let mut manifest: String    = String::default();
+      let mut manifest_type: String = String::default();
+      let mut verify: bool        = false;
       let mut verbose: bool       = false;
+      let mut visualize: bool     = false;
+      let mut playback: bool      = false;
+      let mut export: String      = String::default();
+      let mut palette: Palette    = Palette::default();
+      let mut scale: usize        = 4;
       let mut obfuscate: bool     = false;
+      let mut help: bool          = false;
+      let mut worker: bool        = false;
+      let mut scaffold: bool      = false;
 
-    // Process arguments
-    for i in 0..args.len() {
-      // Get year argument
-      if (args[i] == "--year") && (args.len() > i) {
-        year = args[i + 1].parse::<u32>().expect("Failed parsing '--year' parameter - expecting a positive, whole number!");
-      }
-      // Get day argument
-      if (args[i] == "--day") && (args.len() > i) {
-        day = args[i + 1].parse::<u32>().expect("Failed parsing '--day' parameter - expecting a positive, whole number!");
-      }
-      // Get index argument
-      if (args[i] == "--index") && (args.len() > i) {
-        index = args[i + 1].parse::<u32>().expect("Failed parsing '--index' parameter - expecting a positive, whole number!");
-      }
-      // Get key argument
-      if (args[i] == "--tag") && (args.len() > i) {
-        tag = args[i + 1].trim().to_string();
-      }
-      // Get input data file path argument
-      if (args[i] == "--input-file") &&  (args.len() > i) {
-        input_file = args[i + 1].trim().to_string();
-      }
-      // Get input data explicit value argument
-      if (args[i] == "--input-value") &&  (args.len() > i) {
-        input_value = args[i + 1].trim().to_string();
-      }
-      // Get expected result argument
-      if (args[i] == "--expect") &&  (args.len() > i) {
-        expect = args[i + 1].trim().to_string();
-      }
-      // Get verbose argument
-      if args[i] == "--verbose" {
-        verbose = true;
-      }
-      // Get obfuscate argument
-      if args[i] == "--obfuscate" {        
-        obfuscate = true;
+    // Process arguments (skipping the executable)
+    let mut i = 1;
+    while i < args.len() {
+      match args[i].as_str() {
+        // Get year argument
+        "--year" => {
+          puzzle.years = VArgs::parse_selection(&args, i)?;
+          i += 1;
+        },
+        // Get day argument
+        "--day" => {
+          puzzle.days = VArgs::parse_selection(&args, i)?;
+          i += 1;
+        },
+        // Get index argument
+        "--index" => {
+          puzzle.indices = VArgs::parse_selection(&args, i)?;
+          i += 1;
+        },
+        // Get key argument
+        "--tag" => {
+          let tags = PuzzleFilter::parse_tags(VArgs::parse_value(&args, i)?);
+          puzzle.tags = if tags.is_empty() { None } else { Some(tags) };
+          i += 1;
+        },
+        // Get excluded key argument
+        "--exclude-tag" => {
+          puzzle.exclude_tags = PuzzleFilter::parse_tags(VArgs::parse_value(&args, i)?);
+          i += 1;
+        },
+        // Get input data file path argument
+        "--input-file" => {
+          input_file = VArgs::parse_value(&args, i)?.trim().to_string();
+          i += 1;
+        },
+        // Get input data explicit value argument
+        "--input-value" => {
+          input_value = VArgs::parse_value(&args, i)?.trim().to_string();
+          i += 1;
+        },
+        // Get expected result argument
+        "--expect" => {
+          expect = VArgs::parse_value(&args, i)?.trim().to_string();
+          i += 1;
+        },
+        // Get repeat argument
+        "--repeat" => {
+          repeat = VArgs::parse_number(&args, i)?;
+          i += 1;
+        },
+        // Get jobs argument
+        "--jobs" => {
+          jobs = VArgs::parse_number(&args, i)?;
+          if jobs == 0 {
+            jobs = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+          }
+          i += 1;
+        },
+        // Get timeout argument
+        "--timeout" => {
+          timeout = VArgs::parse_number(&args, i)?;
+          i += 1;
+        },
+        // Get memory limit argument
+        "--memory-limit" => {
+          memory_limit = VArgs::parse_number(&args, i)?;
+          i += 1;
+        },
+        // Get output format argument
+        "--format" => {
+          let value = VArgs::parse_value(&args, i)?;
+          format = ReportFormat::parse(value)
+            .ok_or_else(|| CliError::InvalidValue(args[i].clone(), value.to_string(), String::from("one of: text, json, junit, tap")))?;
+          i += 1;
+        },
+        // Get manifest path argument
+        "--manifest" => {
+          manifest = VArgs::parse_value(&args, i)?.trim().to_string();
+          i += 1;
+        },
+        // Get manifest task type argument
+        "--manifest-type" => {
+          manifest_type = VArgs::parse_value(&args, i)?.trim().to_string();
+          i += 1;
+        },
+        // Get verify argument
+        "--verify" => verify = true,
+        // Get verbose argument
+        "--verbose" => verbose = true,
+        // Get visualize argument
+        "--visualize" => visualize = true,
+        // Get playback argument
+        "--playback" => playback = true,
+        // Get visualization export path argument
+        "--export" => {
+          let value = VArgs::parse_value(&args, i)?.trim();
+          if ImageFormat::from_path(value).is_none() {
+            return Err(CliError::InvalidValue(args[i].clone(), value.to_string(), String::from("a path ending in .png, .ppm or .svg")));
+          }
+          export = value.to_string();
+          i += 1;
+        },
+        // Get visualization export palette argument
+        "--palette" => {
+          let value = VArgs::parse_value(&args, i)?;
+          palette = Palette::parse(value).map_err(|expected| CliError::InvalidValue(args[i].clone(), value.to_string(), expected))?;
+          i += 1;
+        },
+        // Get visualization export scale argument
+        "--scale" => {
+          scale = VArgs::parse_number(&args, i)?;
+          i += 1;
+        },
+        // Get obfuscate argument
+        "--obfuscate" => obfuscate = true,
+        // Get help argument
+        "--help" | "-h" => help = true,
+        // Get worker argument
+        "--worker" => worker = true,
+        // Get scaffold subcommand
+        "scaffold" if i == 1 => scaffold = true,
+        // Unknown arguments
+        arg if arg.starts_with("--") => return Err(CliError::UnknownFlag(arg.to_string())),
+        arg => return Err(CliError::UnexpectedArgument(arg.to_string()))
       }
+      i += 1;
     }
 
     // Return processed arguments
-    VArgs {
-      puzzle: PuzzleInfo {
-        year,
-        day,
-        index,
-        tag
-      },
+    Ok(VArgs {
+      puzzle,
       input_file,
       input_value,
       expect,
+      repeat,
+      jobs,
+      timeout,
+      memory_limit,
+      format,
+      manifest,
+      manifest_type,
+      verify,
       verbose,
-      obfuscate
+      visualize,
+      playback,
+      export,
+      palette,
+      scale,
+      obfuscate,
+      help,
+      worker,
+      scaffold
+    })
+  }
+
+  /// Composes a help listing of all startup arguments
+  /// 
+  /// # Returns
+  /// Help listing text
+  pub fn usage () -> String {
+    let width = VARGS_USAGE.iter().map(|(syntax, _)| syntax.len()).max().unwrap_or(0);
+    let mut lines: Vec<String> = vec![String::from("Usage: adventofcode [arguments]"), String::default()];
+    for (syntax, description) in VARGS_USAGE.iter() {
+      if !syntax.is_empty() {
+        lines.push(format!("  {:width$}  {}", syntax, description, width = width));
+      } else {
+        lines.push(String::default());
+        lines.push(description.to_string());
+      }
     }
+    lines.join("\n")
+  }
+
+  /// Gets value of an argument following a flag
+  /// 
+  /// # Arguments
+  /// * args: Process startup arguments
+  /// * i:    Index of the flag
+  fn parse_value (args: &[String], i: usize) -> Result<&str, CliError> {
+    match args.get(i + 1) {
+      Some(value) => Ok(value.as_str()),
+      None => Err(CliError::MissingValue(args[i].clone()))
+    }
+  }
+
+  /// Gets year/day/index selection value of an argument following a flag
+  /// 
+  /// # Arguments
+  /// * args: Process startup arguments
+  /// * i:    Index of the flag
+  fn parse_selection (args: &[String], i: usize) -> Result<Option<Vec<RangeInclusive<u32>>>, CliError> {
+    let value = VArgs::parse_value(args, i)?;
+    PuzzleFilter::parse_numbers(value)
+      .map_err(|expected| CliError::InvalidValue(args[i].clone(), value.to_string(), expected))
+  }
+
+  /// Gets numeric value of an argument following a flag
+  /// 
+  /// # Arguments
+  /// * args: Process startup arguments
+  /// * i:    Index of the flag
+  fn parse_number<T: std::str::FromStr> (args: &[String], i: usize) -> Result<T, CliError> {
+    let value = VArgs::parse_value(args, i)?;
+    value.trim().parse::<T>()
+      .map_err(|_| CliError::InvalidValue(args[i].clone(), value.to_string(), String::from("a positive, whole number")))
   }
 }
@@ -6,80 +6,316 @@
 #![allow(special_module_name)]
 mod lib;
 
-// Load puzzles
+// Load puzzles (all ./yearYYYY directories)
 mod demo;
 mod year;
-mod year2021;
-mod year2022;
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
 
 // Include dependencies
 use std::env;
-use std::time::Instant;
+use std::ops::RangeInclusive;
+use std::process;
+use lib::error::*;
 use lib::vargs::*;
-use lib::input::*;
+use lib::stdout::*;
 use lib::puzzle::*;
+use lib::report::*;
+use lib::manifest::*;
+use lib::supervisor::*;
+use lib::input::*;
+use lib::scaffold::*;
+use lib::playback::*;
+
+/// Path to the directory holding input data files (and their expected answers) of a puzzle,
+/// searched when verifying puzzles
+const VERIFY_DATA_DIR: &str = "./src/year[:year]/data/day[:day]";
+
+/// Path to the task manifest new days' tasks are added to when scaffolding
+const SCAFFOLD_MANIFEST: &str = "./aoc.json";
+/// Path to the source directory new days are generated in when scaffolding
+const SCAFFOLD_SRC_DIR: &str = "./src";
 
 /// Program entry point
 /// 
 /// # Can be run with startup arguments
-/// * `--year`        - Only executes puzzles marked with same year  (Set 0 or omit for all years)
-/// * `--day`         - Only executes puzzles marked with same day   (Set 0 or omit for all days)
-/// * `--index`       - Only executes puzzles marked with same index (Set 0 or omit for all indices)
-/// * `--tag`        - Only executes puzzles marked with same tag  (Leave empty or omit for all tags)
+/// * `--year`        - Only executes puzzles marked with selected years   (Set 0 or omit for all years)
+/// * `--day`         - Only executes puzzles marked with selected days    (Set 0 or omit for all days)
+/// * `--index`       - Only executes puzzles marked with selected indices (Set 0 or omit for all indices)
+/// * `--tag`         - Only executes puzzles marked with selected tags    (Leave empty or omit for all tags)
+/// * `--exclude-tag` - Doesn't execute puzzles marked with any of selected tags
 /// * `--input-file`  - Path to the input data file (Leave empty if you want to use explicit input value)
 /// * `--input-value` - Explicit input data (Leave empty if you want to use data from the input file instead)
 /// * `--expect`      - Expected result for the puzzle to output (Leave empty or omit for no expected result)
+/// * `--repeat`      - Number of times to run each puzzle to get more precise timing (Set 1 or omit for a single run)
+/// * `--jobs`        - Number of puzzles to execute in parallel (Set 0 for number of CPU cores, omit for 1)
+/// * `--timeout`     - Time limit for each puzzle in milliseconds (Omit for no limit)
+/// * `--memory-limit` - Memory budget for each puzzle in megabytes (Omit for no limit)
+/// * `--format`      - Output format: `text`, `json`, `junit` or `tap` (Omit for `text`)
+/// * `--manifest`    - Path to a task manifest (`aoc.json`) to run all (matching) tasks from
+/// * `--manifest-type` - Only runs manifest tasks of same type, e.g. `test` or `solution` (Omit for all types)
+/// * `--verify`      - Runs puzzles against all inputs with expected answers stored next to them
 /// * `--verbose`     - If any output apart from he result should be displayed
+/// * `--visualize`   - If visualization frames emitted by puzzles should be displayed
+/// * `--playback`    - If visualization frames emitted by puzzles should be played back in the terminal
+/// * `--export`      - Path to save visualization frames emitted by puzzles to, as numbered images
+/// * `--palette`     - Colors of exported visualization frames' characters
+/// * `--scale`       - Size of each exported visualization frame character, in pixels
 /// * `--obfuscate`   - If the final result should be obfuscated
+/// * `--help`        - Outputs help listing of all startup arguments
+/// 
+/// # Can run subcommands
+/// * `scaffold --year <year> --day <day>` - Generates a new day's puzzle module, input data directory and manifest tasks
+/// 
+/// # Exits with code
+/// * `0` - All executed puzzles succeeded (or had no expected result)
+/// * `1` - At least one puzzle's result didn't match the expected result
+/// * `2` - Startup arguments were invalid
+/// * `3` - No registered puzzle matched the puzzle selection
+/// * `4` - Input data (or manifest) could not be read
 fn main() {
   
   // Get arguments
-  let args = VArgs::new(env::args().collect());
+  let args = match VArgs::new(env::args().collect()) {
+    Ok(args) => args,
+    Err(err) => {
+      eprintln!("{}", err);
+      eprintln!("Run with '--help' for a listing of all available arguments.");
+      process::exit(err.exit_code());
+    }
+  };
+
+  // Output help listing
+  if args.help {
+    println!("{}", VArgs::usage());
+    process::exit(ExitCode::SUCCESS);
+  }
+
+  // Scaffold a new day
+  if args.scaffold {
+    process::exit(scaffold(&args));
+  }
+
+  // Check playback has an interactive terminal to play back in
+  if args.playback && !Playback::is_supported() {
+    eprintln!("Playback requires an interactive terminal (both STDIN and STDOUT)!");
+    process::exit(ExitCode::USAGE);
+  }
 
   // Register puzzles
   let mut registry = PuzzleRegistry::new();
   // Register demo puzzle
   registry = demo::init(registry);
-  // Register 2021 puzzles
-  if args.puzzle.year == 0 || args.puzzle.year == 2021 {
-    registry = year2021::init(registry);
-  }
-  // Register 2022 puzzles
-  if args.puzzle.year == 0 || args.puzzle.year == 2022 {
-    registry = year2022::init(registry);
+  // Register puzzles of all selected years
+  registry = register_years(registry, &args.puzzle);
+
+  // Run as a supervised worker process
+  if args.worker {
+    process::exit(Supervisor::serve(&registry, &args));
   }
 
-  // Run all puzzles just for fun
-  for i in 0..registry.puzzles.values().len() {
-    // Fetch puzzle
-    let info = &registry.puzzles.keys().nth(i).unwrap();
-    let f = registry.puzzles.values().nth(i).unwrap();
-    // Check if puzzle matches startup criteria
-    if args.puzzle.matches(info) {
-      // Time function execution
-      let start_input = Instant::now();
-      // Load puzzle input
-      let input = if !args.input_value.is_empty() {
-          args.input_value.clone()
+  // Initialize execution report
+  let mut report = Report::new(args.format);
+
+  // Run all tasks from a manifest
+  if !args.manifest.is_empty() {
+    let manifest = match Manifest::load(&args.manifest) {
+      Ok(manifest) => manifest,
+      Err(err) => {
+        eprintln!("{}", err);
+        process::exit(ExitCode::INPUT);
+      }
+    };
+    let mut tasks: Vec<(String, String, Result<VArgs, String>)> = vec![];
+    for task in manifest.tasks.iter() {
+      // Check if task matches startup criteria
+      if !args.manifest_type.is_empty() && task.kind() != args.manifest_type {
+        continue;
+      }
+      let mut task_args = match VArgs::new([vec![String::default()], task.expand_args(args.verbose)].concat()) {
+        Ok(task_args) => task_args,
+        Err(err) => {
+          tasks.push((task.name.clone(), task.task_type.clone(), Err(err.to_string())));
+          continue;
         }
-        else if !args.input_file.is_empty() {
-          let year  = format!("{:0>4}", info.year);
-          let day   = format!("{:0>2}", info.day);
-          let index = format!("{:0>2}", info.index);
-          let tag   = info.tag.as_str();
-          Input::read_file(
-            &args.input_file
-              .replace("[:year]",  year.to_string().as_str())
-              .replace("[:day]",   day.to_string().as_str())
-              .replace("[:index]", index.to_string().as_str())
-              .replace("[:tag]",   tag)
-          )
+      };
+      // Narrow task's puzzle selection by startup criteria (skipping tasks with nothing left to run)
+      let puzzle = task_args.puzzle.intersect(&args.puzzle);
+      if registry.count(&puzzle) == 0 && !args.puzzle.is_unrestricted() {
+        continue;
+      }
+      task_args.puzzle = puzzle;
+      // Inherit output arguments
+      task_args.input_file = manifest.resolve_path(&task_args.input_file);
+      task_args.repeat = args.repeat;
+      task_args.jobs = args.jobs;
+      task_args.timeout = args.timeout;
+      task_args.memory_limit = args.memory_limit;
+      task_args.format = args.format;
+      task_args.visualize = args.visualize;
+      task_args.playback = args.playback;
+      task_args.export = args.export.clone();
+      task_args.palette = args.palette.clone();
+      task_args.scale = args.scale;
+      task_args.obfuscate = args.obfuscate;
+      tasks.push((task.name.clone(), task.task_type.clone(), Ok(task_args)));
+    }
+    // Run all tasks together (sharing a pool of worker threads)
+    let runs: Vec<&(String, String, Result<VArgs, String>)> = tasks.iter().filter(|(_, _, task_args)| task_args.is_ok()).collect();
+    let mut outcomes = registry.run_all(&runs.iter().map(|(_, _, task_args)| task_args.clone().unwrap()).collect::<Vec<VArgs>>(), args.jobs, &mut report, |run| {
+      if args.verbose && args.format == ReportFormat::Text {
+        StdOut::println(String::default(), None);
+        StdOut::println(format!("Running task {} ({}):", runs[run].0, runs[run].1), None);
+      }
+    }).into_iter();
+    let mut failed_tasks: Vec<(String, String)> = vec![];
+    let executed_tasks: usize = tasks.len();
+    for (name, _, task_args) in tasks {
+      let outcome = match task_args {
+        Err(err) => Err(err),
+        Ok(_) => outcomes.next().unwrap().map_err(|err| err.to_string())
+      };
+      match outcome {
+        Err(err) => failed_tasks.push((name, err)),
+        Ok(records) if records.is_empty() => failed_tasks.push((name, String::from("No registered puzzle matched the task"))),
+        Ok(records) => if report.records[records].iter().any(|record| record.failed()) {
+          failed_tasks.push((name, String::from("Result didn't match the expected value")));
         }
-        else {
-          String::default()
+      }
+    }
+    // Output structured report and summary
+    report.print();
+    let summary = format!("Manifest \"{}\": {} tasks executed, {} passed, {} failed", args.manifest, executed_tasks, executed_tasks - failed_tasks.len(), failed_tasks.len());
+    exit_with_summary(summary, &failed_tasks, executed_tasks, args.format);
+  }
+
+  // Verify all (matching) puzzles against all inputs with known answers
+  if args.verify {
+    let mut names: Vec<String> = vec![];
+    let mut runs: Vec<VArgs> = vec![];
+    for info in registry.puzzles.keys().filter(|info| args.puzzle.matches(info)) {
+      // Find puzzle's input data files
+      let inputs = if !args.input_file.is_empty() {
+        vec![PuzzleRegistry::input_path(info, &args.input_file)]
+      } else {
+        Input::list_inputs(&PuzzleRegistry::input_path(info, VERIFY_DATA_DIR))
+      };
+      for input_file in inputs {
+        // Skip inputs without a known answer for the puzzle
+        let expect = match Input::read_answer(&input_file, info.index) {
+          Some(expect) => expect,
+          None => continue
         };
-      // Run puzzle
-      PuzzleRegistry::execute(info, start_input, f, input, &args);
+        names.push(format!("{:04}/{:02}.{} ({}) with {}", info.year, info.day, info.index, info.tag, input_file));
+        let mut run_args = args.clone();
+        run_args.puzzle = PuzzleFilter::only(info);
+        run_args.input_file = input_file;
+        run_args.input_value = String::default();
+        run_args.expect = expect;
+        runs.push(run_args);
+      }
+    }
+    // Run all puzzles against all inputs together (sharing a pool of worker threads)
+    let outcomes = registry.run_all(&runs, args.jobs, &mut report, |run| {
+      if args.verbose && args.format == ReportFormat::Text {
+        StdOut::println(String::default(), None);
+        StdOut::println(format!("Verifying puzzle {}:", names[run]), None);
+      }
+    });
+    let mut failed_runs: Vec<(String, String)> = vec![];
+    let executed_runs: usize = runs.len();
+    for (name, outcome) in names.iter().zip(outcomes) {
+      match outcome {
+        Err(err) => failed_runs.push((name.clone(), err.to_string())),
+        Ok(records) => if report.records[records].iter().any(|record| record.failed()) {
+          failed_runs.push((name.clone(), String::from("Result didn't match the expected answer")));
+        }
+      }
+    }
+    // Output structured report and summary
+    report.print();
+    let summary = format!("Verified {} known answers: {} passed, {} failed", executed_runs, executed_runs - failed_runs.len(), failed_runs.len());
+    exit_with_summary(summary, &failed_runs, executed_runs, args.format);
+  }
+
+  // Run all puzzles just for fun (in year/day/index/tag order)
+  let result = registry.run(&args, &mut report);
+
+  // Output structured report
+  report.print();
+
+  // Exit with a code describing the outcome
+  match result {
+    Err(err) => {
+      eprintln!("{}", err);
+      process::exit(err.exit_code());
+    },
+    Ok(0) => {
+      eprintln!("No registered puzzle matched the puzzle selection!");
+      process::exit(ExitCode::NO_MATCH);
+    },
+    Ok(_) => if report.records.iter().any(|record| record.failed()) {
+      process::exit(ExitCode::MISMATCH);
+    }
+  }
+}
+
+/// Outputs a summary of executed tasks and exits with a code describing the outcome
+/// 
+/// # Arguments
+/// * summary:  Summary line
+/// * failed:   Names of failed tasks with reasons for failing
+/// * executed: Number of executed tasks
+/// * format:   Output format
+fn exit_with_summary (summary: String, failed: &[(String, String)], executed: usize, format: ReportFormat) -> ! {
+  if format == ReportFormat::Text {
+    StdOut::println(String::default(), None);
+    for (name, reason) in failed.iter() {
+      StdOut::println(format!("Failed task {}: {}", name, reason), Some(StdOutColoring::INVALID));
+    }
+    StdOut::println(summary, Some(if failed.is_empty() { StdOutColoring::VALID } else { StdOutColoring::INVALID }));
+  } else {
+    eprintln!("{}", summary);
+  }
+  process::exit(
+    if executed == 0 { ExitCode::NO_MATCH }
+    else if failed.is_empty() { ExitCode::SUCCESS }
+    else { ExitCode::MISMATCH }
+  );
+}
+
+/// Generates a new day selected by a single `--year` and a single `--day`
+/// 
+/// # Arguments
+/// * args: Startup arguments
+/// 
+/// # Returns
+/// Process exit code
+fn scaffold (args: &VArgs) -> i32 {
+  // Get selected year and day
+  let single = |selection: &Option<Vec<RangeInclusive<u32>>>| match selection {
+    Some(ranges) if ranges.len() == 1 && ranges[0].start() == ranges[0].end() => Some(*ranges[0].start()),
+    _ => None
+  };
+  let (year, day) = match (single(&args.puzzle.years), single(&args.puzzle.days)) {
+    (Some(year), Some(day)) if (1..=25).contains(&day) => (year, day),
+    _ => {
+      eprintln!("Scaffolding requires a single '--year' and a single '--day' (1-25)!");
+      return ExitCode::USAGE;
+    }
+  };
+
+  // Generate day
+  let scaffold = Scaffold { year, day, src: String::from(SCAFFOLD_SRC_DIR), manifest: String::from(SCAFFOLD_MANIFEST) };
+  match scaffold.generate() {
+    Ok(log) => {
+      for line in log {
+        println!("{}", line);
+      }
+      ExitCode::SUCCESS
+    },
+    Err(err) => {
+      eprintln!("{}", err);
+      ExitCode::INPUT
     }
   }
 }
@@ -4,6 +4,7 @@
 // -----------------------------------------------------------------------------
 
 // Include dependencies
+use std::collections::HashSet;
 use crate::year::lib::matrix::*;
 
 /// Dot display struct
@@ -34,37 +35,42 @@ impl DotDisplay {
     points.iter().map(|c| ((c.0 + offset.0) as usize, (c.1 + offset.1) as usize)).collect::<Vec<(usize, usize)>>()
   }
 
-  /// Print points to display
+  /// Renders points as text
   /// 
   /// # Arguments
   /// * points: Vector of points to display
-  pub fn print_binary (points: Vec<(usize, usize)>) {
-    DotDisplay::print(points, |_, _| '#', ());
+  /// 
+  /// # Returns
+  /// Rendered display, one line per row
+  pub fn render_binary (points: Vec<(usize, usize)>) -> String {
+    DotDisplay::render(points, |_, _| '#', ())
   }
 
-  /// Print points to display
+  /// Renders points as text
   /// 
   /// # Arguments
   /// * points: Vector of points to display
   /// * callback: Callback which returns a character to represent any coordinates
   /// * reference: Reference value passed to all calls of the callback function
-  pub fn print<T> (points: Vec<(usize, usize)>, callback: fn(&T, (usize, usize)) -> char, reference: T) {
+  /// 
+  /// # Returns
+  /// Rendered display, one line per row
+  pub fn render<T> (points: Vec<(usize, usize)>, callback: fn(&T, (usize, usize)) -> char, reference: T) -> String {
     // Find max coordinates
     let max: (usize, usize) = (
-      points.iter().map(|p| p.0).collect::<Vec<usize>>().iter().max().unwrap().clone(),
-      points.iter().map(|p| p.1).collect::<Vec<usize>>().iter().max().unwrap().clone()
+      points.iter().map(|p| p.0).max().unwrap(),
+      points.iter().map(|p| p.1).max().unwrap()
     );
     let min: (usize, usize) = (
-      points.iter().map(|p| p.0).collect::<Vec<usize>>().iter().min().unwrap().clone(),
-      points.iter().map(|p| p.1).collect::<Vec<usize>>().iter().min().unwrap().clone()
+      points.iter().map(|p| p.0).min().unwrap(),
+      points.iter().map(|p| p.1).min().unwrap()
     );
-    // Print out display
-    for y in min.1..(max.1 + 1) {
-      for x in min.0..(max.0 + 1) {
-        print!("{}", if points.iter().find(|p| p.0 == x && p.1 == y) != None { callback(&reference, (x, y)) } else { '.' });
-      }
-      println!();
-    }
+    // Render display
+    let points: HashSet<(usize, usize)> = points.into_iter().collect();
+    (min.1..(max.1 + 1))
+      .map(|y| (min.0..(max.0 + 1)).map(|x| if points.contains(&(x, y)) { callback(&reference, (x, y)) } else { '.' }).collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n")
   }
 
   /// Print matrix to display
@@ -73,14 +79,22 @@ impl DotDisplay {
   /// * points: Vector of points to display
   #[allow(dead_code)]
   pub fn print_2d_matrix<T> (matrix: &Matrix, vector: &Vec<T>) where T: std::fmt::Display {
-    // Print out display
-    for y in 0..matrix.dimensions[1] {
-      for x in 0..matrix.dimensions[0] {
-        print!("{}", vector[matrix.coords_to_index(&vec![x, y]).unwrap()]);
-      }
-      println!();
-    }
+    println!("{}", DotDisplay::render_2d_matrix(matrix, vector));
   }
 
+  /// Renders matrix as text
+  /// 
+  /// # Arguments
+  /// * matrix: Matrix describing dimensions of the data
+  /// * vector: Matrix backed data to display
+  /// 
+  /// # Returns
+  /// Rendered display, one line per row
+  pub fn render_2d_matrix<T> (matrix: &Matrix, vector: &[T]) -> String where T: std::fmt::Display {
+    (0..matrix.dimensions[1])
+      .map(|y| (0..matrix.dimensions[0]).map(|x| vector[matrix.coords_to_index(&vec![x, y]).unwrap()].to_string()).collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
 
 }
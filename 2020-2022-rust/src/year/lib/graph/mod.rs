@@ -0,0 +1,367 @@
+//! Graph module
+//! 
+//! Generic shortest path searches over graphs described by a neighbours callback
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// Search result struct
+/// 
+/// Holds distances to all nodes reached by a search, together with their predecessors
+/// on the shortest path, allowing for any of the shortest paths to be reconstructed
+pub struct Search<N> {
+  /// Distances from the start to all reached nodes
+  pub distances: HashMap<N, usize>,
+  /// Preceding node on the shortest path to each reached node (start node has none)
+  pub previous: HashMap<N, N>,
+  /// First reached node matching the target condition, if any
+  pub target: Option<N>
+}
+/// Search result implementation
+impl<N: Eq + Hash + Clone> Search<N> {
+
+  /// Gets distance from the start to a node
+  /// 
+  /// # Arguments
+  /// * node: Node to get distance to
+  /// 
+  /// # Returns
+  /// Distance to the node, or `None` if node was never reached
+  pub fn distance (&self, node: &N) -> Option<usize> {
+    self.distances.get(node).copied()
+  }
+
+  /// Gets distance from the start to the reached target node
+  /// 
+  /// # Returns
+  /// Distance to the target node, or `None` if target was never reached
+  pub fn target_distance (&self) -> Option<usize> {
+    self.target.as_ref().and_then(|target| self.distance(target))
+  }
+
+  /// Reconstructs the shortest path from the start to a node
+  /// 
+  /// # Arguments
+  /// * node: Node to reconstruct path to
+  /// 
+  /// # Returns
+  /// Nodes along the path, starting with the start node and ending with the requested node,
+  /// or `None` if node was never reached
+  pub fn path (&self, node: &N) -> Option<Vec<N>> {
+    if !self.distances.contains_key(node) {
+      return None;
+    }
+    let mut path: Vec<N> = vec![node.clone()];
+    while let Some(previous) = self.previous.get(path.last().unwrap()) {
+      path.push(previous.clone());
+    }
+    path.reverse();
+    Some(path)
+  }
+
+  /// Reconstructs the shortest path from the start to the reached target node
+  pub fn target_path (&self) -> Option<Vec<N>> {
+    self.target.as_ref().and_then(|target| self.path(target))
+  }
+
+}
+
+/// Graph struct
+pub struct Graph {}
+/// Graph implementation
+/// 
+/// All searches take a starting node and a callback listing neighbours of any node, so that
+/// graphs never need to be fully constructed up front. Searches stop as soon as a node matching
+/// the target condition is reached; pass `|_| false` as the target condition to reach all nodes.
+impl Graph {
+
+  /// Breadth first search over a graph where every step costs 1
+  /// 
+  /// # Arguments
+  /// * start:      Starting node
+  /// * neighbours: Callback listing nodes reachable from a node in a single step
+  /// * is_target:  Callback checking if a node is the target node
+  /// 
+  /// # Returns
+  /// Distances to and shortest paths to all reached nodes
+  pub fn bfs<N, I, F, T> (start: N, mut neighbours: F, mut is_target: T) -> Search<N>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = N>, F: FnMut(&N) -> I, T: FnMut(&N) -> bool {
+    // Initialize search
+    let mut search = Search { distances: HashMap::new(), previous: HashMap::new(), target: None };
+    search.distances.insert(start.clone(), 0);
+    if is_target(&start) {
+      search.target = Some(start);
+      return search;
+    }
+    // Expand nodes layer by layer
+    let mut layer: Vec<N> = vec![start];
+    let mut distance = 0;
+    while !layer.is_empty() {
+      distance += 1;
+      let mut next: Vec<N> = vec![];
+      for node in layer {
+        for neighbour in neighbours(&node) {
+          // Skip already reached nodes
+          if search.distances.contains_key(&neighbour) {
+            continue;
+          }
+          // Reach node
+          search.distances.insert(neighbour.clone(), distance);
+          search.previous.insert(neighbour.clone(), node.clone());
+          if is_target(&neighbour) {
+            search.target = Some(neighbour);
+            return search;
+          }
+          next.push(neighbour);
+        }
+      }
+      layer = next;
+    }
+    search
+  }
+
+  /// Dijkstra's search over a graph with (non negative) weighted steps
+  /// 
+  /// # Arguments
+  /// * start:      Starting node
+  /// * neighbours: Callback listing nodes reachable from a node in a single step, together with the cost of the step
+  /// * is_target:  Callback checking if a node is the target node
+  /// 
+  /// # Returns
+  /// Distances to and shortest paths to all reached nodes
+  pub fn dijkstra<N, I, F, T> (start: N, neighbours: F, is_target: T) -> Search<N>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = (N, usize)>, F: FnMut(&N) -> I, T: FnMut(&N) -> bool {
+    Graph::a_star(start, neighbours, |_| 0, is_target)
+  }
+
+  /// A* search over a graph with (non negative) weighted steps
+  /// 
+  /// # Arguments
+  /// * start:      Starting node
+  /// * neighbours: Callback listing nodes reachable from a node in a single step, together with the cost of the step
+  /// * heuristic:  Callback estimating remaining distance from a node to the target (must never overestimate, but
+  ///   needn't be consistent: nodes are expanded again whenever a shorter path to them is found)
+  /// * is_target:  Callback checking if a node is the target node
+  /// 
+  /// # Returns
+  /// Distances to and shortest paths to all reached nodes (only distance to the target is guaranteed to be shortest
+  /// if heuristic isn't constantly 0)
+  pub fn a_star<N, I, F, H, T> (start: N, mut neighbours: F, mut heuristic: H, mut is_target: T) -> Search<N>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = (N, usize)>, F: FnMut(&N) -> I, H: FnMut(&N) -> usize, T: FnMut(&N) -> bool {
+    // Initialize search
+    let mut search = Search { distances: HashMap::new(), previous: HashMap::new(), target: None };
+    let mut queue: BinaryHeap<Queued<N>> = BinaryHeap::new();
+    search.distances.insert(start.clone(), 0);
+    queue.push(Queued { priority: heuristic(&start), distance: 0, node: start });
+    // Expand nodes in order of (estimated) total distance
+    while let Some(Queued { distance, node, .. }) = queue.pop() {
+      // Skip nodes since reached via a shorter path
+      if distance > search.distances[&node] {
+        continue;
+      }
+      if is_target(&node) {
+        search.target = Some(node);
+        return search;
+      }
+      // Relax steps to all neighbours
+      for (neighbour, cost) in neighbours(&node) {
+        let neighbour_distance = distance + cost;
+        if neighbour_distance < *search.distances.get(&neighbour).unwrap_or(&usize::MAX) {
+          search.distances.insert(neighbour.clone(), neighbour_distance);
+          search.previous.insert(neighbour.clone(), node.clone());
+          queue.push(Queued { priority: neighbour_distance + heuristic(&neighbour), distance: neighbour_distance, node: neighbour });
+        }
+      }
+    }
+    search
+  }
+
+  /// Floyd–Warshall calculation of distances between all pairs of nodes
+  /// 
+  /// # Arguments
+  /// * nodes:      All nodes of the graph
+  /// * neighbours: Callback listing nodes reachable from a node in a single step, together with the cost of the step
+  /// 
+  /// # Returns
+  /// Distances between all pairs of nodes, indexed by position in `nodes` (`usize::MAX` if unreachable)
+  pub fn floyd_warshall<N, I, F> (nodes: &[N], mut neighbours: F) -> Vec<Vec<usize>>
+    where N: Eq + Hash, I: IntoIterator<Item = (N, usize)>, F: FnMut(&N) -> I {
+    // Initialize distances with direct steps
+    let indexes: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, node)| (node, i)).collect();
+    let mut distances: Vec<Vec<usize>> = vec![vec![usize::MAX; nodes.len()]; nodes.len()];
+    for (i, node) in nodes.iter().enumerate() {
+      distances[i][i] = 0;
+      for (neighbour, cost) in neighbours(node) {
+        if let Some(j) = indexes.get(&neighbour) {
+          distances[i][*j] = distances[i][*j].min(cost);
+        }
+      }
+    }
+    // Shorten distances via every intermediate node
+    for k in 0..nodes.len() {
+      for i in 0..nodes.len() {
+        if distances[i][k] == usize::MAX {
+          continue;
+        }
+        for j in 0..nodes.len() {
+          if distances[k][j] != usize::MAX && distances[i][k] + distances[k][j] < distances[i][j] {
+            distances[i][j] = distances[i][k] + distances[k][j];
+          }
+        }
+      }
+    }
+    distances
+  }
+
+  /// Breadth first search through a time-expanded graph, where nodes reachable from a node
+  /// depend on the current time and every step takes a single unit of time
+  /// 
+  /// # Arguments
+  /// * start:      Starting node
+  /// * time:       Starting time
+  /// * period:     Period after which the graph repeats itself, or `0` if graph never repeats
+  ///   (states at times differing by a multiple of the period are considered the same)
+  /// * neighbours: Callback listing nodes reachable from a node at a given time, by the following unit of time
+  ///   (include the node itself to allow for waiting in place)
+  /// * is_target:  Callback checking if a node is the target node at a given time
+  /// 
+  /// # Returns
+  /// Target node and time it was reached at, or `None` if target can't be reached
+  pub fn bfs_in_time<N, I, F, T> (start: N, time: usize, period: usize, mut neighbours: F, mut is_target: T) -> Option<(N, usize)>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = N>, F: FnMut(&N, usize) -> I, T: FnMut(&N, usize) -> bool {
+    // Initialize search
+    let phase = |time: usize| if period > 0 { time % period } else { time };
+    let mut visited: HashSet<(N, usize)> = HashSet::new();
+    visited.insert((start.clone(), phase(time)));
+    // Expand all nodes reachable at each time
+    let mut time = time;
+    let mut layer: Vec<N> = vec![start];
+    while !layer.is_empty() {
+      let mut next: Vec<N> = vec![];
+      for node in layer {
+        if is_target(&node, time) {
+          return Some((node, time));
+        }
+        for neighbour in neighbours(&node, time) {
+          if visited.insert((neighbour.clone(), phase(time + 1))) {
+            next.push(neighbour);
+          }
+        }
+      }
+      layer = next;
+      time += 1;
+    }
+    None
+  }
+
+}
+
+/// Priority queue entry, ordered so that the lowest priority is popped first from a (max) binary heap
+struct Queued<N> {
+  priority: usize,
+  distance: usize,
+  node: N
+}
+impl<N> PartialEq for Queued<N> {
+  fn eq (&self, other: &Self) -> bool {
+    self.priority == other.priority && self.distance == other.distance
+  }
+}
+impl<N> Eq for Queued<N> {}
+impl<N> PartialOrd for Queued<N> {
+  fn partial_cmp (&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+impl<N> Ord for Queued<N> {
+  fn cmp (&self, other: &Self) -> Ordering {
+    // Prefer lower priority, then longer distance (closer to the target when estimates are equal)
+    other.priority.cmp(&self.priority).then(self.distance.cmp(&other.distance))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Weighted directed edges of a small graph: a cheap detour via 'b' beats the direct 'a' -> 'c' step
+  const EDGES: [(char, char, usize); 6] = [('a', 'b', 1), ('a', 'c', 5), ('b', 'c', 1), ('c', 'd', 2), ('b', 'd', 7), ('e', 'a', 1)];
+
+  /// Lists weighted steps from a node of the small graph
+  fn weighted (node: &char) -> Vec<(char, usize)> {
+    EDGES.iter().filter(|(from, _, _)| from == node).map(|(_, to, cost)| (*to, *cost)).collect()
+  }
+
+  /// Lists unweighted steps from a node of the small graph
+  fn unweighted (node: &char) -> Vec<char> {
+    weighted(node).into_iter().map(|(to, _)| to).collect()
+  }
+
+  #[test]
+  fn bfs_finds_fewest_steps () {
+    let search = Graph::bfs('a', unweighted, |node| *node == 'd');
+    assert_eq!(search.target_distance(), Some(2));
+    assert_eq!(search.target_path().unwrap().len(), 3);
+    let search = Graph::bfs('a', unweighted, |_| false);
+    assert_eq!(search.distance(&'c'), Some(1));
+    assert_eq!(search.distance(&'e'), None);
+    assert_eq!(search.target_path(), None);
+  }
+
+  #[test]
+  fn dijkstra_finds_cheapest_path () {
+    let search = Graph::dijkstra('a', weighted, |node| *node == 'd');
+    assert_eq!(search.target_distance(), Some(4));
+    assert_eq!(search.target_path(), Some(vec!['a', 'b', 'c', 'd']));
+    let search = Graph::dijkstra('a', weighted, |_| false);
+    assert_eq!(search.distance(&'c'), Some(2));
+    assert_eq!(search.path(&'e'), None);
+  }
+
+  #[test]
+  fn a_star_finds_cheapest_path_with_inconsistent_heuristic () {
+    // Heuristic is admissible (h(a) = 5 is below the remaining distance of 6), but inconsistent (h(a) exceeds the
+    // 'a' -> 'b' step cost plus h(b) = 0), so 'b' is first expanded via the expensive direct step and needs to be
+    // expanded again once the cheaper path via 'a' is found
+    let edges = |node: &char| match node {
+      's' => vec![('a', 1), ('b', 3)],
+      'a' => vec![('b', 1)],
+      'b' => vec![('g', 5)],
+      _ => vec![]
+    };
+    let heuristic = |node: &char| if *node == 'a' { 5 } else { 0 };
+    let search = Graph::a_star('s', edges, heuristic, |node| *node == 'g');
+    assert_eq!(search.target_distance(), Some(7));
+    assert_eq!(search.target_path(), Some(vec!['s', 'a', 'b', 'g']));
+    // Consistent heuristic finds the same path
+    let search = Graph::a_star('a', weighted, |node| if *node == 'd' { 0 } else { 2 }, |node| *node == 'd');
+    assert_eq!(search.target_distance(), Some(4));
+  }
+
+  #[test]
+  fn floyd_warshall_finds_all_distances () {
+    let nodes = ['a', 'b', 'c', 'd', 'e'];
+    let distances = Graph::floyd_warshall(&nodes, weighted);
+    assert_eq!(distances[0], vec![0, 1, 2, 4, usize::MAX]);
+    assert_eq!(distances[4], vec![1, 2, 3, 5, 0]);
+    assert_eq!(distances[3], vec![usize::MAX, usize::MAX, usize::MAX, 0, usize::MAX]);
+  }
+
+  #[test]
+  fn bfs_in_time_waits_for_blocked_nodes () {
+    // Walk along a line of 4 positions, position 2 being blocked at every even time
+    let blocked = |position: usize, time: usize| position == 2 && time.is_multiple_of(2);
+    let steps = |position: &usize, time: usize| {
+      let position = *position;
+      vec![position.saturating_sub(1), position, (position + 1).min(3)].into_iter().filter(move |next| !blocked(*next, time + 1))
+    };
+    assert_eq!(Graph::bfs_in_time(0, 0, 2, steps, |position, _| *position == 3), Some((3, 4)));
+    assert_eq!(Graph::bfs_in_time(0, 1, 2, steps, |position, _| *position == 3), Some((3, 4)));
+    // Permanently blocked target is never reached, even though time never stops
+    assert_eq!(Graph::bfs_in_time(0, 0, 2, |position: &usize, _| vec![*position], |position, _| *position == 3), None);
+  }
+
+}
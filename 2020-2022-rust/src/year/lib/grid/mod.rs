@@ -0,0 +1,299 @@
+//! Grid module
+//! 
+//! 2D grid of cells, owning both the matrix geometry and the cell data
+// -----------------------------------------------------------------------------
+
+// Include dependencies
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use crate::lib::input::*;
+use crate::lib::error::*;
+use crate::year::lib::matrix::*;
+
+/// Grid struct
+/// 
+/// Cell data is stored row by row, cell at (x, y) being stored at `data[y * width + x]`
+pub struct Grid<T> {
+  pub matrix: Matrix,
+  pub data: Vec<T>
+}
+/// Grid implementation
+/// 
+/// Provides construction, indexing, iteration and transformation of 2D grids
+impl<T> Grid<T> {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * width:  Width of the grid
+  /// * height: Height of the grid
+  /// * data:   Cell data, row by row (needs to hold exactly `width * height` cells)
+  pub fn new (width: usize, height: usize, data: Vec<T>) -> Grid<T> {
+    assert_eq!(data.len(), width * height, "Grid data needs to hold exactly {}x{} cells!", width, height);
+    Grid {
+      matrix: Matrix::new(vec![width, height]),
+      data
+    }
+  }
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * width:  Width of the grid
+  /// * height: Height of the grid
+  /// * value:  Value to fill all cells with
+  pub fn filled (width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+    Grid::new(width, height, vec![value; width * height])
+  }
+
+  /// Parses a grid from text, one row per (non empty) line and one cell per character (ignoring trailing whitespace)
+  /// 
+  /// # Arguments
+  /// * data: Text to parse
+  /// * f:    Callback converting a character into a cell value, or returning `None` if character isn't allowed
+  /// 
+  /// # Returns
+  /// Parsed grid, or an error pointing to the first character which isn't allowed or a row of different width
+  pub fn parse<F: Fn(char) -> Option<T>> (data: &str, f: F) -> Result<Grid<T>, ParseError> {
+    let lines: Vec<Parser> = Parser::new(data).lines().iter().map(|line| line.trim_end()).filter(|line| !line.text().is_empty()).collect();
+    let width = lines.first().map_or(0, |line| line.text().chars().count());
+    let mut cells: Vec<T> = Vec::with_capacity(width * lines.len());
+    for line in lines.iter() {
+      let row: Vec<T> = line.grid(&f)?.into_iter().flatten().collect();
+      if row.len() != width {
+        return Err(line.error(format!("Expected a row of {} cells, found {}", width, row.len())));
+      }
+      cells.extend(row);
+    }
+    Ok(Grid::new(width, lines.len(), cells))
+  }
+
+  /// Gets width of the grid
+  pub fn width (&self) -> usize {
+    self.matrix.dimensions[0]
+  }
+
+  /// Gets height of the grid
+  pub fn height (&self) -> usize {
+    self.matrix.dimensions[1]
+  }
+
+  /// Gets (x, y) coordinates of a cell from its data index
+  /// 
+  /// # Arguments
+  /// * index: Data index of the cell
+  pub fn coords (&self, index: usize) -> (usize, usize) {
+    (index % self.width(), index / self.width())
+  }
+
+  /// Gets data index of a cell from its (x, y) coordinates
+  /// 
+  /// # Arguments
+  /// * coords: Coordinates of the cell
+  /// 
+  /// # Returns
+  /// Data index of the cell, or `None` if coordinates are out of bounds
+  pub fn index (&self, coords: (usize, usize)) -> Option<usize> {
+    self.matrix.coords_to_index(&vec![coords.0, coords.1])
+  }
+
+  /// Gets a cell
+  /// 
+  /// # Arguments
+  /// * coords: (x, y) coordinates of the cell
+  /// 
+  /// # Returns
+  /// Cell value, or `None` if coordinates are out of bounds
+  pub fn get (&self, coords: (usize, usize)) -> Option<&T> {
+    self.index(coords).map(|index| &self.data[index])
+  }
+
+  /// Gets a mutable cell
+  /// 
+  /// # Arguments
+  /// * coords: (x, y) coordinates of the cell
+  /// 
+  /// # Returns
+  /// Cell value, or `None` if coordinates are out of bounds
+  pub fn get_mut (&mut self, coords: (usize, usize)) -> Option<&mut T> {
+    self.index(coords).map(move |index| &mut self.data[index])
+  }
+
+  /// Iterates over all cells, row by row, together with their (x, y) coordinates
+  pub fn iter (&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+    self.data.iter().enumerate().map(move |(index, value)| (self.coords(index), value))
+  }
+
+  /// Gets a row of cells
+  /// 
+  /// # Arguments
+  /// * y: Row to get
+  pub fn row (&self, y: usize) -> &[T] {
+    &self.data[(y * self.width())..((y + 1) * self.width())]
+  }
+
+  /// Iterates over all rows of cells
+  pub fn rows (&self) -> impl Iterator<Item = &[T]> {
+    self.data.chunks(self.width().max(1))
+  }
+
+  /// Iterates over a column of cells
+  /// 
+  /// # Arguments
+  /// * x: Column to iterate over
+  pub fn column (&self, x: usize) -> impl Iterator<Item = &T> {
+    self.data.iter().skip(x).step_by(self.width().max(1))
+  }
+
+  /// Iterates over all columns of cells
+  pub fn columns (&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+    (0..self.width()).map(move |x| self.column(x))
+  }
+
+  /// Gets coordinates of all neighbouring cells within the grid
+  /// 
+  /// # Arguments
+  /// * coords:             (x, y) coordinates of the cell to find neighbours of
+  /// * include_diagonals:  If diagonal neighbours should be included
+  pub fn neighbours (&self, coords: (usize, usize), include_diagonals: bool) -> Vec<(usize, usize)> {
+    match self.index(coords) {
+      Some(index) => self.matrix.neighbours(index, include_diagonals).map(|index| self.coords(index)).collect(),
+      None => vec![]
+    }
+  }
+
+  /// Finds coordinates of the first cell (row by row) matching a value
+  /// 
+  /// # Arguments
+  /// * value: Value to find
+  pub fn find (&self, value: &T) -> Option<(usize, usize)> where T: PartialEq {
+    self.data.iter().position(|cell| cell == value).map(|index| self.coords(index))
+  }
+
+  /// Composes a grid with rows and columns swapped
+  pub fn transpose (&self) -> Grid<T> where T: Clone {
+    Grid::new(self.height(), self.width(), self.columns().flatten().cloned().collect())
+  }
+
+  /// Composes a grid rotated 90° clockwise
+  pub fn rotate (&self) -> Grid<T> where T: Clone {
+    // Transpose, then mirror every row
+    let transposed = self.transpose();
+    let data = transposed.rows().flat_map(|row| row.iter().rev().cloned()).collect();
+    Grid::new(transposed.width(), transposed.height(), data)
+  }
+
+  /// Composes a grid from a rectangular section of this grid
+  /// 
+  /// # Arguments
+  /// * coords: (x, y) coordinates of the top left cell of the section
+  /// * width:  Width of the section (cropped to fit within the grid)
+  /// * height: Height of the section (cropped to fit within the grid)
+  pub fn crop (&self, coords: (usize, usize), width: usize, height: usize) -> Grid<T> where T: Clone {
+    let coords = (coords.0.min(self.width()), coords.1.min(self.height()));
+    let width = width.min(self.width() - coords.0);
+    let height = height.min(self.height() - coords.1);
+    let data = (coords.1..(coords.1 + height))
+      .flat_map(|y| self.row(y)[coords.0..(coords.0 + width)].iter().cloned())
+      .collect();
+    Grid::new(width, height, data)
+  }
+
+  /// Renders the grid as text, one row per line
+  /// 
+  /// # Arguments
+  /// * f: Callback converting a cell value into a character
+  pub fn render<F: Fn(&T) -> char> (&self, f: F) -> String {
+    self.rows().map(|row| row.iter().map(&f).collect::<String>()).collect::<Vec<String>>().join("\n")
+  }
+
+}
+impl<T> Index<(usize, usize)> for Grid<T> {
+  type Output = T;
+  fn index (&self, coords: (usize, usize)) -> &T {
+    self.get(coords).unwrap_or_else(|| panic!("Coordinates {:?} out of {}x{} grid!", coords, self.width(), self.height()))
+  }
+}
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+  fn index_mut (&mut self, coords: (usize, usize)) -> &mut T {
+    let (width, height) = (self.width(), self.height());
+    self.get_mut(coords).unwrap_or_else(|| panic!("Coordinates {:?} out of {}x{} grid!", coords, width, height))
+  }
+}
+impl<T: Clone> Clone for Grid<T> {
+  fn clone (&self) -> Self {
+    Grid::new(self.width(), self.height(), self.data.clone())
+  }
+}
+impl fmt::Display for Grid<char> {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.render(|c| *c))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Parses a 4x3 grid of letters
+  fn letters () -> Grid<char> {
+    Grid::parse("abcd\nefgh\nijkl\n", |c| if c.is_ascii_lowercase() { Some(c) } else { None }).unwrap()
+  }
+
+  #[test]
+  fn parses_and_indexes_cells () {
+    let grid = letters();
+    assert_eq!((grid.width(), grid.height()), (4, 3));
+    assert_eq!(grid[(1, 2)], 'j');
+    assert_eq!(grid.index((3, 1)), Some(7));
+    assert_eq!(grid.coords(7), (3, 1));
+    assert_eq!(grid.get((4, 0)), None);
+    assert_eq!(grid.get((0, 3)), None);
+    assert_eq!(grid.find(&'g'), Some((2, 1)));
+    assert_eq!(grid.row(1), &['e', 'f', 'g', 'h']);
+    assert_eq!(grid.column(2).collect::<String>(), "cgk");
+    assert_eq!(grid.columns().count(), 4);
+    assert_eq!(grid.rows().count(), 3);
+    // Trailing whitespace and blank lines are ignored
+    let padded = Grid::parse("\nab  \ncd\n\n", Some).unwrap();
+    assert_eq!(padded.to_string(), "ab\ncd");
+  }
+
+  #[test]
+  fn rejects_unexpected_characters_and_ragged_rows () {
+    let error = Grid::parse("ab\na#", |c| if c.is_ascii_lowercase() { Some(c) } else { None }).err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+    let error = Grid::parse("ab\nabc", Some).err().unwrap();
+    assert_eq!(error.line, 2);
+  }
+
+  #[test]
+  fn finds_neighbours_within_grid () {
+    let grid = letters();
+    let mut neighbours = grid.neighbours((0, 0), false);
+    neighbours.sort();
+    assert_eq!(neighbours, vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours((1, 1), true).len(), 8);
+    assert_eq!(grid.neighbours((3, 2), true).len(), 3);
+    assert_eq!(grid.neighbours((4, 2), true).len(), 0);
+  }
+
+  #[test]
+  fn transposes_rotates_and_crops () {
+    let grid = letters();
+    assert_eq!(grid.transpose().to_string(), "aei\nbfj\ncgk\ndhl");
+    assert_eq!(grid.rotate().to_string(), "iea\njfb\nkgc\nlhd");
+    assert_eq!(grid.rotate().rotate().to_string(), "lkji\nhgfe\ndcba");
+    assert_eq!(grid.rotate().rotate().rotate().rotate().to_string(), grid.to_string());
+    assert_eq!(grid.crop((1, 1), 2, 2).to_string(), "fg\njk");
+    // Sections reaching out of the grid are cropped to fit
+    let cropped = grid.crop((2, 1), 5, 5);
+    assert_eq!((cropped.width(), cropped.height()), (2, 2));
+    assert_eq!(cropped.to_string(), "gh\nkl");
+    let cropped = grid.crop((5, 0), 1, 1);
+    assert_eq!((cropped.width(), cropped.height()), (0, 1));
+    let cropped = grid.crop((0, 4), 1, 1);
+    assert_eq!((cropped.width(), cropped.height()), (1, 0));
+  }
+
+}
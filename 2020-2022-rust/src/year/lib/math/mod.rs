@@ -34,4 +34,29 @@ impl Math {
     modulo - value - 1
   }
 
+
+  /// Calculates greatest common divisor of 2 numbers
+  /// 
+  /// # Arguments
+  /// * a: First number
+  /// * b: Second number
+  /// 
+  /// # Returns
+  /// Greatest common divisor
+  pub fn gcd (a: usize, b: usize) -> usize {
+    if b == 0 { a } else { Math::gcd(b, a % b) }
+  }
+
+  /// Calculates least common multiple of 2 numbers
+  /// 
+  /// # Arguments
+  /// * a: First number
+  /// * b: Second number
+  /// 
+  /// # Returns
+  /// Least common multiple
+  pub fn lcm (a: usize, b: usize) -> usize {
+    if a == 0 || b == 0 { 0 } else { a / Math::gcd(a, b) * b }
+  }
+
 }
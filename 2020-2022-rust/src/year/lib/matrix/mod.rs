@@ -4,11 +4,20 @@
 // -----------------------------------------------------------------------------
 
 /// Matrix struct
+/// 
+/// Maps N-dimensional coordinates onto indices of a linear data vector, with the first
+/// dimension changing fastest (in 2D, index of `(x, y)` is `x + y * width`). Unbounded spaces are
+/// covered by growing the matrix (and recomposing its data) as points outside of it are reached
 pub struct Matrix {
   pub length: usize,
   pub dimensions: Vec<usize>,
+  /// Signed coordinates of the point stored at index 0 (all zeros unless set)
+  pub origin: Vec<isize>,
+  /// If coordinates out of bounds should wrap around to the opposite side (toroidal topology)
+  pub wrapping: bool,
   dimension_offset_factors: Vec<usize>,
-  neighbour_relative_coordinates: Vec<Vec<isize>>
+  neighbour_relative_coordinates: Vec<Vec<isize>>,
+  orthogonal_neighbours_count: usize
 }
 /// Matrix implementation
 impl Matrix {
@@ -19,54 +28,69 @@ impl Matrix {
   /// * dimensions: Dimensionality of the matrix
   pub fn new(dimensions: Vec<usize>) -> Matrix {
     // Calculate total length
-    let mut length = 1;
-    for i in 0..dimensions.len() {
-      length *= dimensions[i];
-    }
-    // Calculate dimension offsets
+    let length = dimensions.iter().product();
+    // Calculate dimension offsets (each being the product of all preceding dimensions)
     let mut dimension_offset_factors: Vec<usize> = Vec::with_capacity(dimensions.len());
-    for i in 0..dimensions.len() {
-      if i == 0 {
-        dimension_offset_factors.push(1);
-      } else {
-        let mut dimension_offset_factor = 1;
-        for j in i-1..i {
-          dimension_offset_factor *= dimensions[j];
-        }
-        dimension_offset_factors.push(dimension_offset_factor);
-      }
+    let mut dimension_offset_factor = 1;
+    for dimension in dimensions.iter() {
+      dimension_offset_factors.push(dimension_offset_factor);
+      dimension_offset_factor *= dimension;
     }
     // Calculate neighbours' relative coordinates
     let mut neighbour_relative_coordinates: Vec<Vec<isize>> = vec![vec![]];
     for _ in 0..dimensions.len() {
-      // Extend existing coordinates with an extra dimension
-      for j in 0..neighbour_relative_coordinates.len() {
-        neighbour_relative_coordinates[j].push(0);
-      }
-      // Add new coordinates
-      for j in 0..neighbour_relative_coordinates.len() {
-        let mut a = neighbour_relative_coordinates[j].clone();
-        a.pop();
-        a.push(1);
-        neighbour_relative_coordinates.push(a);
-        let mut b = neighbour_relative_coordinates[j].clone();
-        b.pop();
-        b.push(-1);
-        neighbour_relative_coordinates.push(b);
-      }
+      neighbour_relative_coordinates = neighbour_relative_coordinates.iter()
+        .flat_map(|coords| [-1, 0, 1].iter().map(move |d| [coords.clone(), vec![*d]].concat()))
+        .collect();
     }
+    // Drop the point itself and order orthogonal neighbours (differing in a single dimension) first
+    neighbour_relative_coordinates.retain(|coords| coords.iter().any(|d| d != &0));
+    neighbour_relative_coordinates.sort_by_key(|coords| coords.iter().filter(|d| d != &&0).count() != 1);
+    let orthogonal_neighbours_count = neighbour_relative_coordinates.iter().filter(|coords| coords.iter().filter(|d| d != &&0).count() == 1).count();
     // Instantiate matrix
     Matrix {
       length,
+      origin: vec![0; dimensions.len()],
+      wrapping: false,
       dimensions,
       dimension_offset_factors,
-      neighbour_relative_coordinates: neighbour_relative_coordinates.iter()
-        .filter(|c| { c.iter().find(|x| x != &&0) != None })
-        .map(|c| { c.clone() })
-        .collect()
+      neighbour_relative_coordinates,
+      orthogonal_neighbours_count
     }
   }
 
+  /// Sets signed coordinates of the point stored at index 0, allowing for negative coordinates
+  /// 
+  /// # Arguments
+  /// * origin: Signed coordinates of the point stored at index 0
+  pub fn with_origin (mut self, origin: Vec<isize>) -> Matrix {
+    assert_eq!(origin.len(), self.dimensions.len(), "Origin needs to have same dimensionality as the matrix!");
+    self.origin = origin;
+    self
+  }
+
+  /// Sets if coordinates out of bounds should wrap around to the opposite side
+  /// 
+  /// # Arguments
+  /// * wrapping: If coordinates out of bounds should wrap around
+  pub fn with_wrapping (mut self, wrapping: bool) -> Matrix {
+    self.wrapping = wrapping;
+    self
+  }
+
+  /// Composes a matrix grown by a margin on every side, keeping signed coordinates of all points
+  /// 
+  /// # Arguments
+  /// * margin: Number of points to grow by, on every side of every dimension
+  /// 
+  /// # Returns
+  /// Grown matrix, with its origin moved so that every point keeps its signed coordinates
+  pub fn grow (&self, margin: usize) -> Matrix {
+    Matrix::new(self.dimensions.iter().map(|dimension| dimension + 2 * margin).collect())
+      .with_origin(self.origin.iter().map(|origin| origin - margin as isize).collect())
+      .with_wrapping(self.wrapping)
+  }
+
   /// Creates a preallocated vector for storing data for a matrix with matxhing dimensions
   /// 
   /// # Returns
@@ -81,16 +105,12 @@ impl Matrix {
   /// * Index: Data index
   /// 
   /// # Returns
-  /// Calculated N-dimensional coordinates
+  /// Calculated N-dimensional coordinates, or `None` if index is out of bounds
   pub fn index_to_coords (&self, index: &usize) -> Option<Vec<usize>> {
-    let mut coords: Vec<usize> = Vec::with_capacity(self.dimensions.len());
-    let mut index: usize = index.clone();
-    for i in (0..self.dimensions.len()).rev() {
-      coords.push(index / self.dimension_offset_factors[i]);
-      index = index % self.dimension_offset_factors[i];
+    if *index >= self.length {
+      return None;
     }
-    coords.reverse();
-    Some(coords)
+    Some((0..self.dimensions.len()).map(|i| self.coordinate(*index, i)).collect())
   }
 
   /// Gets data index from N-dimensional coordinates
@@ -99,12 +119,15 @@ impl Matrix {
   /// * coords: Coordinates for requested data
   /// 
   /// # Returns
-  /// Calculated index
+  /// Calculated index, or `None` if coordinates are out of bounds
   pub fn coords_to_index (&self, coords: &Vec<usize>) -> Option<usize> {
+    if coords.len() != self.dimensions.len() {
+      return None;
+    }
     let mut index = 0;
-    for i in 0..coords.len() {
-      if coords[i] < self.dimensions[i] {
-        index += coords[i] * self.dimension_offset_factors[i];
+    for ((coordinate, dimension), factor) in coords.iter().zip(self.dimensions.iter()).zip(self.dimension_offset_factors.iter()) {
+      if coordinate < dimension {
+        index += coordinate * factor;
       } else {
         return None;
       }
@@ -112,39 +135,192 @@ impl Matrix {
     Some(index)
   }
 
-  /// Gets neighbouring points to a requested point
+  /// Gets signed N-dimensional coordinates (relative to the origin) from data index
+  /// 
+  /// # Arguments
+  /// * Index: Data index
+  /// 
+  /// # Returns
+  /// Calculated signed N-dimensional coordinates, or `None` if index is out of bounds
+  pub fn index_to_signed_coords (&self, index: &usize) -> Option<Vec<isize>> {
+    self.index_to_coords(index)
+      .map(|coords| coords.iter().zip(self.origin.iter()).map(|(coordinate, origin)| *coordinate as isize + origin).collect())
+  }
+
+  /// Gets data index from signed N-dimensional coordinates (relative to the origin)
+  /// 
+  /// # Arguments
+  /// * coords: Signed coordinates for requested data
+  /// 
+  /// # Returns
+  /// Calculated index, or `None` if coordinates are out of bounds (never when wrapping)
+  pub fn signed_coords_to_index (&self, coords: &[isize]) -> Option<usize> {
+    if coords.len() != self.dimensions.len() || self.length == 0 {
+      return None;
+    }
+    let mut index = 0;
+    for i in 0..coords.len() {
+      index += self.bound(coords[i] - self.origin[i], i)? * self.dimension_offset_factors[i];
+    }
+    Some(index)
+  }
+
+  /// Gets data index of a point at relative coordinates to another point
+  /// 
+  /// # Arguments
+  /// * index:    Index of the starting point
+  /// * relative: Relative coordinates of the requested point
+  /// 
+  /// # Returns
+  /// Index of the requested point, or `None` if out of bounds (never when wrapping)
+  pub fn offset (&self, index: usize, relative: &[isize]) -> Option<usize> {
+    if index >= self.length || relative.len() != self.dimensions.len() {
+      return None;
+    }
+    let mut offset_index = index;
+    for (i, delta) in relative.iter().enumerate() {
+      let coordinate = self.coordinate(index, i);
+      let offset_coordinate = self.bound(coordinate as isize + delta, i)?;
+      offset_index = offset_index - coordinate * self.dimension_offset_factors[i] + offset_coordinate * self.dimension_offset_factors[i];
+    }
+    Some(offset_index)
+  }
+
+  /// Iterates over neighbouring points to a requested point, without allocating
   /// 
   /// # Arguments
   /// * index:              Index to find neighbours for
   /// * include_diagonals:  If diagonal neighbours should be included
   /// 
   /// # Returns
-  /// Vector of indexes of neighbouring points
-  pub fn get_neighbours(&self, index: &usize, include_diagonals: bool) -> Vec<usize> {
-    // Find coordinates
-    let coords = self.index_to_coords(index).unwrap();
-    // Generate neighbour coordinates from pregenerated neighbour relative coordinates
-    let mut neighbours: Vec<usize> = Vec::with_capacity(self.neighbour_relative_coordinates.len());
-    for i in 0..self.neighbour_relative_coordinates.len() {
-      if include_diagonals || self.neighbour_relative_coordinates[i].iter().find(|n| n == &&0) != None {
-        let mut inbounds = true;
-        let mut neighbour: Vec<usize> = coords.clone();
-        for j in 0..self.neighbour_relative_coordinates[i].len() {
-          let coordinate: isize = neighbour[j] as isize + self.neighbour_relative_coordinates[i][j];
-          if coordinate >= 0 && coordinate < self.dimensions[j] as isize {
-            neighbour[j] = (neighbour[j] as isize + self.neighbour_relative_coordinates[i][j]) as usize;
-          } else {
-            inbounds = false;
-            break;
+  /// Iterator over indexes of neighbouring points (orthogonal neighbours first). When wrapping around
+  /// dimensions of only 1 or 2 points, a point is never its own neighbour and each neighbour is only
+  /// included once
+  pub fn neighbours (&self, index: usize, include_diagonals: bool) -> impl Iterator<Item = usize> + '_ {
+    let count = if include_diagonals { self.neighbour_relative_coordinates.len() } else { self.orthogonal_neighbours_count };
+    let relatives = &self.neighbour_relative_coordinates[..count];
+    relatives.iter().enumerate().filter_map(move |(i, relative)| {
+      let neighbour = self.offset(index, relative)?;
+      // Skip offsets wrapping back onto the point itself or onto an already included neighbour
+      if self.wrapping && (neighbour == index || relatives[..i].iter().any(|other| self.offset(index, other) == Some(neighbour))) {
+        return None;
+      }
+      Some(neighbour)
+    })
+  }
+
+  /// Gets a single (unsigned) coordinate of a point from its data index
+  fn coordinate (&self, index: usize, dimension: usize) -> usize {
+    (index / self.dimension_offset_factors[dimension]) % self.dimensions[dimension]
+  }
+
+  /// Brings an (unsigned) coordinate within bounds of a dimension by wrapping it if wrapping
+  fn bound (&self, coordinate: isize, dimension: usize) -> Option<usize> {
+    let size = self.dimensions[dimension] as isize;
+    if coordinate >= 0 && coordinate < size {
+      Some(coordinate as usize)
+    } else if self.wrapping && size > 0 {
+      Some(coordinate.rem_euclid(size) as usize)
+    } else {
+      None
+    }
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Matrix shapes to check, including dimensions of only 1 and 2 points
+  const SHAPES: [&[usize]; 10] = [&[5], &[1], &[2], &[3, 4], &[1, 3], &[2, 2], &[2, 3, 4], &[3, 1, 2], &[4, 4, 3], &[2, 3, 2, 3]];
+
+  /// Enumerates all coordinates within dimensions, first dimension changing fastest
+  fn enumerate (dimensions: &[usize]) -> Vec<Vec<usize>> {
+    dimensions.iter().rev().fold(vec![vec![]], |all, dimension| {
+      all.iter().flat_map(|coords| (0..*dimension).map(move |c| [coords.clone(), vec![c]].concat())).collect()
+    }).into_iter().map(|coords| coords.into_iter().rev().collect()).collect()
+  }
+
+  /// Finds neighbours of a point by comparing it against every point of the matrix
+  fn naive_neighbours (dimensions: &[usize], coords: &[usize], include_diagonals: bool, wrapping: bool) -> Vec<Vec<usize>> {
+    enumerate(dimensions).into_iter()
+      .filter(|other| {
+        let distances: Vec<usize> = other.iter().zip(coords.iter()).zip(dimensions.iter())
+          .map(|((a, b), size)| { let d = (*a as isize - *b as isize).unsigned_abs(); if wrapping { d.min(size - d) } else { d } })
+          .collect();
+        let differing = distances.iter().filter(|d| **d != 0).count();
+        distances.iter().all(|d| *d <= 1) && differing > 0 && (include_diagonals || differing == 1)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn maps_indices_to_coordinates_and_back () {
+    for shape in SHAPES.iter() {
+      let matrix = Matrix::new(shape.to_vec());
+      let all = enumerate(shape);
+      assert_eq!(matrix.length, all.len());
+      for (index, coords) in all.iter().enumerate() {
+        assert_eq!(matrix.coords_to_index(coords), Some(index));
+        assert_eq!(matrix.index_to_coords(&index).as_ref(), Some(coords));
+      }
+      assert_eq!(matrix.index_to_coords(&matrix.length), None);
+      assert_eq!(matrix.coords_to_index(&shape.to_vec()), None);
+    }
+  }
+
+  #[test]
+  fn maps_signed_coordinates_relative_to_origin () {
+    for shape in SHAPES.iter() {
+      let origin: Vec<isize> = (0..shape.len()).map(|i| i as isize * 3 - 2).collect();
+      let matrix = Matrix::new(shape.to_vec()).with_origin(origin.clone());
+      for index in 0..matrix.length {
+        let signed = matrix.index_to_signed_coords(&index).unwrap();
+        let coords = matrix.index_to_coords(&index).unwrap();
+        assert_eq!(signed, coords.iter().zip(origin.iter()).map(|(c, o)| *c as isize + o).collect::<Vec<isize>>());
+        assert_eq!(matrix.signed_coords_to_index(&signed), Some(index));
+      }
+      assert_eq!(matrix.signed_coords_to_index(&origin.iter().map(|o| o - 1).collect::<Vec<isize>>()), None);
+    }
+  }
+
+  #[test]
+  fn finds_same_neighbours_as_naive_reference () {
+    for shape in SHAPES.iter() {
+      for wrapping in [false, true].iter() {
+        for origin in [0, -7].iter() {
+          let matrix = Matrix::new(shape.to_vec()).with_origin(vec![*origin; shape.len()]).with_wrapping(*wrapping);
+          for include_diagonals in [false, true].iter() {
+            for index in 0..matrix.length {
+              let coords = matrix.index_to_coords(&index).unwrap();
+              let found: Vec<usize> = matrix.neighbours(index, *include_diagonals).collect();
+              let mut sorted = found.clone();
+              sorted.sort_unstable();
+              sorted.dedup();
+              assert_eq!(sorted.len(), found.len(), "Duplicate neighbours of {:?} in {:?}", coords, shape);
+              let mut expected: Vec<usize> = naive_neighbours(shape, &coords, *include_diagonals, *wrapping).iter()
+                .map(|other| matrix.coords_to_index(other).unwrap())
+                .collect();
+              expected.sort_unstable();
+              assert_eq!(sorted, expected, "Neighbours of {:?} in {:?} (wrapping: {})", coords, shape, wrapping);
+            }
           }
         }
-        if inbounds {
-          neighbours.push(self.coords_to_index(&neighbour).unwrap());
-        }
       }
     }
-    // Return found neighbours
-    neighbours
+  }
+
+  #[test]
+  fn grows_keeping_signed_coordinates () {
+    let matrix = Matrix::new(vec![3, 2]).with_origin(vec![-1, 4]);
+    let grown = matrix.grow(2);
+    assert_eq!(grown.dimensions, vec![7, 6]);
+    assert_eq!(grown.origin, vec![-3, 2]);
+    for index in 0..matrix.length {
+      let signed = matrix.index_to_signed_coords(&index).unwrap();
+      assert_eq!(grown.index_to_signed_coords(&grown.signed_coords_to_index(&signed).unwrap()), Some(signed));
+    }
   }
 
 }
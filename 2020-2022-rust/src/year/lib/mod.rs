@@ -4,4 +4,7 @@
 // Load child modules
 pub mod math;
 pub mod matrix;
+pub mod grid;
 pub mod dot_display;
+pub mod graph;
+pub mod ocr;
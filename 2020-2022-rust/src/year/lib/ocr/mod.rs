@@ -0,0 +1,189 @@
+//! OCR module
+//! 
+//! Recognizes text drawn in the block letter fonts puzzles draw their answers in
+// -----------------------------------------------------------------------------
+
+/// Character output in place of a glyph which doesn't match any letter of the font
+const OCR_UNKNOWN: char = '?';
+
+/// Small, 4x6 font (glyphs spaced 5 columns apart)
+const OCR_FONT_SMALL: OcrFont = OcrFont {
+  width: 4,
+  height: 6,
+  pitch: 5,
+  glyphs: &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"])
+  ]
+};
+
+/// Large, 6x10 font (glyphs spaced 8 columns apart)
+const OCR_FONT_LARGE: OcrFont = OcrFont {
+  width: 6,
+  height: 10,
+  pitch: 8,
+  glyphs: &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"])
+  ]
+};
+
+/// Block letter font
+struct OcrFont {
+  /// Width of each glyph, in cells
+  width: usize,
+  /// Height of each glyph, in cells
+  height: usize,
+  /// Distance between left edges of neighbouring glyphs, in cells
+  pitch: usize,
+  /// Known glyphs, as rows of `#` (lit) and `.` (unlit) cells
+  glyphs: &'static [(char, &'static [&'static str])]
+}
+
+/// OCR struct
+pub struct Ocr {}
+/// OCR implementation
+/// 
+/// Text is expected to be aligned to the top left corner (column and row 0) and to be exactly as high as
+/// one of the fonts, which font is then used to read it
+impl Ocr {
+
+  /// Reads text from rows of cells
+  /// 
+  /// # Arguments
+  /// * rows: Rows of cells, each cell being either lit or not
+  /// 
+  /// # Returns
+  /// Read text (glyphs not matching any letter read as `?`, blank glyphs as spaces, trailing blank glyphs dropped),
+  /// or a description of why text couldn't be read
+  pub fn read (rows: &[Vec<bool>]) -> Result<String, String> {
+    // Pick font matching the height of the text
+    let fonts = [OCR_FONT_SMALL, OCR_FONT_LARGE];
+    let font = fonts.iter().find(|font| font.height == rows.len())
+      .ok_or_else(|| format!("Expected text {} or {} cells high, found {} rows", OCR_FONT_SMALL.height, OCR_FONT_LARGE.height, rows.len()))?;
+    // Read each glyph
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let text: String = (0..width.div_ceil(font.pitch))
+      .map(|i| {
+        let glyph: Vec<String> = rows.iter()
+          .map(|row| (0..font.width).map(|x| if row.get(i * font.pitch + x).copied().unwrap_or(false) { '#' } else { '.' }).collect())
+          .collect();
+        if glyph.iter().all(|row| !row.contains('#')) {
+          return ' ';
+        }
+        font.glyphs.iter()
+          .find(|(_, rows)| rows.iter().zip(glyph.iter()).all(|(a, b)| a == b))
+          .map_or(OCR_UNKNOWN, |(c, _)| *c)
+      })
+      .collect();
+    Ok(text.trim_end().to_string())
+  }
+
+  /// Reads text from a buffer of cells
+  /// 
+  /// # Arguments
+  /// * buffer: Cells, row after row, each being either lit or not
+  /// * width:  Number of cells in each row
+  /// 
+  /// # Returns
+  /// Read text, or a description of why text couldn't be read
+  pub fn read_buffer (buffer: &[bool], width: usize) -> Result<String, String> {
+    Ocr::read(&buffer.chunks(width).map(|row| row.to_vec()).collect::<Vec<Vec<bool>>>())
+  }
+
+  /// Reads text from a set of lit points
+  /// 
+  /// # Arguments
+  /// * points: (x, y) coordinates of lit cells
+  /// 
+  /// # Returns
+  /// Read text, or a description of why text couldn't be read
+  pub fn read_points (points: &[(usize, usize)]) -> Result<String, String> {
+    let width = points.iter().map(|p| p.0 + 1).max().unwrap_or(0);
+    let height = points.iter().map(|p| p.1 + 1).max().unwrap_or(0);
+    let mut rows = vec![vec![false; width]; height];
+    for point in points.iter() {
+      rows[point.1][point.0] = true;
+    }
+    Ocr::read(&rows)
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Draws glyphs side by side, spaced as within text drawn in the font
+  fn draw (font: &OcrFont, glyphs: &[&[&str]]) -> Vec<Vec<bool>> {
+    (0..font.height)
+      .map(|y| glyphs.iter().enumerate().fold(vec![false; glyphs.len() * font.pitch], |mut row, (i, glyph)| {
+        for (x, cell) in glyph[y].chars().enumerate() {
+          row[i * font.pitch + x] = cell == '#';
+        }
+        row
+      }))
+      .collect()
+  }
+
+  #[test]
+  fn reads_every_glyph_of_every_font () {
+    for font in [OCR_FONT_SMALL, OCR_FONT_LARGE].iter() {
+      let glyphs: Vec<&[&str]> = font.glyphs.iter().map(|(_, rows)| *rows).collect();
+      let text: String = font.glyphs.iter().map(|(c, _)| *c).collect();
+      assert_eq!(Ocr::read(&draw(font, &glyphs)), Ok(text));
+    }
+  }
+
+  #[test]
+  fn reads_unknown_glyphs_as_placeholders () {
+    let unknown: &[&str] = &["####", "####", "....", "....", "####", "####"];
+    let rows = draw(&OCR_FONT_SMALL, &[OCR_FONT_SMALL.glyphs[0].1, unknown, OCR_FONT_SMALL.glyphs[1].1]);
+    assert_eq!(Ocr::read(&rows), Ok(format!("A{}B", OCR_UNKNOWN)));
+  }
+
+  #[test]
+  fn rejects_text_of_unknown_height () {
+    assert!(Ocr::read(&vec![vec![true; 4]; 7]).is_err());
+  }
+
+  #[test]
+  fn reads_points_and_buffers () {
+    let rows = draw(&OCR_FONT_SMALL, &[OCR_FONT_SMALL.glyphs[7].1]);
+    let points: Vec<(usize, usize)> = rows.iter().enumerate()
+      .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, cell)| **cell).map(move |(x, _)| (x, y)))
+      .collect();
+    let buffer: Vec<bool> = rows.concat();
+    assert_eq!(Ocr::read_points(&points), Ok(String::from("I")));
+    assert_eq!(Ocr::read_buffer(&buffer, OCR_FONT_SMALL.pitch), Ok(String::from("I")));
+  }
+
+}
@@ -20,69 +20,41 @@ fn parse(data: &String) -> Vec<usize>{
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 1,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Find increasing values
-      let mut increases: usize = 0;
-      for i in 1..(data.len()) {
-        if data[i] > data[i - 1] {
-          increases += 1;
-        }
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Find increasing values
+    let mut increases: usize = 0;
+    for i in 1..(data.len()) {
+      if data[i] > data[i - 1] {
+        increases += 1;
       }
-
-      // Return result
-      String::from(format!("{:?}", increases))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", increases))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 1,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Find increasing values
-      let mut increases: usize = 0;
-      for i in 3..(data.len()) {
-        if (data[i] + data[i - 1] + data[i - 2]) > data[i - 1] + data[i - 2] + data[i - 3] {
-          increases += 1;
-        }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Find increasing values
+    let mut increases: usize = 0;
+    for i in 3..(data.len()) {
+      if (data[i] + data[i - 1] + data[i - 2]) > data[i - 1] + data[i - 2] + data[i - 3] {
+        increases += 1;
       }
-
-      // Return result
-      String::from(format!("{:?}", increases))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", increases))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -21,81 +21,53 @@ fn parse<'a>(data: &'a String) -> Vec<(&'a str, usize)>{
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 2,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Track position
-      let mut x = 0;
-      let mut z = 0;
-      for i in 0..data.len() {
-        if data[i].0 == "forward" {
-          x += data[i].1;
-        } else if data[i].0 == "down" {
-          z += data[i].1;
-        } else if data[i].0 == "up" {
-          z -= data[i].1;
-        }
+    // Track position
+    let mut x = 0;
+    let mut z = 0;
+    for i in 0..data.len() {
+      if data[i].0 == "forward" {
+        x += data[i].1;
+      } else if data[i].0 == "down" {
+        z += data[i].1;
+      } else if data[i].0 == "up" {
+        z -= data[i].1;
       }
-
-      // Return result
-      String::from(format!("{:?}", x * z))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", x * z))
+  },
 
   // Part II
-  registry.register(
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 2,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Track position
-      let mut x = 0;
-      let mut aim = 0;
-      let mut z = 0;
-      for i in 0..data.len() {
-        if data[i].0 == "forward" {
-          x += data[i].1;
-          z += data[i].1 * aim;
-        } else if data[i].0 == "down" {
-          aim += data[i].1;
-        } else if data[i].0 == "up" {
-          aim -= data[i].1;
-        }
+    // Track position
+    let mut x = 0;
+    let mut aim = 0;
+    let mut z = 0;
+    for i in 0..data.len() {
+      if data[i].0 == "forward" {
+        x += data[i].1;
+        z += data[i].1 * aim;
+      } else if data[i].0 == "down" {
+        aim += data[i].1;
+      } else if data[i].0 == "up" {
+        aim -= data[i].1;
       }
-
-      // Return result
-      String::from(format!("{:?}", x * z))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", x * z))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -20,92 +20,64 @@ fn parse(data: &String) -> (Vec<u8>, Vec<Vec<Vec<u8>>>) {
   (numbers, cards)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 4,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize bing and cards
-      let numbers = data.0;
-      let cards = data.1;
-      let mut bingo = Bingo::new(5, 5);
-      for i in 0..cards.len() {
-        bingo.add_card(&cards[i]);
-      }
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize bing and cards
+    let numbers = data.0;
+    let cards = data.1;
+    let mut bingo = Bingo::new(5, 5);
+    for i in 0..cards.len() {
+      bingo.add_card(&cards[i]);
+    }
 
-      // Play bingo until a win
-      for i in 0..numbers.len() {
-        let winner = bingo.draw(numbers[i].clone());
-        match winner {
-          Some(card) => {
-            return String::from(format!("{:?}", numbers[i] as usize * card.borrow().get_remaining_numbers_sum()));
-          },
-          _ => {}
-        }
+    // Play bingo until a win
+    for i in 0..numbers.len() {
+      let winner = bingo.draw(numbers[i].clone());
+      match winner {
+        Some(card) => {
+          return String::from(format!("{:?}", numbers[i] as usize * card.borrow().get_remaining_numbers_sum()));
+        },
+        _ => {}
       }
-
-      // Return result
-      String::from(format!("{:?}", "No winning card found!"))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", "No winning card found!"))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 4,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize bing and cards
-      let numbers = data.0;
-      let cards = data.1;
-      let mut bingo = Bingo::new(5, 5);
-      for i in 0..cards.len() {
-        bingo.add_card(&cards[i]);
-      }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize bing and cards
+    let numbers = data.0;
+    let cards = data.1;
+    let mut bingo = Bingo::new(5, 5);
+    for i in 0..cards.len() {
+      bingo.add_card(&cards[i]);
+    }
 
-      // Play bingo until a win
-      let mut last_win: usize = 0;
-      for i in 0..numbers.len() {
-        let winner = bingo.draw(numbers[i].clone());
-        match winner {
-          Some(card) => {
-            last_win = numbers[i] as usize * card.borrow().get_remaining_numbers_sum();
-          },
-          _ => {}
-        }
+    // Play bingo until a win
+    let mut last_win: usize = 0;
+    for i in 0..numbers.len() {
+      let winner = bingo.draw(numbers[i].clone());
+      match winner {
+        Some(card) => {
+          last_win = numbers[i] as usize * card.borrow().get_remaining_numbers_sum();
+        },
+        _ => {}
       }
-      
-      // Return result
-      String::from(format!("{:?}", last_win))
     }
+    
+    // Return result
+    String::from(format!("{:?}", last_win))
+  }
 
-  );
-
-  // Return registry ownership
-  registry
 }
@@ -17,73 +17,45 @@ fn parse(data: &String) -> Vec<Vec<Vec<isize>>> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 5,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a geometry plain
-      let mut plain = GeometryPlain::new(data.len());
-      // Add lines to the plain
-      for i in 0..data.len() {
-        plain.add_line(data[i][0][0], data[i][0][1], data[i][1][0], data[i][1][1]);
-      }
+// Registers puzzles for the day
+puzzles! {
 
-      // Find intersections
-      let intersections = plain.find_intersections(false);
-
-      // Return result
-      String::from(format!("{:?}", intersections.len()))
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize a geometry plain
+    let mut plain = GeometryPlain::new(data.len());
+    // Add lines to the plain
+    for i in 0..data.len() {
+      plain.add_line(data[i][0][0], data[i][0][1], data[i][1][0], data[i][1][1]);
     }
 
-  );
+    // Find intersections
+    let intersections = plain.find_intersections(false);
 
-  // Part II
-  registry.register(
+    // Return result
+    String::from(format!("{:?}", intersections.len()))
+  },
 
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 5,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a geometry plain
-      let mut plain = GeometryPlain::new(data.len());
-      // Add lines to the plain
-      for i in 0..data.len() {
-        plain.add_line(data[i][0][0], data[i][0][1], data[i][1][0], data[i][1][1]);
-      }
-
-      // Find intersections
-      let intersections = plain.find_intersections(true);
-
-      // Return result
-      String::from(format!("{:?}", intersections.len()))
+  // Part II
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize a geometry plain
+    let mut plain = GeometryPlain::new(data.len());
+    // Add lines to the plain
+    for i in 0..data.len() {
+      plain.add_line(data[i][0][0], data[i][0][1], data[i][1][0], data[i][1][1]);
     }
 
-  );
+    // Find intersections
+    let intersections = plain.find_intersections(true);
+
+    // Return result
+    String::from(format!("{:?}", intersections.len()))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -13,71 +13,43 @@ fn parse(data: &String) -> Vec<usize> {
   Input::parse(data.trim(), ",", |n| { n.parse::<usize>().unwrap() })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 6,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize hatchery
-      let mut hatchery = Hatchery::new(2, 7, 1);
-      hatchery.populate(data);
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Run model
-      for _ in 0..80 {
-        hatchery.tick();
-      }
+    // Initialize hatchery
+    let mut hatchery = Hatchery::new(2, 7, 1);
+    hatchery.populate(data);
 
-      // Return result
-      String::from(format!("{:?}", hatchery.len()))
+    // Run model
+    for _ in 0..80 {
+      hatchery.tick();
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", hatchery.len()))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 6,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize hatchery
-      let mut hatchery = Hatchery::new(2, 7, 1);
-      hatchery.populate(data);
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Run model
-      for _ in 0..256 {
-        hatchery.tick();
-      }
+    // Initialize hatchery
+    let mut hatchery = Hatchery::new(2, 7, 1);
+    hatchery.populate(data);
 
-      // Return result
-      String::from(format!("{:?}", hatchery.len()))
+    // Run model
+    for _ in 0..256 {
+      hatchery.tick();
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", hatchery.len()))
+  }
 
-  // Return registry ownership
-  registry
 }
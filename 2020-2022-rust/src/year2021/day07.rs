@@ -13,85 +13,57 @@ fn parse(data: &String) -> Vec<usize> {
   Input::parse(data.trim(), ",", |n| { n.parse::<usize>().unwrap() })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 7,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Find limits of the search space
-      let min = data.iter().min().unwrap().clone();
-      let max = data.iter().max().unwrap().clone();
-
-      // Find target with minimal fuel consumption
-      let mut cache: HashMap<usize, usize> = HashMap::new();
-      for i in (min + 1)..max {
-        let target_consumption = calculate_fuel(&data, i, |d| { d }, &mut cache);
-        let prev_consumption = calculate_fuel(&data, i - 1, |d| { d }, &mut cache);
-        let next_consumption = calculate_fuel(&data, i + 1, |d| { d }, &mut cache);
-        if target_consumption < prev_consumption && target_consumption < next_consumption {
-          return String::from(format!("{:?}", target_consumption));
-        }
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Find limits of the search space
+    let min = data.iter().min().unwrap().clone();
+    let max = data.iter().max().unwrap().clone();
+
+    // Find target with minimal fuel consumption
+    let mut cache: HashMap<usize, usize> = HashMap::new();
+    for i in (min + 1)..max {
+      let target_consumption = calculate_fuel(&data, i, |d| { d }, &mut cache);
+      let prev_consumption = calculate_fuel(&data, i - 1, |d| { d }, &mut cache);
+      let next_consumption = calculate_fuel(&data, i + 1, |d| { d }, &mut cache);
+      if target_consumption < prev_consumption && target_consumption < next_consumption {
+        return String::from(format!("{:?}", target_consumption));
       }
-
-      // Return result
-      String::from(format!("{:?}", 0))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", 0))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 7,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Find limits of the search space
-      let min = data.iter().min().unwrap().clone();
-      let max = data.iter().max().unwrap().clone();
-
-      // Find target with minimal fuel consumption
-      let mut cache: HashMap<usize, usize> = HashMap::new();
-      for i in (min + 1)..max {
-        let target_consumption = calculate_fuel(&data, i, |d| { ((1.0 + d as f64) * (0.5 * d as f64)) as usize }, &mut cache);
-        let prev_consumption = calculate_fuel(&data, i - 1, |d| { ((1.0 + d as f64) * (0.5 * d as f64)) as usize }, &mut cache);
-        let next_consumption = calculate_fuel(&data, i + 1, |d| { ((1.0 + d as f64) * (0.5 * d as f64)) as usize }, &mut cache);
-        if target_consumption < prev_consumption && target_consumption < next_consumption {
-          return String::from(format!("{:?}", target_consumption));
-        }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Find limits of the search space
+    let min = data.iter().min().unwrap().clone();
+    let max = data.iter().max().unwrap().clone();
+
+    // Find target with minimal fuel consumption
+    let mut cache: HashMap<usize, usize> = HashMap::new();
+    for i in (min + 1)..max {
+      let target_consumption = calculate_fuel(&data, i, |d| { ((1.0 + d as f64) * (0.5 * d as f64)) as usize }, &mut cache);
+      let prev_consumption = calculate_fuel(&data, i - 1, |d| { ((1.0 + d as f64) * (0.5 * d as f64)) as usize }, &mut cache);
+      let next_consumption = calculate_fuel(&data, i + 1, |d| { ((1.0 + d as f64) * (0.5 * d as f64)) as usize }, &mut cache);
+      if target_consumption < prev_consumption && target_consumption < next_consumption {
+        return String::from(format!("{:?}", target_consumption));
       }
-
-      // Return result
-      String::from(format!("{:?}", 0))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", 0))
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Calculates total fuel expenditure to get all vehicles to the target position
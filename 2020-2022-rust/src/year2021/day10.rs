@@ -14,89 +14,61 @@ fn parse(data: &String) -> Vec<Vec<char>> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 10,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Validaet all lines
-      let mut score = 0;
-      for i in 0..data.len() {
-        match validate_line(&data[i]) {
-          Err(c) => {
-            if c == ')' { score += 3; }
-            if c == ']' { score += 57; }
-            if c == '}' { score += 1197; }
-            if c == '>' { score += 25137; }
-          },
-          Ok(_) => ()
-        }
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Validaet all lines
+    let mut score = 0;
+    for i in 0..data.len() {
+      match validate_line(&data[i]) {
+        Err(c) => {
+          if c == ')' { score += 3; }
+          if c == ']' { score += 57; }
+          if c == '}' { score += 1197; }
+          if c == '>' { score += 25137; }
+        },
+        Ok(_) => ()
       }
-
-      // Calculate and return result
-      String::from(format!("{:?}", score))
     }
 
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", score))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 10,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Complete all valid lines
-      let mut scores: Vec<usize> = vec![];
-      for i in 0..data.len() {
-        match validate_line(&data[i]) {
-          Ok(closing) => {
-            let mut score = 0;
-            for j in 0..closing.len() {
-              score *= 5;
-              if closing[j] == '(' { score += 1; }
-              if closing[j] == '[' { score += 2; }
-              if closing[j] == '{' { score += 3; }
-              if closing[j] == '<' { score += 4; }
-            }
-            scores.push(score);
-          },
-          Err(_) => ()
-        }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Complete all valid lines
+    let mut scores: Vec<usize> = vec![];
+    for i in 0..data.len() {
+      match validate_line(&data[i]) {
+        Ok(closing) => {
+          let mut score = 0;
+          for j in 0..closing.len() {
+            score *= 5;
+            if closing[j] == '(' { score += 1; }
+            if closing[j] == '[' { score += 2; }
+            if closing[j] == '{' { score += 3; }
+            if closing[j] == '<' { score += 4; }
+          }
+          scores.push(score);
+        },
+        Err(_) => ()
       }
-      scores.sort();
-
-      // Calculate and return result
-      String::from(format!("{:?}", scores[(scores.len() as f64 / 2.0) as usize]))
     }
+    scores.sort();
 
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", scores[(scores.len() as f64 / 2.0) as usize]))
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Validates a line of bracketted syntax
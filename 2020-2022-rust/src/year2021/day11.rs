@@ -5,103 +5,61 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
-use crate::lib::input::*;
+use crate::lib::error::*;
 use crate::year::lib::matrix::*;
+use crate::year::lib::grid::*;
+use crate::year::lib::dot_display::*;
 
 /// Parses input data
-fn parse(data: &String) -> Vec<Vec<isize>> {
-  Input::parse(data.trim(), "\n", |line| {
-    Input::parse(line.trim(), "", |n| { n.parse::<isize>().unwrap() })
-  })
+fn parse(data: &str) -> Result<Grid<isize>, PuzzleError> {
+  Ok(Grid::parse(data, |n| n.to_digit(10).map(|n| n as isize))?)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 11,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Store data into a 2D grid
-      let matrix: Matrix = Matrix::new(vec![data[0].len(), data.len()]);
-      let mut vector: Vec<isize> = Vec::with_capacity(matrix.length);
-      for y in 0..data.len() {
-        for x in 0..data[y].len() {
-          vector.push(data[y][x]);
-        }
-      }
+// Registers puzzles for the day
+puzzles! {
 
-      let mut total = 0;
-      let mut cache: Vec<isize> = Vec::with_capacity(matrix.length);
-      for _ in 0..matrix.length { cache.push(-1); }
-      for i in 0..100 {
-        let popped = do_step(i as isize, &matrix, &mut vector, 10 as isize, 0 as isize, &mut cache, |_index| {});
-        total += popped.len();
-      }
-
-      // Calculate and return result
-      String::from(format!("{:?}", total))
+  // Part I
+  1 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data into a 2D grid
+    let mut grid = parse(data)?;
+
+    let mut total = 0;
+    let mut cache: Vec<isize> = Vec::with_capacity(grid.data.len());
+    for _ in 0..grid.data.len() { cache.push(-1); }
+    context.frame(|| DotDisplay::render_2d_matrix(&grid.matrix, &grid.data));
+    for i in 0..100 {
+      let popped = do_step(i as isize, &grid.matrix, &mut grid.data, 10 as isize, 0 as isize, &mut cache, |_index| {});
+      total += popped.len();
+      context.frame(|| DotDisplay::render_2d_matrix(&grid.matrix, &grid.data));
     }
 
-  );
+    // Calculate and return result
+    Ok(format!("{:?}", total))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 11,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Store data into a 2D grid
-      let matrix: Matrix = Matrix::new(vec![data[0].len(), data.len()]);
-      let mut vector: Vec<isize> = Vec::with_capacity(matrix.length);
-      for y in 0..data.len() {
-        for x in 0..data[y].len() {
-          vector.push(data[y][x]);
-        }
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data into a 2D grid
+    let mut grid = parse(data)?;
+
+    let mut step = 0;
+    let mut cache: Vec<isize> = Vec::with_capacity(grid.data.len());
+    for _ in 0..grid.data.len() { cache.push(-1); }
+    context.frame(|| DotDisplay::render_2d_matrix(&grid.matrix, &grid.data));
+    loop {
+      let popped = do_step(step as isize, &grid.matrix, &mut grid.data, 10 as isize, 0 as isize, &mut cache, |_index| {});
+      context.frame(|| DotDisplay::render_2d_matrix(&grid.matrix, &grid.data));
+      if popped.len() != grid.data.len() {
+        step += 1;
+      } else {
+        break;
       }
-
-      let mut step = 0;
-      let mut cache: Vec<isize> = Vec::with_capacity(matrix.length);
-      for _ in 0..matrix.length { cache.push(-1); }
-      loop {
-        let popped = do_step(step as isize, &matrix, &mut vector, 10 as isize, 0 as isize, &mut cache, |_index| {});
-        if popped.len() != vector.len() {
-          step += 1;
-        } else {
-          break;
-        }
-      }
-
-      // Calculate and return result
-      String::from(format!("{:?}", step + 1))
     }
 
-  );
+    // Calculate and return result
+    Ok(format!("{:?}", step + 1))
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Performs a single time step of the model
@@ -151,13 +109,12 @@ fn do_step (step: isize, matrix: &Matrix, vector: &mut Vec<isize>, pop_value: is
     callback(i);
 
     // Find neighbouring octopi and try scheduling them to "pop" as well
-    let neighbours = matrix.get_neighbours(&index, true);
-    for i in 0..neighbours.len() {
+    for neighbour in matrix.neighbours(index, true) {
       // Increase neighbouring octopus energy level
-      vector[neighbours[i]] += 1;
+      vector[neighbour] += 1;
       // Schedule those ready to "pop" to "pop"
-      if vector[neighbours[i]] >= pop_value && cache[neighbours[i]] != step {
-        ready_to_pop.push(neighbours[i]);
+      if vector[neighbour] >= pop_value && cache[neighbour] != step {
+        ready_to_pop.push(neighbour);
       }
     }
   }
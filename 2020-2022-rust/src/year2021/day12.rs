@@ -4,7 +4,6 @@
 // -----------------------------------------------------------------------------
 
 // Include dependencies
-use std::collections::hash_map::*;
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
 use crate::year2021::lib::path_finding::*;
@@ -17,92 +16,51 @@ fn parse(data: &String) -> Vec<(&str, &str)> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 12,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize path finding
-      let finding = PathFinding::new(data);
-      let mut cache: HashMap<&str, bool> = HashMap::new();
-      let mut path: Vec<&str> = Vec::new();
-      let mut paths: Vec<Vec<&str>> = Vec::new();
-      finding.find_all_paths("start", "end", &mut cache , &mut path, &mut paths, |key: &&str, path: &mut Vec<&str>, _cache: &mut HashMap<&str, bool>| {
-        // Check if key is upper-case
-        if key != &key.to_lowercase() { return true; }
-        // Check if previously visited
-        let previously_visited = path.iter().find(|k| k == &key) != None;
-        if !previously_visited { return true; } 
-        // Default to false
-        false
-      });
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Calculate and return result
-      String::from(format!("{:?}", paths.len()))
-    }
+    // Initialize path finding
+    let finding = PathFinding::new(data);
+    let count = finding.paths("start", "end", (), |key, path, _| {
+      // Check if key is upper-case
+      if key != &key.to_lowercase() { return Some(()); }
+      // Check if previously visited
+      if !path.contains(key) { return Some(()); }
+      // Default to not allowed
+      None
+    }).count();
 
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", count))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 12,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize path finding
-      let finding = PathFinding::new(data);
-      let mut cache: HashMap<&str, bool> = HashMap::new();
-      let mut path: Vec<&str> = Vec::new();
-      let mut paths: Vec<Vec<&str>> = Vec::new();
-      finding.find_all_paths("start", "end", &mut cache , &mut path, &mut paths, |key: &&str, path: &mut Vec<&str>, _cache: &mut HashMap<&str, bool>| {        
-        // Check if key is upper-case
-        if key != &key.to_lowercase() { return true; }
-        // Check if previously visited
-        let previously_visited = path.iter().find(|k| k == &key) != None;
-        if !previously_visited { return true; } 
-        // Check if "start"
-        if key == &"start" { return false; }
-        // Check if no doubled lower-cased keys already in path
-        let mut lc = path.iter().filter(|k| k == &&&k.to_lowercase()).collect::<Vec<&&str>>();
-        lc.sort();
-        let mut lc_dedup = lc.clone();
-        lc_dedup.dedup();
-        let has_doubled_lc = lc_dedup.len() != lc.len();
-        if !has_doubled_lc { return true; }
-        // Default to false
-        false
-      });
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Calculate and return result
-      String::from(format!("{:?}", paths.len()))
-    }
+    // Initialize path finding (with state tracking if a small cave was already visited twice)
+    let finding = PathFinding::new(data);
+    let count = finding.paths("start", "end", false, |key, path, visited_twice| {
+      // Check if key is upper-case
+      if key != &key.to_lowercase() { return Some(*visited_twice); }
+      // Check if previously visited
+      if !path.contains(key) { return Some(*visited_twice); }
+      // Check if "start"
+      if key == &"start" { return None; }
+      // Check if no small cave was already visited twice
+      if !visited_twice { return Some(true); }
+      // Default to not allowed
+      None
+    }).count();
 
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", count))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -6,10 +6,12 @@
 // Include dependencies
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
+use crate::lib::error::*;
 use crate::year::lib::dot_display::*;
+use crate::year::lib::ocr::*;
 
 /// Parses input data
-fn parse(data: &String) -> (Vec<(usize, usize)>, Vec<(&str, usize)>) {
+fn parse(data: &str) -> (Vec<(usize, usize)>, Vec<(&str, usize)>) {
   let sections = Input::parse(data.trim(), "\n\n", |section| { section.trim() });
   let coordinates: Vec<(usize, usize)> = Input::parse(sections[0], "\n", |line| {
     let coords = Input::parse(line.trim(), ",", |coord| { coord.parse::<usize>().unwrap() } );
@@ -22,80 +24,52 @@ fn parse(data: &String) -> (Vec<(usize, usize)>, Vec<(&str, usize)>) {
   (coordinates, folds)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 13,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Ready points
-      let mut points = data.0;
-      
-      // Perform first fold
-      fold(&mut points, data.1[0]);
+    // Ready points
+    let mut points = data.0;
+    
+    // Perform first fold
+    fold(&mut points, data.1[0]);
 
-      // Remove duplicate points
-      points.sort();
-      points.dedup();
+    // Remove duplicate points
+    points.sort();
+    points.dedup();
 
-      // Calculate and return result
-      String::from(format!("{:?}", points.len()))
-    }
-
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", points.len()))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 13,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Ready points
-      let mut points = data.0;
-      
-      // Perform folds
-      for i in 0..data.1.len() {
-        fold(&mut points, data.1[i]);
-      }
-
-      // Remove duplicate points
-      points.sort();
-      points.dedup();
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
+
+    // Ready points
+    let mut points = data.0;
+    
+    // Perform folds
+    for i in 0..data.1.len() {
+      fold(&mut points, data.1[i]);
+    }
 
-      // Display points
-      DotDisplay::print_binary(points);
+    // Remove duplicate points
+    points.sort();
+    points.dedup();
 
-      // Calculate and return result
-      String::from(format!("{}", "PGHZBFJC"))
-    }
+    // Visualize points
+    context.frame(|| DotDisplay::render_binary(points.clone()));
 
-  );
+    // Read and return result
+    Ocr::read_points(&points).map_err(PuzzleError::InvalidInput)
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Performs a fold on the plain of 2D points
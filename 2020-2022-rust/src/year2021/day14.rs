@@ -5,95 +5,73 @@
 
 // Include dependencies
 use std::collections::hash_map::*;
+use regex::Regex;
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
-
-/// Parses input data
-fn parse(data: &String) -> (Vec<char>, HashMap<(char, char), char>) {
-  let sections = Input::parse(data.trim(), "\n\n", |section| { section.trim() });
-  let compound: Vec<char> = Input::parse(sections[0], "", |el| { el.chars().next().unwrap() });
-  let mut reactions: HashMap<(char, char), char> = HashMap::new();
-  let reaction_pairs = Input::parse(sections[1], "\n", |reaction| {
-    let mut reaction = Input::parse(reaction.trim(), "->", |els| { els.trim().chars() } );
-    ((reaction[0].next().unwrap(), reaction[0].next().unwrap()), reaction[1].next().unwrap())
-  });
-  for i in 0..reaction_pairs.len() {
-    reactions.insert(reaction_pairs[i].0, reaction_pairs[i].1);
-  }
-  (compound, reactions)
+use crate::lib::error::*;
+
+/// Reaction rules, producing an element to insert between each pair of elements
+type Reactions = HashMap<(char, char), char>;
+
+/// Parses input data into compound elements and reaction rules
+fn parse(data: &str) -> Result<(Vec<char>, Reactions), PuzzleError> {
+  let sections = Parser::new(data).sections();
+  let (compound, reactions) = match sections.as_slice() {
+    [compound, reactions] => (compound, reactions),
+    _ => return Err(PuzzleError::Parse(String::from("Expected a compound, followed by a list of reactions")))
+  };
+  let compound: Vec<char> = compound.grid(|el| Some(el).filter(char::is_ascii_uppercase))?.concat();
+  let regex = Regex::new(r"^([A-Z])([A-Z]) -> ([A-Z])$").unwrap();
+  let reactions = reactions.parse_lines(|reaction| {
+    let (first, second, reactant): (char, char, char) = reaction.trim().capture(&regex)?;
+    Ok(((first, second), reactant))
+  })?;
+  Ok((compound, reactions.into_iter().collect()))
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
+// Registers puzzles for the day
+puzzles! {
+
   // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 14,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize count
-      let mut count = init_count(&data.0);
-
-      // Run 10 steps of reactions
-      for _ in 0..10 {
-        react(&mut count, &data.1);
-      }
-
-      // Count individual elements
-      let count = count_elements(&count);
-
-      // Calculate and return result
-      String::from(format!("{:?}", count[count.len() - 1] - count[0]))
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Initialize count
+    let mut count = init_count(&data.0);
+
+    // Run 10 steps of reactions
+    for _ in 0..10 {
+      react(&mut count, &data.1);
     }
 
-  );
+    // Count individual elements
+    let count = count_elements(&count);
+
+    // Calculate and return result
+    Ok(format!("{:?}", count[count.len() - 1] - count[0]))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 14,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize count
-      let mut count = init_count(&data.0);
-
-      // Run 10 steps of reactions
-      for _ in 0..40 {
-        react(&mut count, &data.1);
-      }
-
-      // Count individual elements
-      let count = count_elements(&count);
-
-      // Calculate and return result
-      String::from(format!("{:?}", count[count.len() - 1] - count[0]))
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Initialize count
+    let mut count = init_count(&data.0);
+
+    // Run 10 steps of reactions
+    for _ in 0..40 {
+      react(&mut count, &data.1);
     }
 
-  );
+    // Count individual elements
+    let count = count_elements(&count);
+
+    // Calculate and return result
+    Ok(format!("{:?}", count[count.len() - 1] - count[0]))
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Initializes element pairs count hashmap
@@ -5,172 +5,80 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
-use crate::lib::input::*;
+use crate::lib::error::*;
 use crate::year::lib::matrix::*;
+use crate::year::lib::grid::*;
+use crate::year::lib::graph::*;
 
 /// Parses input data
-fn parse(data: &String) -> Vec<Vec<usize>> {
-  Input::parse(data.trim(), "\n", |line| {
-    Input::parse(line.trim(), "", |n| { n.parse::<usize>().unwrap() })
-  })
+fn parse(data: &str) -> Result<Grid<usize>, PuzzleError> {
+  Ok(Grid::parse(data, |c| c.to_digit(10).map(|risk| risk as usize))?)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 15,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Initialize a matrix for the data
-      let matrix = Matrix::new(vec![data[0].len(), data.len()]);
-      let mut vector: Vec<usize> = Vec::with_capacity(matrix.length);
-      for y in 0..matrix.dimensions[1] {
-        for x in 0..matrix.dimensions[0] {
-          vector.push(data[y][x]);
-        }
-      }
-
-      // Generate distance map
-      let distances = find_distances(&vector, &matrix, 1);
+  // Part I
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
 
-      // Calculate and return result
-      String::from(format!("{:?}", distances.last().unwrap()))
-    }
+    // Find least risky path
+    let distance = find_distance(&data, 1);
 
-  );
+    // Calculate and return result
+    Ok(format!("{:?}", distance))
+  },
 
   // Part II
-  registry.register(
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
 
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 15,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a matrix for the data
-      let matrix = Matrix::new(vec![data[0].len(), data.len()]);
-      let mut vector: Vec<usize> = Vec::with_capacity(matrix.length);
-      for y in 0..matrix.dimensions[1] {
-        for x in 0..matrix.dimensions[0] {
-          vector.push(data[y][x]);
-        }
-      }
+    // Find least risky path
+    let distance = find_distance(&data, 5);
 
-      // Generate distance map
-      let distances = find_distances(&vector, &matrix, 5);
-
-      // Calculate and return result
-      String::from(format!("{:?}", distances.last().unwrap()))
-    }
-
-  );
+    // Calculate and return result
+    Ok(format!("{:?}", distance))
+  }
 
-  // Return registry ownership
-  registry
 }
 
-/// Generates a 2D distance map of all points on the full, scaled map
+/// Finds the lowest total risk of any path from the top left to the bottom right of the full, scaled map
 /// 
 /// # Arguments
-/// * vector:       Vector of 2D data of original risk factors
-/// * data_matrix:  Matrix instance describing the 2D data
-/// * scale:        Scale of the full map relative to the data section
+/// * data:   Grid of original risk factors
+/// * scale:  Scale of the full map relative to the data section
 /// 
 /// # Returns
-/// 2D distance map of all points on the full, scaled map
-fn find_distances (vector: &Vec<usize>, data_matrix: &Matrix, scale: usize) -> Vec<usize> {
-  // Initialize distances
-  let mut distances: Vec<usize> = Vec::with_capacity(data_matrix.length * scale * scale);
-  let distances_matrix: Matrix = Matrix::new(vec![data_matrix.dimensions[0] * scale, data_matrix.dimensions[1] * scale]);
-  
-  distances.push(0);
-  for _ in 1..(data_matrix.length * scale * scale) {
-    distances.push(10 * data_matrix.length * scale * scale);
-  }
-
-  // Iterate on optimizing distance values
-  let mut optimizations_found = true;
-  while optimizations_found {
-    // Reset optimizations found
-    optimizations_found = false;
-
-    // Search for optimizations for every point
-    for i in 0..(data_matrix.length * scale * scale) {
-
-      // Get coordinates of current point
-      let coords = distances_matrix.index_to_coords(&i).unwrap();
-
-      // Get value of corresponding data field
-      let data_x = coords[0] % data_matrix.dimensions[0];
-      let data_correction_x = coords[0] / data_matrix.dimensions[0];
-      let data_y = coords[1] % data_matrix.dimensions[1];
-      let data_correction_y = coords[1] / data_matrix.dimensions[1];
-      let data_index = data_matrix.coords_to_index(&vec![data_x, data_y]).unwrap();
-      let data_value = (vector[data_index] + data_correction_x + data_correction_y - 1) % 9 + 1;
-
-      // Check optimization based on relative point: top
-      if coords[1] > 0 {
-        let top_coords = vec![coords[0], coords[1] - 1];
-        let top_index = distances_matrix.coords_to_index(&top_coords).unwrap();
-        if distances[top_index] + data_value < distances[i] {
-          distances[i] = distances[top_index] + data_value;
-          optimizations_found = true;
-        }
-      }
-
-      // Check optimization based on relative point: left
-      if coords[0] > 0 {
-        let left_coords = vec![coords[0] - 1, coords[1]];
-        let left_index = distances_matrix.coords_to_index(&left_coords).unwrap();
-        if distances[left_index] + data_value < distances[i] {
-          distances[i] = distances[left_index] + data_value;
-          optimizations_found = true;
-        }
-      }
-
-      // Check optimization based on relative point: right
-      if coords[0] < distances_matrix.dimensions[0] - 1 {
-        let left_coords = vec![coords[0] + 1, coords[1]];
-        let left_index = distances_matrix.coords_to_index(&left_coords).unwrap();
-        if distances[left_index] + data_value < distances[i] {
-          distances[i] = distances[left_index] + data_value;
-          optimizations_found = true;
-        }
-      }
-
-      // Check optimization based on relative point: bottom
-      if coords[1] < distances_matrix.dimensions[1] - 1 {
-        let top_coords = vec![coords[0], coords[1] + 1];
-        let top_index = distances_matrix.coords_to_index(&top_coords).unwrap();
-        if distances[top_index] + data_value < distances[i] {
-          distances[i] = distances[top_index] + data_value;
-          optimizations_found = true;
-        }
-      }
-    }
-  }
+/// Lowest total risk of any path through the full, scaled map
+fn find_distance (data: &Grid<usize>, scale: usize) -> usize {
+  // Initialize full map
+  let distances_matrix: Matrix = Matrix::new(vec![data.width() * scale, data.height() * scale]);
+  let end = distances_matrix.length - 1;
+  let end_coords = distances_matrix.index_to_coords(&end).unwrap();
+
+  // Get risk of entering a point on the full map
+  let risk = |index: usize| {
+    let coords = distances_matrix.index_to_coords(&index).unwrap();
+    let data_x = coords[0] % data.width();
+    let data_correction_x = coords[0] / data.width();
+    let data_y = coords[1] % data.height();
+    let data_correction_y = coords[1] / data.height();
+    (data[(data_x, data_y)] + data_correction_x + data_correction_y - 1) % 9 + 1
+  };
+
+  // Search for the least risky path (every point costing at least 1, manhattan distance never overestimates)
+  let search = Graph::a_star(
+    0,
+    |index| distances_matrix.neighbours(*index, false).map(|neighbour| (neighbour, risk(neighbour))).collect::<Vec<(usize, usize)>>(),
+    |index| {
+      let coords = distances_matrix.index_to_coords(index).unwrap();
+      (end_coords[0] - coords[0]) + (end_coords[1] - coords[1])
+    },
+    |index| *index == end
+  );
 
-  // Return calculated distances
-  distances
+  // Return found distance
+  search.target_distance().unwrap()
 }
@@ -5,11 +5,12 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
+use crate::lib::context::*;
 use crate::lib::input::*;
 use crate::year2021::lib::sparse_point_cloud::*;
 
 /// Parses input data
-fn parse(data: &String) -> Vec<Vec<Vec<isize>>> {
+fn parse(data: &str) -> Vec<Vec<Vec<isize>>> {
   Input::parse(data.trim(), "\n\n", |scanner| {
     Input::parse(scanner.trim(), "\n", |line| {
       Input::parse(line.trim(), ",", |n| {
@@ -22,79 +23,51 @@ fn parse(data: &String) -> Vec<Vec<Vec<isize>>> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 19,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-      
-      // Merge all data intoa single point cloud
-      let clouds = create_clouds(&data);
-      let merged = merge_all(&clouds);
-
-      // Calculate and return result
-      String::from(format!("{:?}", merged.len()))
-    }
+// Registers puzzles for the day
+puzzles! {
 
-  );
+  // Part I
+  1 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
+    
+    // Merge all data intoa single point cloud
+    let clouds = create_clouds(&data);
+    let merged = merge_all(&clouds, context);
+
+    // Calculate and return result
+    Ok(format!("{:?}", merged.len()))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 19,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Merge all data intoa single point cloud
-      let clouds = create_clouds(&data);
-      let merged = merge_all(&clouds);
-      let origins = merged.origins();
-
-      // Find largest distance between origin points
-      let mut max_manhattan_dist: usize = 0;
-      for i in 0..(origins.len() - 1) {
-        for j in i..origins.len() {
-          let mut manhattan_dist: usize = 0;
-          for k in 0..3 {
-            manhattan_dist += (origins[i][k] - origins[j][k]).abs() as usize;
-          }
-          if manhattan_dist > max_manhattan_dist {
-            max_manhattan_dist = manhattan_dist;
-          }
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
+
+    // Merge all data intoa single point cloud
+    let clouds = create_clouds(&data);
+    let merged = merge_all(&clouds, context);
+    let origins = merged.origins();
+
+    // Find largest distance between origin points
+    let mut max_manhattan_dist: usize = 0;
+    for i in 0..(origins.len() - 1) {
+      for j in i..origins.len() {
+        let mut manhattan_dist: usize = 0;
+        for k in 0..3 {
+          manhattan_dist += (origins[i][k] - origins[j][k]).abs() as usize;
+        }
+        if manhattan_dist > max_manhattan_dist {
+          max_manhattan_dist = manhattan_dist;
         }
       }
-
-      
-      // Calculate and return result
-      String::from(format!("{:?}", max_manhattan_dist))
     }
 
-  );
+    
+    // Calculate and return result
+    Ok(format!("{:?}", max_manhattan_dist))
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Creates point clouds from input data
@@ -119,13 +92,14 @@ fn create_clouds (data: &Vec<Vec<Vec<isize>>>) -> Vec<SparsePointCloud> {
 /// Converts data into point clouds and merges everything it can
 /// 
 /// # Arguments
-/// * data: Input data
+/// * clouds:  Point clouds to merge
+/// * context: Execution context, to log progress to
 /// 
 /// # Returns
 /// A tuple consisting of:
 /// - A single point cloud with all the data merged into it
 /// - Sparse cloud collection of all transformed clouds transformed in a way they were when merged
-fn merge_all (clouds: &Vec<SparsePointCloud>) -> SparsePointCloud {
+fn merge_all (clouds: &Vec<SparsePointCloud>, context: &PuzzleContext) -> SparsePointCloud {
 
   // Get all transformations for each cloud
   let mut tcollections: Vec<(TransformedSparsePointCloudCollection, bool)> = clouds.iter()
@@ -144,7 +118,7 @@ fn merge_all (clouds: &Vec<SparsePointCloud>) -> SparsePointCloud {
 
         // Quick check if merge is possible
         let quick_check = TransformedSparsePointCloudCollection::quick_test_merge(&tcollections[i].0, &tcollections[j].0);
-        println!("... quick check merge {}x{} with quick check result: {} > 77?", i, j, quick_check.len());
+        context.log(format!("... quick check merge {}x{} with quick check result: {} > 77?", i, j, quick_check.len()));
         if quick_check.len() < (12 * 11 / 2) { continue; }
 
         // Try merging any of the transformations around each of the quick cehck candidates
@@ -172,9 +146,9 @@ fn merge_all (clouds: &Vec<SparsePointCloud>) -> SparsePointCloud {
               merge_found = true;
 
               // Prompt status
-              println!(">>> MERGED:    {} ({:?}) + {} ({:?}) - fit: {}", i, &merge_attempt.2.transformations, j, &merge_attempt.3.transformations, merge_attempt.1);
+              context.log(format!(">>> MERGED:    {} ({:?}) + {} ({:?}) - fit: {}", i, &merge_attempt.2.transformations, j, &merge_attempt.3.transformations, merge_attempt.1));
               // println!("    Quick distance: {} / {}", k + 1, quick_check.len());
-              println!("    REMAINING: {}", tcollections.iter().filter(|c| !c.1).collect::<Vec<&(TransformedSparsePointCloudCollection, bool)>>().len());
+              context.log(format!("    REMAINING: {}", tcollections.iter().filter(|c| !c.1).collect::<Vec<&(TransformedSparsePointCloudCollection, bool)>>().len()));
             }
           }
         }
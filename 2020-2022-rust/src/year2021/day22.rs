@@ -34,110 +34,82 @@ fn parse(data: &String) -> Vec<(bool, Vec<Vec<isize>>)> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
+// Registers puzzles for the day
+puzzles! {
+
   // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 22,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize cuboid space
-      let mut space = GeometryCuboidSpace::new(3);
-
-      // Add and remove cuboids from the input
-      for i in 0..data.len() {
-
-        // Instantiate the cuboid volume
-        let cuboid = GeometryCuboid::new(
-          &GeometryPoint { coords: data[i].1[0].clone() },
-          &GeometryPoint { coords: data[i].1[1].clone() }
-        );
-
-        // Check if within bounds
-        let mut out_of_bounds = false;
-        for j in 0..data[i].1.len() {
-          if out_of_bounds { break; }
-          for k in 0..data[i].1[j].len() {
-            if data[i].1[j][k] < -50 || data[i].1[j][k] > 50 {
-              out_of_bounds = true;
-              break;
-            }
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize cuboid space
+    let mut space = GeometryCuboidSpace::new(3);
+
+    // Add and remove cuboids from the input
+    for i in 0..data.len() {
+
+      // Instantiate the cuboid volume
+      let cuboid = GeometryCuboid::new(
+        &GeometryPoint { coords: data[i].1[0].clone() },
+        &GeometryPoint { coords: data[i].1[1].clone() }
+      );
+
+      // Check if within bounds
+      let mut out_of_bounds = false;
+      for j in 0..data[i].1.len() {
+        if out_of_bounds { break; }
+        for k in 0..data[i].1[j].len() {
+          if data[i].1[j][k] < -50 || data[i].1[j][k] > 50 {
+            out_of_bounds = true;
+            break;
           }
         }
-        if out_of_bounds { continue; }
-
-        // Add cuboid volume
-        if data[i].0 {
-          space.add(&cuboid);
-        }
-        // Subtract cuboid volume
-        else {
-          space.subtract(&cuboid);
-        }
       }
+      if out_of_bounds { continue; }
 
-      // Calculate and return result
-      String::from(format!("{:?}", space.get_volume()))
+      // Add cuboid volume
+      if data[i].0 {
+        space.add(&cuboid);
+      }
+      // Subtract cuboid volume
+      else {
+        space.subtract(&cuboid);
+      }
     }
 
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", space.get_volume()))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 22,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize cuboid space
-      let mut space = GeometryCuboidSpace::new(3);
-
-      // Add and remove cuboids from the input
-      for i in 0..data.len() {
-
-        // Instantiate the cuboid volume
-        let cuboid = GeometryCuboid::new(
-          &GeometryPoint { coords: data[i].1[0].clone() },
-          &GeometryPoint { coords: data[i].1[1].clone() }
-        );
-
-        // Add cuboid volume
-        if data[i].0 {
-          space.add(&cuboid);
-        }
-        // Subtract cuboid volume
-        else {
-          space.subtract(&cuboid);
-        }
-      }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize cuboid space
+    let mut space = GeometryCuboidSpace::new(3);
+
+    // Add and remove cuboids from the input
+    for i in 0..data.len() {
 
-      // Calculate and return result
-      String::from(format!("{:?}", space.get_volume()))
+      // Instantiate the cuboid volume
+      let cuboid = GeometryCuboid::new(
+        &GeometryPoint { coords: data[i].1[0].clone() },
+        &GeometryPoint { coords: data[i].1[1].clone() }
+      );
+
+      // Add cuboid volume
+      if data[i].0 {
+        space.add(&cuboid);
+      }
+      // Subtract cuboid volume
+      else {
+        space.subtract(&cuboid);
+      }
     }
 
-  );
+    // Calculate and return result
+    String::from(format!("{:?}", space.get_volume()))
+  }
 
-  // Return registry ownership
-  registry
 }
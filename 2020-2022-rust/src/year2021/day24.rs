@@ -34,73 +34,46 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
+use crate::lib::context::*;
 use crate::lib::input::*;
 use crate::year2021::lib::alu::*;
 
 /// Parses input data
-fn parse(data: &String) -> Vec<Vec<String>> {
+fn parse(data: &str) -> Vec<Vec<String>> {
   Input::parse(data.trim(), "\n", |line| {
     Input::parse(line.trim(), " ", |symbol| symbol.to_string())
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 24,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Find first valid serial number (in descending order)
-      let serial = find_first_serial(&data, true);
-      let serial_string = serial.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("");
+  // Part I
+  1 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
 
-      // Calculate and return result
-      String::from(format!("{}", serial_string))
-    }
+    // Find first valid serial number (in descending order)
+    let serial = find_first_serial(&data, true, context);
+    let serial_string = serial.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("");
 
-  );
+    // Calculate and return result
+    Ok(format!("{}", serial_string))
+  },
 
   // Part II
-  registry.register(
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
 
-    // Info
-    PuzzleInfo {
-      year: 2021,
-      day: 24,
-      index: 2,
-      tag: String::from("puzzle")
-    },
+    // Find first valid serial number (in descending order)
+    let serial = find_first_serial(&data, false, context);
+    let serial_string = serial.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("");
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Find first valid serial number (in descending order)
-      let serial = find_first_serial(&data, false);
-      let serial_string = serial.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("");
-
-      // Calculate and return result
-      String::from(format!("{}", serial_string))
-    }
-
-  );
+    // Calculate and return result
+    Ok(format!("{}", serial_string))
+  }
 
-  // Return registry ownership
-  registry
 }
 
 /// Finds and returns first valid 14 digit serial number
@@ -108,11 +81,12 @@ pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
 /// # Arguments
 /// * data:       Input data, ALU program to validate serial numbers
 /// * high_first: If serials should be checked lowest-to-highest, or highest-to-lowest
+/// * context:    Execution context, to log found solutions to
 /// 
 /// # Returns
 /// First valid 14 digit serial number
-fn find_first_serial (data: &Vec<Vec<String>>, high_first: bool) -> Vec<usize> {
-  let all_serials = find_serials(data, high_first, |serial| serial.len() != 14);
+fn find_first_serial (data: &Vec<Vec<String>>, high_first: bool, context: &PuzzleContext) -> Vec<usize> {
+  let all_serials = find_serials(data, high_first, |serial| serial.len() != 14, context);
   all_serials[0].clone()
 }
 
@@ -123,10 +97,11 @@ fn find_first_serial (data: &Vec<Vec<String>>, high_first: bool) -> Vec<usize> {
 /// * high_first: If serials should be checked lowest-to-highest, or highest-to-lowest
 /// * callback:   Callback function that is executed every time a valid serial number is found. If the callback retuns false,
 ///               finding further serial numbers is stopped.
+/// * context:    Execution context, to log found solutions to
 /// 
 /// # Returns
 /// All valid 14 digit serial numbers, or numbers found up to the point of execution being stopped by the callback function
-fn find_serials (data: &Vec<Vec<String>>, high_first: bool, callback: fn(serial: &Vec<usize>) -> bool) -> Vec<Vec<usize>> {
+fn find_serials (data: &Vec<Vec<String>>, high_first: bool, callback: fn(serial: &Vec<usize>) -> bool, context: &PuzzleContext) -> Vec<Vec<usize>> {
   // Initialize the ALU
   let mut alu = ALU::new('w'..('w' as u8 + 4) as char);
   alu.load(data);
@@ -218,7 +193,7 @@ fn find_serials (data: &Vec<Vec<String>>, high_first: bool, callback: fn(serial:
                         inputs_to_keep.push(inputs[i]);
                       }
                       // Prompt found solution
-                      println!("Found a solution to get z==0 for step #{} ({:?}): {:?} + {:?}", 1 + (ec / 18), instruction, optimized_inputs, inputs_to_keep);
+                      context.log(format!("Found a solution to get z==0 for step #{} ({:?}): {:?} + {:?}", 1 + (ec / 18), instruction, optimized_inputs, inputs_to_keep));
                       // Return solution
                       return Some(inputs_to_keep);
                     }
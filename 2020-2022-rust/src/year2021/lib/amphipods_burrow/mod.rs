@@ -11,7 +11,8 @@
 // 4:     .   .   .   .
 
 // Include dependencies
-use std::collections::hash_map::*;
+use crate::lib::context::*;
+use crate::year::lib::graph::Graph;
 
 /// Amphipod structur
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -22,7 +23,7 @@ pub struct Amphipod {
 }
 
 /// Amphipods burrow structure
-#[derive(Clone)]
+#[derive(Clone, Hash, PartialEq, Eq)]
 pub struct AmphipodsBurrow {
   amphipods: Vec<Amphipod>,
   room_size: usize,
@@ -52,43 +53,16 @@ impl AmphipodsBurrow {
     }
   }
 
-  /// Recursivelly organizes amphipods and keeps track of minimal number of moves needed to reorganize all amphipods
-  /// 
-  /// # Arguments
-  /// * burrow:   State of the burroww to organize from
-  /// * cache:    Cache object to store calculation results and avoid recalculating same state multiple times
-  /// * depth:    Recursive depth
-  /// * verbose:  If true, all full state will be output aftre each step
+  /// Lists all burrow states reachable by moving a single amphipod
   /// 
   /// Returns
-  /// Option of minimal number of moves needed to reorganize all amphipods, or NOne if not able to organize
-  fn organize_internal(burrow: &mut AmphipodsBurrow, cache: &mut HashMap<Vec<Amphipod>, Option<usize>>, depth: usize, verbose: bool) -> Option<usize> {
-    // Print burrow state
-    if verbose {
-      println!("{}", burrow.to_string());
-      println!("");
-    }
-
-    // Check if all amphipods in final state
-    match burrow.amphipods.iter().find(|a| !a.finished) {
-      None => {
-        return Some(0)
-      },
-      _ => ()
-    }
-
-    // Check if state has a cached value
-    match cache.get(&burrow.amphipods) {
-      Some(count) => { return count.clone(); },
-      None => ()
-    }
-    
-    // Initialize minimal count
-    let mut count: Option<usize> = None;
+  /// Reachable burrow states, each with the energy needed to reach it
+  fn moves (&self) -> Vec<(AmphipodsBurrow, usize)> {
+    let mut moves: Vec<(AmphipodsBurrow, usize)> = vec![];
 
     // Try all possible moves for all amphipods
-    for i in 0..burrow.amphipods.len() {
-      let amphipod = &burrow.amphipods[i];
+    for i in 0..self.amphipods.len() {
+      let amphipod = &self.amphipods[i];
       let amphipod_energy_per_step = if amphipod.color == 'A' {
           1
         } else if amphipod.color == 'B' {
@@ -108,43 +82,32 @@ impl AmphipodsBurrow {
       if amphipod.position.1 == 0 {
         // Get destination based on color and if destination room is already partly full
         let destination: (usize, usize) = match amphipod.color {
-          'A' => (2, burrow.room_size - burrow.room_ocupancy[0]),
-          'B' => (4, burrow.room_size - burrow.room_ocupancy[1]),
-          'C' => (6, burrow.room_size - burrow.room_ocupancy[2]),
-          'D' => (8, burrow.room_size - burrow.room_ocupancy[3]),
+          'A' => (2, self.room_size - self.room_ocupancy[0]),
+          'B' => (4, self.room_size - self.room_ocupancy[1]),
+          'C' => (6, self.room_size - self.room_ocupancy[2]),
+          'D' => (8, self.room_size - self.room_ocupancy[3]),
           _ => panic!("Unknown amphipod color found!")
         };
         // Check if destination taken
-        let destination_available = match burrow.amphipods.iter().find(|a| a.position.0 == destination.0 && a.position.1 <= destination.1) { None => true, _=> false };
+        let destination_available = match self.amphipods.iter().find(|a| a.position.0 == destination.0 && a.position.1 <= destination.1) { None => true, _=> false };
         if destination_available {
           // Check if path clear to destination
           let path_clear_option = if amphipod.position.0 < destination.0 {
-            burrow.amphipods.iter().find(|a| a.position.1 == 0 && a.position.0 > amphipod.position.0 && a.position.0 < destination.0)
+            self.amphipods.iter().find(|a| a.position.1 == 0 && a.position.0 > amphipod.position.0 && a.position.0 < destination.0)
           } else {
-            burrow.amphipods.iter().find(|a| a.position.1 == 0 && a.position.0 > destination.0 && a.position.0 < amphipod.position.0)
+            self.amphipods.iter().find(|a| a.position.1 == 0 && a.position.0 > destination.0 && a.position.0 < amphipod.position.0)
           };
           match path_clear_option {
             // If path clear, go home
             None => {
               // Move 
-              let mut next = burrow.clone();
+              let mut next = self.clone();
               let path_energy = amphipod_energy_per_step * (destination.1 + (amphipod.position.0 as isize - destination.0 as isize).abs() as usize);
               next.amphipods[i].position.0 = destination.0;
               next.amphipods[i].position.1 = destination.1;
               next.amphipods[i].finished = true;
               next.room_ocupancy[destination.0 / 2 - 1] += 1;
-              // Recursively reorganize
-              match AmphipodsBurrow::organize_internal(&mut next, cache, depth + 1, verbose) {
-                // Found organized position
-                Some(c_next) => {
-                  match count {
-                    Some(c_current) => { if c_next + path_energy < c_current { count = Some(c_next + path_energy); } },
-                    None => count = Some(c_next + path_energy)
-                  }
-                },
-                // Didn't find organized position
-                None => ()
-              }
+              moves.push((next, path_energy));
             },
             _ => ()
           }
@@ -153,15 +116,15 @@ impl AmphipodsBurrow {
 
       // Check if positioned inside a room
       let is_in_room = amphipod.position.1 != 0;
-      let is_not_blocked_in_room = match burrow.amphipods.iter().find(|a| a.position.0 == amphipod.position.0 && a.position.1 < amphipod.position.1) { None => true, _ => false };
+      let is_not_blocked_in_room = match self.amphipods.iter().find(|a| a.position.0 == amphipod.position.0 && a.position.1 < amphipod.position.1) { None => true, _ => false };
       if is_in_room && is_not_blocked_in_room {        
         // Search for amphipod blocking hallway to the left
         let mut x_leftmost_free = 0;
-        for j in 0..burrow.amphipods.len() {
+        for j in 0..self.amphipods.len() {
           // Check if in hallway
-          if burrow.amphipods[j].position.1 != 0 { continue; }
+          if self.amphipods[j].position.1 != 0 { continue; }
           // Check if blocking the path
-          let ax = burrow.amphipods[j].position.0;
+          let ax = self.amphipods[j].position.0;
           if ax + 1 <= amphipod.position.0 && ax + 1 > x_leftmost_free {
             x_leftmost_free = ax + 1;
           }
@@ -171,30 +134,19 @@ impl AmphipodsBurrow {
           // Check if allowed position in hallway
           if x == 2 || x == 4 || x == 6 || x == 8 { continue; }
           // Move 
-          let mut next = burrow.clone();
+          let mut next = self.clone();
           let path_energy = amphipod_energy_per_step * (amphipod.position.1 + (amphipod.position.0 - x));
           next.amphipods[i].position.0 = x;
           next.amphipods[i].position.1 = 0;
-          // Recursively reorganize
-          match AmphipodsBurrow::organize_internal(&mut next, cache, depth + 1, verbose) {
-            // Found organized position
-            Some(c_next) => {
-              match count {
-                Some(c_current) => { if c_next + path_energy < c_current { count = Some(c_next + path_energy); } },
-                None => count = Some(c_next + path_energy)
-              }
-            },
-            // Didn't find organized position
-            None => ()
-          }
+          moves.push((next, path_energy));
         }
         // Search for amphipod blocking hallway to the right
         let mut x_rightmost_free = 10;
-        for j in 0..burrow.amphipods.len() {
+        for j in 0..self.amphipods.len() {
           // Check if in hallway
-          if burrow.amphipods[j].position.1 != 0 { continue; }
+          if self.amphipods[j].position.1 != 0 { continue; }
           // Check if blocking the path
-          let ax = burrow.amphipods[j].position.0;
+          let ax = self.amphipods[j].position.0;
           if ax >= amphipod.position.0 + 1 && ax < x_rightmost_free + 1 {
             x_rightmost_free = ax - 1;
           }
@@ -204,41 +156,27 @@ impl AmphipodsBurrow {
           // Check if allowed position in hallway
           if x == 2 || x == 4 || x == 6 || x == 8 { continue; }
           // Move 
-          let mut next = burrow.clone();
+          let mut next = self.clone();
           let path_energy = amphipod_energy_per_step * (amphipod.position.1 + (x - amphipod.position.0));
           next.amphipods[i].position.0 = x;
           next.amphipods[i].position.1 = 0;
-          // Recursively reorganize
-          match AmphipodsBurrow::organize_internal(&mut next, cache, depth + 1, verbose) {
-            // Found organized position
-            Some(c_next) => {
-              match count {
-                Some(c_current) => { if c_next + path_energy < c_current { count = Some(c_next + path_energy); } },
-                None => count = Some(c_next + path_energy)
-              }
-            },
-            // Didn't find organized position
-            None => ()
-          }
+          moves.push((next, path_energy));
         }
       }
     }
 
-    // Cache result    
-    cache.insert(burrow.amphipods.clone(), count);
-
-    // Return minimal found count
-    count
+    // Return all possible moves
+    moves
   }
 
-  /// Starts a recursive organization process and returns a minimal number of moves needed to reorganize all amphipods
+  /// Starts an organization process and returns a minimal number of moves needed to reorganize all amphipods
   /// 
   /// # Arguments
-  /// * verbose: If true, all full state will be output aftre each step
+  /// * context: Execution context, visualizing the full state after each step
   /// 
   /// # Returns
   /// Minimal number of moves needed to reorganize all amphipods
-  pub fn organize (&mut self, verbose: bool) -> usize {
+  pub fn organize (&mut self, context: &PuzzleContext) -> usize {
     // Check any that can stay in place
     for y in (1..(self.room_size + 1)).rev() {
       for i in 0..self.amphipods.len() {
@@ -261,8 +199,19 @@ impl AmphipodsBurrow {
         }
       }
     }
-    // Recursivelly organize
-    match AmphipodsBurrow::organize_internal(self, &mut HashMap::new(), 0, verbose) {
+    // Search for the least energy consuming sequence of moves organizing all amphipods
+    let search = Graph::dijkstra(
+      self.clone(),
+      |burrow| burrow.moves(),
+      |burrow| burrow.amphipods.iter().all(|a| a.finished)
+    );
+    // Visualize all burrow states along the way
+    if context.is_visualizing() {
+      for burrow in search.target_path().unwrap_or_default() {
+        context.frame(|| burrow.to_string());
+      }
+    }
+    match search.target_distance() {
       Some(count) => count,
       None => panic!("Failed to organize amphipods!")
     }
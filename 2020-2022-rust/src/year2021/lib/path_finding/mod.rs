@@ -53,35 +53,153 @@ impl<T> PathFinding<T> where T: std::cmp::Eq + std::hash::Hash + Copy + std::fmt
     }
   }
 
-  /// Finds all paths between 2 points on the graph
+  /// Lazily enumerates all paths between 2 points on the graph
   /// 
   /// # Arguments
-  /// * start:    Starting point on the graph
-  /// * end:      Ending point on the graph
-  /// * cache:    Shared cache, shared between callback executions
-  /// * path:     Currently searching path (When running should just pass vec![])
-  /// * paths:    Paths found so far (When running should just pass vec![])
-  /// * callback: Callback function, called for every point before moving to it, should return if point is allowed to be navigated to
+  /// * start:  Starting point on the graph
+  /// * end:    Ending point on the graph
+  /// * state:  Initial state, passed to the visit rule when visiting the starting point
+  /// * visit:  Rule called for every point before moving to it, receiving the point, the path leading to it and the
+  ///   state of the path so far; should return the state after visiting the point, or `None` if point isn't allowed
   /// 
   /// # Returns
-  /// All found paths
-  pub fn find_all_paths<C> (&self, start: T, end: T, cache: &mut C, path: &mut Vec<T>, paths: &mut Vec<Vec<T>>, callback: fn(key: &T, path: &mut Vec<T>, cache: &mut C) -> bool) {
-    // Check if allowed
-    if callback(&start, path, cache) {
-      // Append current path
-      path.push(start);
-      // If reached end, add to paths
-      if start == end {
-        paths.push(path.clone());
+  /// Iterator over all found paths
+  pub fn paths<S, F> (&self, start: T, end: T, state: S, visit: F) -> Paths<'_, T, S, F> where F: FnMut(&T, &[T], &S) -> Option<S> {
+    Paths {
+      connections: &self.connections,
+      end,
+      visit,
+      pending: Some((start, state)),
+      stack: vec![],
+      path: vec![]
+    }
+  }
+
+}
+
+/// Paths iterator struct
+/// 
+/// Walks the graph depth first, keeping only the path currently being walked in memory
+pub struct Paths<'a, T, S, F> where T: std::cmp::Eq + std::hash::Hash + Copy + std::fmt::Debug, F: FnMut(&T, &[T], &S) -> Option<S> {
+  // Traversable connections in the graph
+  connections: &'a HashMap<T, Vec<T>>,
+  // Ending point on the graph
+  end: T,
+  // Rule called for every point before moving to it
+  visit: F,
+  // Starting point and initial state, until walking starts
+  pending: Option<(T, S)>,
+  // State of the path and index of the next connection to try, for every point of the current path
+  stack: Vec<(S, usize)>,
+  // Currently walked path
+  path: Vec<T>
+}
+/// Paths iterator implementation
+impl<'a, T, S, F> Paths<'a, T, S, F> where T: std::cmp::Eq + std::hash::Hash + Copy + std::fmt::Debug, F: FnMut(&T, &[T], &S) -> Option<S> {
+
+  /// Walks the graph until the next path reaching the ending point is found
+  /// 
+  /// # Returns
+  /// If a path was found (found path is held in `.path`)
+  fn advance (&mut self) -> bool {
+    // Start walking from the starting point
+    if let Some((start, state)) = self.pending.take() {
+      if let Some(state) = (self.visit)(&start, &self.path, &state) {
+        self.stack.push((state, 0));
+        self.path.push(start);
+        if start == self.end {
+          return true;
+        }
+      }
+    }
+    // Walk until next path is found or all paths have been walked
+    while let Some(point) = self.path.last().copied() {
+      // Get next connection to try (points without connections are dead ends)
+      let destinations = self.connections.get(&point).map_or(&[][..], |destinations| &destinations[..]);
+      let index = self.stack.last().unwrap().1;
+      // Step back if reached end or tried all connections
+      if point == self.end || index >= destinations.len() {
+        self.stack.pop();
+        self.path.pop();
+        continue;
       }
-      // If not reached end, continue to all possible connections
-      else {
-        let destinations = self.connections.get(&start).unwrap();
-        for i in 0..destinations.len() {
-          self.find_all_paths(destinations[i], end, cache, &mut path.clone(), paths, callback);
+      self.stack.last_mut().unwrap().1 += 1;
+      // Step forward if allowed
+      let destination = destinations[index];
+      if let Some(state) = (self.visit)(&destination, &self.path, &self.stack.last().unwrap().0) {
+        self.stack.push((state, 0));
+        self.path.push(destination);
+        if destination == self.end {
+          return true;
         }
       }
     }
+    false
+  }
+
+}
+impl<'a, T, S, F> Iterator for Paths<'a, T, S, F> where T: std::cmp::Eq + std::hash::Hash + Copy + std::fmt::Debug, F: FnMut(&T, &[T], &S) -> Option<S> {
+  type Item = Vec<T>;
+
+  fn next (&mut self) -> Option<Vec<T>> {
+    if self.advance() { Some(self.path.clone()) } else { None }
+  }
+
+  /// Counts paths without materializing them
+  fn count (mut self) -> usize {
+    let mut count = 0;
+    while self.advance() {
+      count += 1;
+    }
+    count
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Visit rule allowing every point to be visited only once per path
+  fn once<T: PartialEq> (point: &T, path: &[T], _: &()) -> Option<()> {
+    if path.contains(point) { None } else { Some(()) }
+  }
+
+  #[test]
+  fn skips_dead_ends () {
+    // Only "d" leads on from "b" to the end, "c" and "e" are dead ends
+    let graph = PathFinding::new(vec![("a", "b"), ("b", "c"), ("b", "d"), ("d", "end"), ("c", "e")]);
+    let paths: Vec<Vec<&str>> = graph.paths("a", "end", (), once).collect();
+    assert_eq!(paths, vec![vec!["a", "b", "d", "end"]]);
+    // Unconnected ending or starting points can't be reached
+    assert_eq!(graph.paths("a", "x", (), once).count(), 0);
+    assert_eq!(graph.paths("x", "end", (), once).count(), 0);
+  }
+
+  #[test]
+  fn finds_single_path_from_start_to_itself () {
+    let graph = PathFinding::new(vec![("a", "b"), ("b", "a")]);
+    let paths: Vec<Vec<&str>> = graph.paths("a", "a", (), once).collect();
+    assert_eq!(paths, vec![vec!["a"]]);
+    // Starting point disallowed by the visit rule yields no paths
+    assert_eq!(graph.paths("a", "a", (), |_, _, _| None::<()>).count(), 0);
+  }
+
+  #[test]
+  fn counts_as_many_paths_as_collected () {
+    // Small caves (lower case) can be visited only once, big caves (upper case) any number of times
+    let graph = PathFinding::new(vec![("start", "A"), ("start", "b"), ("A", "c"), ("A", "b"), ("b", "d"), ("A", "end"), ("b", "end")]);
+    let visit = |point: &&str, path: &[&str], _: &()| {
+      if point.chars().all(|c| c.is_lowercase()) && path.contains(point) { None } else { Some(()) }
+    };
+    let paths: Vec<Vec<&str>> = graph.paths("start", "end", (), visit).collect();
+    assert_eq!(paths.len(), 10);
+    assert_eq!(graph.paths("start", "end", (), visit).count(), paths.len());
+    // Every path is unique, and runs from the starting to the ending point
+    assert!(paths.iter().all(|path| path.first() == Some(&"start") && path.last() == Some(&"end")));
+    let mut unique = paths.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), paths.len());
   }
 
 }
@@ -68,81 +68,53 @@ fn evaluate_item_priority(item: u8) -> u8 {
   }
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 3,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-      let rucksacks = data_to_rucksacks(data);
-
-      // Find and sum up duplicate priorities
-      let mut sum: usize = 0;
-      for rucksack in rucksacks {
-        let duplicates = find_duplicates_in_rucksack(&rucksack);
-        for item in duplicates {
-          sum += evaluate_item_priority(item) as usize
-        }
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+    let rucksacks = data_to_rucksacks(data);
+
+    // Find and sum up duplicate priorities
+    let mut sum: usize = 0;
+    for rucksack in rucksacks {
+      let duplicates = find_duplicates_in_rucksack(&rucksack);
+      for item in duplicates {
+        sum += evaluate_item_priority(item) as usize
       }
-
-      // Return result
-      String::from(format!("{:?}", sum))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", sum))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 3,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Process all rucksacks and sum up group tags
-      let mut sum: usize = 0;
-      for i in 0..(data.len() / 3) {
-        // Group rucksacks into groups of 3
-        let rucksacks = (
-          get_compartment_items_count_by_type(&data[3 * i + 0]),
-          get_compartment_items_count_by_type(&data[3 * i + 1]),
-          get_compartment_items_count_by_type(&data[3 * i + 2])
-        );
-        // Find common item in group
-        for i in 0..256 {
-          if rucksacks.0[i] > 0 && rucksacks.1[i] > 0 && rucksacks.2[i] > 0 {
-            sum += evaluate_item_priority(i as u8) as usize;
-            break;
-          }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Process all rucksacks and sum up group tags
+    let mut sum: usize = 0;
+    for i in 0..(data.len() / 3) {
+      // Group rucksacks into groups of 3
+      let rucksacks = (
+        get_compartment_items_count_by_type(&data[3 * i + 0]),
+        get_compartment_items_count_by_type(&data[3 * i + 1]),
+        get_compartment_items_count_by_type(&data[3 * i + 2])
+      );
+      // Find common item in group
+      for i in 0..256 {
+        if rucksacks.0[i] > 0 && rucksacks.1[i] > 0 && rucksacks.2[i] > 0 {
+          sum += evaluate_item_priority(i as u8) as usize;
+          break;
         }
       }
-
-      // Return result
-      String::from(format!("{:?}", sum))
     }
 
-  );
+    // Return result
+    String::from(format!("{:?}", sum))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -6,16 +6,13 @@
 // Include dependencies
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
+use crate::lib::error::*;
 
 /// Parses input data
-fn parse(data: &String) -> Vec<Vec<Vec<usize>>> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
-    Input::parse(data, ",", |data| {
-      Input::parse(data, "-", |x| {
-        x.parse::<usize>().unwrap()
-      })
-    })
-  })
+fn parse(data: &str) -> Result<Vec<Vec<Vec<usize>>>, PuzzleError> {
+  Ok(Parser::new(data).parse_lines(|pair| {
+    pair.split(",").iter().map(|range| range.integers::<usize>()).collect()
+  })?)
 }
 
 fn calculate_length (range: (usize, usize)) -> usize {
@@ -30,73 +27,45 @@ fn calculate_overlap (range_1: (usize, usize), range_2: (usize, usize)) -> usize
   }
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 4,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Count completely overlapping regions
-      let mut count = 0;
-      for pairs in data {
-        let range_1_len = calculate_length((pairs[0][0], pairs[0][1]));        
-        let range_2_len = calculate_length((pairs[1][0], pairs[1][1]));
-        let ranges_overlap = calculate_overlap((pairs[0][0], pairs[0][1]), (pairs[1][0], pairs[1][1]));
-        if ranges_overlap == range_1_len || ranges_overlap == range_2_len {
-          count += 1;
-        }
+  // Part I
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Count completely overlapping regions
+    let mut count = 0;
+    for pairs in data {
+      let range_1_len = calculate_length((pairs[0][0], pairs[0][1]));        
+      let range_2_len = calculate_length((pairs[1][0], pairs[1][1]));
+      let ranges_overlap = calculate_overlap((pairs[0][0], pairs[0][1]), (pairs[1][0], pairs[1][1]));
+      if ranges_overlap == range_1_len || ranges_overlap == range_2_len {
+        count += 1;
       }
-
-      // Return result
-      String::from(format!("{:?}", count))
     }
 
-  );
+    // Return result
+    Ok(format!("{:?}", count))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 4,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Count completely overlapping regions
-      let mut count = 0;
-      for pairs in data {
-        let ranges_overlap = calculate_overlap((pairs[0][0], pairs[0][1]), (pairs[1][0], pairs[1][1]));
-        if ranges_overlap > 0 {
-          count += 1;
-        }
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Count completely overlapping regions
+    let mut count = 0;
+    for pairs in data {
+      let ranges_overlap = calculate_overlap((pairs[0][0], pairs[0][1]), (pairs[1][0], pairs[1][1]));
+      if ranges_overlap > 0 {
+        count += 1;
       }
-      
-      // Return result
-      String::from(format!("{:?}", count))
     }
+    
+    // Return result
+    Ok(format!("{:?}", count))
+  }
 
-  );
-
-  // Return registry ownership
-  registry
 }
@@ -4,122 +4,87 @@
 // -----------------------------------------------------------------------------
 
 // Include dependencies
+use regex::Regex;
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
-
-/// Parses input data
-fn parse(data: &String) -> (Vec<Vec<String>>, Vec<(usize, usize, usize)>) {
-  let data = Input::parse(data, "\n\n", |data| data);
-  // Parse stacks' levels
-  let levels =     Input::parse(data[0], "\n", |data| data ).iter()
-    .filter(|row| {
-      row.contains("[") && row.contains("]")
-    })
-    .map(|row| {
-      (0..((row.len() as f32 / 4.0).ceil() as usize))
-        .map(|i| row.clone()[(4 * i)..(4 * (i+1)).min(row.len() - 1)].to_string().trim().replace("[", "").replace("]", ""))
-        .collect()
-    })
-    .collect();
-  // Parse moves
-  let moves = Input::parse(data[1].trim(), "\n", |data| {
-    let data = Input::parse(data, " ", |data| data);
-    (data[1].parse::<usize>().unwrap(), data[3].parse::<usize>().unwrap(), data[5].parse::<usize>().unwrap())
-  });
-  (levels, moves)
-}
-
-fn levels_to_stacks (levels: &Vec<Vec<String>>) -> Vec<Vec<&str>> {
-  // Initialize stacks
-  let mut stacks: Vec<Vec<&str>> = vec![vec![]; levels[0].len()];
-  // Process levels into stacks
-  for i in (0..levels.len()).rev() {
-    for j in 0..levels[i].len() {
-      if levels[i][j] != "" {
-        stacks[j].push(levels[i][j].as_str());
+use crate::lib::error::*;
+
+/// Parses input data into stacks (bottom to top) and moves (count, from stack, to stack)
+fn parse(data: &str) -> Result<(Vec<Vec<char>>, Vec<(usize, usize, usize)>), PuzzleError> {
+  let sections = Parser::new(data).sections();
+  let (drawing, moves) = match sections.as_slice() {
+    [drawing, moves] => (drawing, moves),
+    _ => return Err(PuzzleError::Parse(String::from("Expected a drawing of stacks, followed by a list of moves")))
+  };
+  // Parse stacks' levels (crates are drawn every 4 columns, above a line numbering the stacks)
+  let mut levels = drawing.lines();
+  let numbering = levels.pop().ok_or_else(|| drawing.error(String::from("Expected a drawing of stacks")))?;
+  let mut stacks: Vec<Vec<char>> = vec![vec![]; numbering.integers::<usize>()?.len()];
+  for level in levels.iter().rev() {
+    let level = level.grid(Some)?.concat();
+    for (i, stack) in stacks.iter_mut().enumerate() {
+      if let Some(item) = level.get(4 * i + 1).filter(|item| item.is_ascii_alphabetic()) {
+        stack.push(*item);
       }
     }
   }
-  // Return stacks
-  stacks
+  // Parse moves
+  let regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+  let moves = moves.parse_lines(|line| {
+    let mv: (usize, usize, usize) = line.trim().capture(&regex)?;
+    if mv.1 < 1 || mv.1 > stacks.len() || mv.2 < 1 || mv.2 > stacks.len() {
+      return Err(line.trim().error(format!("Move between stacks {} and {}, expected stacks 1 to {}", mv.1, mv.2, stacks.len())));
+    }
+    Ok(mv)
+  })?;
+  Ok((stacks, moves))
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 5,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+/// Collects top items of all stacks
+fn collect_tops (stacks: &[Vec<char>]) -> String {
+  stacks.iter().filter_map(|stack| stack.last()).collect()
+}
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let (levels, moves) = parse(&data);
-      let mut stacks = levels_to_stacks(&levels);
+// Registers puzzles for the day
+puzzles! {
 
-      // Execute moves
-      for mv in moves {
-        for _ in 0..mv.0 {
-          let item = stacks[mv.1 - 1].pop().unwrap();
-          stacks[mv.2 - 1].push(item);
-        }
+  // Part I
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let (mut stacks, moves) = parse(data)?;
+
+    // Execute moves
+    for mv in moves {
+      for _ in 0..mv.0 {
+        let item = stacks[mv.1 - 1].pop().unwrap();
+        stacks[mv.2 - 1].push(item);
       }
-
-      // Collect top items
-      let tops = stacks.iter().map(|stack| stack.last().unwrap().clone()).collect::<Vec<&str>>().join("");
-
-      // Return result
-      String::from(format!("{}", tops))
     }
 
-  );
+    // Collect top items and return result
+    Ok(collect_tops(&stacks))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 5,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let (levels, moves) = parse(&data);
-      let mut stacks = levels_to_stacks(&levels);
-
-      // Execute moves
-      for mv in moves {
-        let mut crane: Vec<&str> = vec![];
-        for _ in 0..mv.0 {
-          let item = stacks[mv.1 - 1].pop().unwrap();
-          crane.push(item);
-        }
-        for _ in 0..mv.0 {
-          let item = crane.pop().unwrap();
-          stacks[mv.2 - 1].push(item);
-        }
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let (mut stacks, moves) = parse(data)?;
+
+    // Execute moves
+    for mv in moves {
+      let mut crane: Vec<char> = vec![];
+      for _ in 0..mv.0 {
+        let item = stacks[mv.1 - 1].pop().unwrap();
+        crane.push(item);
+      }
+      for _ in 0..mv.0 {
+        let item = crane.pop().unwrap();
+        stacks[mv.2 - 1].push(item);
       }
-
-      // Collect top items
-      let tops = stacks.iter().map(|stack| stack.last().unwrap().clone()).collect::<Vec<&str>>().join("");
-
-      // Return result
-      String::from(format!("{}", tops))
     }
 
-  );
+    // Collect top items and return result
+    Ok(collect_tops(&stacks))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -7,10 +7,11 @@
 use std::collections::HashSet;
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
+use crate::lib::error::*;
 
 /// Parses input data
-fn parse(data: &String) -> Vec<&str> {
-  Input::parse(data.as_str().trim(), "", |data| data)
+fn parse(data: &str) -> Vec<&str> {
+  Input::parse(data.trim(), "", |data| data)
 }
 
 fn find_start_packet_position<'a> (data: Vec<&'a str>, packet_len: usize, hash: &mut HashSet<&'a str>) -> Option<usize> {
@@ -52,59 +53,37 @@ fn find_duplicates<'a> (buffer: &Vec<&'a str>, hash: &mut HashSet<&'a str>) -> b
   // false
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 6,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+  // Part I
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
 
-      // Initialize a global hash set for determining duplicates
-      let mut hash: HashSet<&str> = HashSet::with_capacity(4);
+    // Initialize a global hash set for determining duplicates
+    let mut hash: HashSet<&str> = HashSet::with_capacity(4);
 
-      // Return result
-      String::from(format!("{:?}", find_start_packet_position(data, 4, &mut hash).unwrap() + 1))
+    // Return result
+    match find_start_packet_position(data, 4, &mut hash) {
+      Some(position) => Ok(format!("{:?}", position + 1)),
+      None => Err(PuzzleError::NoSolution(String::from("No start marker of 4 distinct characters found")))
     }
-
-  );
+  },
 
   // Part II
-  registry.register(
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data);
 
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 6,
-      index: 2,
-      tag: String::from("puzzle")
-    },
+    // Initialize a global hash set for determining duplicates
+    let mut hash: HashSet<&str> = HashSet::with_capacity(14);
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a global hash set for determining duplicates
-      let mut hash: HashSet<&str> = HashSet::with_capacity(14);
-
-      // Return result
-      String::from(format!("{:?}", find_start_packet_position(data, 14, &mut hash).unwrap() + 1))
+    // Return result
+    match find_start_packet_position(data, 14, &mut hash) {
+      Some(position) => Ok(format!("{:?}", position + 1)),
+      None => Err(PuzzleError::NoSolution(String::from("No start marker of 14 distinct characters found")))
     }
+  }
 
-  );
-
-  // Return registry ownership
-  registry
 }
@@ -5,89 +5,59 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
-use crate::lib::input::*;
+use crate::lib::error::*;
 use crate::year2022::lib::dos::DOS;
+use crate::year2022::lib::dos::gpu::*;
 
-/// Parses input data
-fn parse(data: &String) -> Vec<&str> {
-  Input::parse(data.as_str().trim(), "\n", |data| data)
-}
+// Registers puzzles for the day
+puzzles! {
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
   // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 10,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize DOS
-      let mut dos = DOS::new();
-      dos.gpu.process_controller_stdin(&data);
-
-      // Run GPU
-      let mut signal_sum: isize = 0;
-      for (i, registers) in dos.gpu.into_iter() {
-        if [20, 60, 100, 140, 180, 220].contains(&i) {
-          signal_sum += (i as isize) * registers[0];
-        }
-      }
-
-      // Return result
-      String::from(format!("{:?}", signal_sum))
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Initialize DOS and load program
+    let mut dos = DOS::new();
+    dos.gpu.load(data)?;
+
+    // Run GPU, breaking on cycles the signal is sampled on
+    for clock in [20, 60, 100, 140, 180, 220].iter() {
+      dos.gpu.break_on(GpuBreakpoint::Clock(*clock)).map_err(PuzzleError::InvalidInput)?;
+    }
+    let mut signal_sum: isize = 0;
+    while let GpuHalt::Breakpoint(_) = dos.gpu.run() {
+      let cycle = dos.gpu.last_cycle().unwrap();
+      signal_sum += (cycle.clock as isize) * cycle.registers[0];
     }
 
-  );
+    // Return result
+    Ok(signal_sum.to_string())
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 10,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize DOS
-      let mut dos = DOS::new();
-      dos.gpu.process_controller_stdin(&data);
-
-      // Run GPU ad connect output to screen
-      for (i, registers) in dos.gpu.into_iter() {
-        // Execute for 240 cycles
-        if i > 240 { break; }
-        // Forward GPU output to screen
-        dos.screen.update_sprite_position(registers[0]);
-        dos.screen.draw_pixel();
-      }
-
-      // Output result to screen
-      dos.screen.prompt();
-
-      // Return result
-      String::from(format!("{}", "EKRHEPUZ"))
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Initialize DOS and load program
+    let mut dos = DOS::new();
+    dos.gpu.load(data)?;
+    dos.gpu.tracing = context.verbose;
+
+    // Run GPU ad connect output to screen
+    let pixels = dos.screen.width * dos.screen.height;
+    for (i, registers) in dos.gpu.by_ref() {
+      // Execute for a cycle per pixel of the screen
+      if i > pixels { break; }
+      // Forward GPU output to screen
+      dos.screen.update_sprite_position(registers[0]);
+      dos.screen.draw_pixel();
+    }
+    for trace in dos.gpu.take_trace() {
+      context.log(trace.to_string());
     }
 
-  );
+    // Visualize screen
+    let screen = &dos.screen;
+    context.frame(|| screen.render());
+
+    // Read and return result
+    screen.read().map_err(PuzzleError::InvalidInput)
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -51,95 +51,67 @@ fn parse<'a>(data: &'a String) -> Vec<PlayerState<'a>> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
+// Registers puzzles for the day
+puzzles! {
+
   // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 11,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-      
-      // Initialize Keep-Away
-      let mut keepaway = KeepAway::new(data, 3);
-
-      // Play for 20 rounds and keep track of how active each monkey is
-      let rounds_count = 20;
-      let mut monkeys: Vec<usize> = vec![0; keepaway.players.len()];
-      for i in 0..(rounds_count * keepaway.players.len()) {
-        // Check active monkey activity in next round
-        let index = i % keepaway.players.len();
-        monkeys[index] += keepaway.players[index].items.len();
-        // Play next round's next monkey
-        keepaway.execute_turn();
-      }
-
-      // Find top 2 active monkeys
-      let mut monkeys_sorted = monkeys.clone();
-      monkeys_sorted.sort();
-      monkeys_sorted.reverse();
-      // Calculate level of monkey bussiness
-      let monkey_bussiness = monkeys_sorted[0] * monkeys_sorted[1];
-
-      // Return result
-      String::from(format!("{:?}", monkey_bussiness))
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+    
+    // Initialize Keep-Away
+    let mut keepaway = KeepAway::new(data, 3);
+
+    // Play for 20 rounds and keep track of how active each monkey is
+    let rounds_count = 20;
+    let mut monkeys: Vec<usize> = vec![0; keepaway.players.len()];
+    for i in 0..(rounds_count * keepaway.players.len()) {
+      // Check active monkey activity in next round
+      let index = i % keepaway.players.len();
+      monkeys[index] += keepaway.players[index].items.len();
+      // Play next round's next monkey
+      keepaway.execute_turn();
     }
 
-  );
+    // Find top 2 active monkeys
+    let mut monkeys_sorted = monkeys.clone();
+    monkeys_sorted.sort();
+    monkeys_sorted.reverse();
+    // Calculate level of monkey bussiness
+    let monkey_bussiness = monkeys_sorted[0] * monkeys_sorted[1];
+
+    // Return result
+    String::from(format!("{:?}", monkey_bussiness))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 11,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-      
-      // Initialize Keep-Away
-      let mut keepaway = KeepAway::new(data, 0);
-
-      // Play for 20 rounds and keep track of how active each monkey is
-      let rounds_count = 10000;
-      let mut monkeys: Vec<usize> = vec![0; keepaway.players.len()];
-      for i in 0..(rounds_count * keepaway.players.len()) {
-        // Check active monkey activity in next round
-        let index = i % keepaway.players.len();
-        monkeys[index] += keepaway.players[index].items.len();
-        // Play next round's next monkey
-        keepaway.execute_turn();
-      }
-
-      // Find top 2 active monkeys
-      let mut monkeys_sorted = monkeys.clone();
-      monkeys_sorted.sort();
-      monkeys_sorted.reverse();
-      // Calculate level of monkey bussiness
-      let monkey_bussiness = monkeys_sorted[0] * monkeys_sorted[1];
-
-      // Return result
-      String::from(format!("{:?}", monkey_bussiness))
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+    
+    // Initialize Keep-Away
+    let mut keepaway = KeepAway::new(data, 0);
+
+    // Play for 20 rounds and keep track of how active each monkey is
+    let rounds_count = 10000;
+    let mut monkeys: Vec<usize> = vec![0; keepaway.players.len()];
+    for i in 0..(rounds_count * keepaway.players.len()) {
+      // Check active monkey activity in next round
+      let index = i % keepaway.players.len();
+      monkeys[index] += keepaway.players[index].items.len();
+      // Play next round's next monkey
+      keepaway.execute_turn();
     }
 
-  );
+    // Find top 2 active monkeys
+    let mut monkeys_sorted = monkeys.clone();
+    monkeys_sorted.sort();
+    monkeys_sorted.reverse();
+    // Calculate level of monkey bussiness
+    let monkey_bussiness = monkeys_sorted[0] * monkeys_sorted[1];
+
+    // Return result
+    String::from(format!("{:?}", monkey_bussiness))
+  }
 
-  // Return registry ownership
-  registry
 }
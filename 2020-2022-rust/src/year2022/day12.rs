@@ -5,93 +5,64 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
-use crate::lib::input::*;
+use crate::lib::error::*;
+use crate::year::lib::grid::*;
 use crate::year2022::lib::hill_climb::*;
 
 /// Parses input data
-fn parse<'a>(data: &'a String) -> Vec<Vec<&str>> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
-    Input::parse(data, "", |x| x)
-  })
+fn parse(data: &str) -> Result<Grid<char>, PuzzleError> {
+  Ok(Grid::parse(data, |c| if c.is_ascii_lowercase() || c == 'S' || c == 'E' { Some(c) } else { None })?)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 12,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize hill climb heightmap
-      let mut hillclimb = HillClimb::new(
-        data,
-        |_, from_height, to_height| from_height + 1 >= to_height,
-        |hillclimb, coords, _| coords[0] == hillclimb.end[0] && coords[1] == hillclimb.end[1]
-      );
-
-      // Calculate distances from start point
-      let start = &hillclimb.start.clone();
-      let distances = hillclimb.calculate_distances(start);
-
-      // Get end point index and distance
-      let end = &hillclimb.end.clone();
-      let end_index = hillclimb.heightmap.coords_to_index(end).unwrap();
-      let distance = distances[end_index];
+// Registers puzzles for the day
+puzzles! {
 
-      // Return result
-      String::from(format!("{:?}", distance))
-    }
-
-  );
+  // Part I
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Initialize hill climb heightmap
+    let mut hillclimb = HillClimb::new(
+      &data,
+      |_, from_height, to_height| from_height + 1 >= to_height,
+      |hillclimb, coords, _| coords[0] == hillclimb.end[0] && coords[1] == hillclimb.end[1]
+    );
+
+    // Calculate distances from start point
+    let start = &hillclimb.start.clone();
+    let distances = hillclimb.calculate_distances(start);
+
+    // Get end point index and distance
+    let end = &hillclimb.end.clone();
+    let end_index = hillclimb.heightmap.matrix.coords_to_index(end).unwrap();
+    let distance = distances[end_index];
+
+    // Return result
+    Ok(format!("{:?}", distance))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 12,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize hill climb heightmap
-      let mut hillclimb = HillClimb::new(
-        data,
-        |_, from_height, to_height| to_height + 1 >= from_height,
-        |_, _, height| height == 'a' as usize - 96
-      );
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
 
-      // Calculate distances from start point
-      let end = &hillclimb.end.clone();
-      let distances = hillclimb.calculate_distances(end);
+    // Initialize hill climb heightmap
+    let mut hillclimb = HillClimb::new(
+      &data,
+      |_, from_height, to_height| to_height + 1 >= from_height,
+      |_, _, height| height == 'a' as usize - 96
+    );
 
-      // Find max found distance
-      let distance = distances.iter().filter(|x| x != &&usize::MAX).max().unwrap();
+    // Calculate distances from start point
+    let end = &hillclimb.end.clone();
+    let distances = hillclimb.calculate_distances(end);
 
-      // Return result
-      String::from(format!("{:?}", distance))
-    }
+    // Find max found distance
+    let distance = distances.iter().filter(|x| x != &&usize::MAX).max().unwrap();
 
-  );
+    // Return result
+    Ok(format!("{:?}", distance))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -4,80 +4,56 @@
 // -----------------------------------------------------------------------------
 
 // Include dependencies
+use regex::Regex;
 use crate::lib::puzzle::*;
 use crate::lib::input::*;
+use crate::lib::error::*;
 use crate::year2022::lib::vulcano::Vulcano;
 
+/// Valve's name, flow rate and names of connected valves
+type Valve<'a> = (&'a str, usize, Vec<&'a str>);
+
 /// Parses input data
-fn parse<'a>(data: &'a String) -> Vec<(&str, usize, Vec<&str>)> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
-    let parsed: Vec<&str> = data.split(';').collect::<Vec<&str>>();
-    let name = &parsed[0][6..8];
-    let flow_rate = (&parsed[0].split('=').last().unwrap().parse::<usize>().unwrap()).clone();
-    let connections = (&parsed[1][23..].trim().split(',').map(|c| c.trim().clone()).collect::<Vec<&str>>()).clone();
-    (name, flow_rate, connections)
-  })
+fn parse(data: &str) -> Result<Vec<Valve<'_>>, PuzzleError> {
+  let regex = Regex::new(r"^Valve (?P<name>\w+) has flow rate=(?P<rate>\d+); tunnels? leads? to valves? (?P<valves>.+)$").unwrap();
+  Ok(Parser::new(data).parse_lines(|valve| {
+    let captures = valve.trim().captures(&regex)?;
+    let connections = captures.name::<&str>("valves")?.split(',').map(|connection| connection.trim()).collect();
+    Ok((captures.name("name")?, captures.name("rate")?, connections))
+  })?)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 16,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Initialize a vulcano
-      let mut vulcano = Vulcano::new(data);
+  // Part I
+  1 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
 
-      // Calculate maximum release starting from valve "AA" within 30 minutes
-      let max = vulcano.calculate_max_release(vec!["AA"], 30);
-      
-      // Return result
-      String::from(format!("{:?}", max))
-    }
+    // Initialize a vulcano
+    let mut vulcano = Vulcano::new(data);
 
-  );
+    // Calculate maximum release starting from valve "AA" within 30 minutes
+    let max = vulcano.calculate_max_release(vec!["AA"], 30);
+    
+    // Return result
+    Ok(format!("{:?}", max))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 16,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a vulcano
-      let mut vulcano = Vulcano::new(data);
+  2 => |data: &str| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
 
-      // Calculate maximum release starting from valve "AA" within 30 minutes
-      let max = vulcano.calculate_max_release(vec!["AA", "AA"], 26);
-      
-      // Return result
-      String::from(format!("{:?}", max))
-    }
+    // Initialize a vulcano
+    let mut vulcano = Vulcano::new(data);
 
-  );
+    // Calculate maximum release starting from valve "AA" within 30 minutes
+    let max = vulcano.calculate_max_release(vec!["AA", "AA"], 26);
+    
+    // Return result
+    Ok(format!("{:?}", max))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -17,71 +17,43 @@ fn parse<'a>(data: &'a String) -> Vec<Vec<isize>> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 18,
-      index: 1,
-      tag: String::from("puzzle")
-    },
+// Registers puzzles for the day
+puzzles! {
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a voxel saoce
-      let mut voxel_space = VoxelSpace::new(data);
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Enumerae unique polygons
-      let polygons = voxel_space.enumerate_polygons();
+    // Initialize a voxel saoce
+    let mut voxel_space = VoxelSpace::new(data);
 
-      // Return result
-      String::from(format!("{:?}", polygons.len()))
-    }
+    // Enumerae unique polygons
+    let polygons = voxel_space.enumerate_polygons();
 
-  );
+    // Return result
+    String::from(format!("{:?}", polygons.len()))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 18,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize a voxel saoce
-      let mut voxel_space = VoxelSpace::new(data);
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
 
-      // Find fully enclosed voxels and fill them in
-      let fully_enclosed_voxels = voxel_space.find_fully_enclosed_voxels();
-      for fully_enclosed_voxel in fully_enclosed_voxels {
-        voxel_space.voxels.push(fully_enclosed_voxel);
-      }
+    // Initialize a voxel saoce
+    let mut voxel_space = VoxelSpace::new(data);
 
-      // Enumerae unique polygons
-      let polygons = voxel_space.enumerate_polygons();
-      
-      // Return result
-      String::from(format!("{:?}", polygons.len()))
+    // Find fully enclosed voxels and fill them in
+    let fully_enclosed_voxels = voxel_space.find_fully_enclosed_voxels();
+    for fully_enclosed_voxel in fully_enclosed_voxels {
+      voxel_space.voxels.push(fully_enclosed_voxel);
     }
 
-  );
+    // Enumerae unique polygons
+    let polygons = voxel_space.enumerate_polygons();
+    
+    // Return result
+    String::from(format!("{:?}", polygons.len()))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -9,8 +9,8 @@ use crate::lib::input::*;
 use crate::year2022::lib::mining_blueprint::*;
 
 /// Parses input data
-fn parse<'a>(data: &'a String) -> Vec<Vec<(&str, Vec<(usize, &str)>)>> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
+fn parse(data: &str) -> Vec<Vec<(&str, Vec<(usize, &str)>)>> {
+  Input::parse(data.trim(), "\n", |data| {
     data.split(':').collect::<Vec<&str>>()[1].split('.')
       .map(|recipe| recipe.trim())
       .filter(|recipe| recipe.len() > 0)
@@ -32,84 +32,56 @@ fn parse<'a>(data: &'a String) -> Vec<Vec<(&str, Vec<(usize, &str)>)>> {
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 19,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize all the blueprints
-      let blueprints = data.iter()
-        .enumerate()
-        .map(|(index, data)| MiningBlueprint::new(index + 1, data))
-        .collect::<Vec<MiningBlueprint>>();
+// Registers puzzles for the day
+puzzles! {
 
-      // Evaluate all the blueprints and find max
-      let max = blueprints.iter()
-        .map(|blueprint| {
-          let max = blueprint.evaluate("geode", 24);
-          // println!("Evaluated blueprint #{}: {}", blueprint.index, max);
-          blueprint.index * max
-        })
-        .sum::<usize>();
-
-      // Return result
-      String::from(format!("{:?}", max))
-    }
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize all the blueprints
+    let blueprints = data.iter()
+      .enumerate()
+      .map(|(index, data)| MiningBlueprint::new(index + 1, data))
+      .collect::<Vec<MiningBlueprint>>();
+
+    // Evaluate all the blueprints and find max
+    let max = blueprints.iter()
+      .map(|blueprint| {
+        let max = blueprint.evaluate("geode", 24);
+        // println!("Evaluated blueprint #{}: {}", blueprint.index, max);
+        blueprint.index * max
+      })
+      .sum::<usize>();
 
-  );
+    // Return result
+    String::from(format!("{:?}", max))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 19,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize all the blueprints
-      let blueprints = data[0..(if data.len() >= 3 { 3 } else { data.len() })].iter()
-        .enumerate()
-        .map(|(index, data)| MiningBlueprint::new(index + 1, data))
-        .collect::<Vec<MiningBlueprint>>();
-
-      // Evaluate all the blueprints and find max
-      let product = blueprints.iter()
-        .map(|blueprint| {
-          let max = blueprint.evaluate("geode", 32);
-          // println!("Evaluated blueprint #{}: {}", blueprint.index, max);
-          max
-        })
-        .reduce(|accum, item| accum * item)
-        .unwrap();
-
-      // Return result
-      String::from(format!("{:?}", product))
-    }
+  2 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Initialize all the blueprints
+    let blueprints = data[0..(if data.len() >= 3 { 3 } else { data.len() })].iter()
+      .enumerate()
+      .map(|(index, data)| MiningBlueprint::new(index + 1, data))
+      .collect::<Vec<MiningBlueprint>>();
+
+    // Evaluate all the blueprints and find max
+    let product = blueprints.iter()
+      .map(|blueprint| {
+        let max = blueprint.evaluate("geode", 32);
+        // println!("Evaluated blueprint #{}: {}", blueprint.index, max);
+        max
+      })
+      .reduce(|accum, item| accum * item)
+      .unwrap();
 
-  );
+    // Return result
+    String::from(format!("{:?}", product))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -5,98 +5,69 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
-use crate::lib::input::*;
+use crate::lib::error::*;
+use crate::year::lib::grid::*;
 use crate::year2022::lib::unstable_diffusion::UnstableDiffusion;
 
 /// Parses input data
-fn parse<'a>(data: &'a String) -> Vec<Vec<&str>> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
-    Input::parse(data, "", |data| {
-      data
-    })
-  })
+fn parse(data: &str) -> Result<Grid<bool>, PuzzleError> {
+  Ok(Grid::parse(data, |c| match c { '#' => Some(true), '.' => Some(false), _ => None })?)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 23,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize unstable diffusion
-      let mut ud = UnstableDiffusion::new(data);
-      // Play until stable
-      for _ in 0..10 {
-        // Play round
-        ud.play_round();
-
-        // Prompt
-        // ud._prompt();
-        // println!();
-      }
-
-      // Calculate empty spaces
-      let bounds = ud.get_bounds();
-      let spaces = bounds.0 * bounds.1;
-      let spaces_taken = ud.points.len();
-      let spaces_empty = spaces - spaces_taken;
+// Registers puzzles for the day
+puzzles! {
 
-      // Return result
-      String::from(format!("{:?}", spaces_empty))
+  // Part I
+  1 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Initialize unstable diffusion
+    let mut ud = UnstableDiffusion::new(&data);
+    context.frame(|| ud.render());
+    // Play 10 rounds
+    for _ in 0..10 {
+      // Play round
+      ud.play_round();
+
+      // Visualize
+      context.frame(|| ud.render());
     }
 
-  );
-
-  // Part II
-  registry.register(
+    // Calculate empty spaces
+    let bounds = ud.get_bounds();
+    let spaces = bounds.0 * bounds.1;
+    let spaces_taken = ud.points.len();
+    let spaces_empty = spaces - spaces_taken;
 
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 23,
-      index: 2,
-      tag: String::from("puzzle")
-    },
+    // Return result
+    Ok(String::from(format!("{:?}", spaces_empty)))
+  },
 
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-      
-      // Initialize unstable diffusion
-      let mut ud = UnstableDiffusion::new(data);
-      // Play until stable
-      let mut rounds_count = 0;
-      loop {
-        // Play until stable and count rounds
-        if ud.play_round() != 0 {
-          rounds_count += 1;
-        }
-        // Once stable, stop
-        else {
-          break;
-        }
+  // Part II
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+    
+    // Initialize unstable diffusion
+    let mut ud = UnstableDiffusion::new(&data);
+    context.frame(|| ud.render());
+    // Play until stable
+    let mut rounds_count = 0;
+    loop {
+      // Play until stable and count rounds
+      if ud.play_round() != 0 {
+        rounds_count += 1;
+        context.frame(|| ud.render());
+      }
+      // Once stable, stop
+      else {
+        break;
       }
-
-      // Return result
-      String::from(format!("{:?}", rounds_count + 1))
     }
 
-  );
+    // Return result
+    Ok(String::from(format!("{:?}", rounds_count + 1)))
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -5,78 +5,55 @@
 
 // Include dependencies
 use crate::lib::puzzle::*;
-use crate::lib::input::*;
+use crate::lib::error::*;
+use crate::year::lib::grid::*;
 use crate::year2022::lib::blizzards::BlizzardBasin;
 
 /// Parses input data
-fn parse<'a>(data: &'a String) -> Vec<Vec<char>> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
-    data.chars().collect::<Vec<char>>()
-  })
+fn parse(data: &str) -> Result<Grid<char>, PuzzleError> {
+  Ok(Grid::parse(data, |c| if "#.<>^v".contains(c) { Some(c) } else { None })?)
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 24,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Initialize blizzard basin
-      let mut blizzards = BlizzardBasin::new(data);
-
-      // Find quickest path
-      let steps = blizzards.traverse(blizzards.position_start.clone(), blizzards.position_end.clone(), 0);      
-      
-      // Return result
-      String::from(format!("{:?}", steps))
-    }
-
-  );
+  // Part I
+  1 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Initialize blizzard basin
+    let mut blizzards = BlizzardBasin::new(&data);
+
+    // Find quickest path
+    context.frame(|| blizzards.render(&blizzards.position_start, 0));
+    let steps = blizzards.traverse(blizzards.position_start.clone(), blizzards.position_end.clone(), 0);      
+    context.frame(|| blizzards.render(&blizzards.position_end, steps));
+    
+    // Return result
+    Ok(format!("{:?}", steps))
+  },
 
   // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 24,
-      index: 2,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
-
-      // Initialize blizzard basin
-      let mut blizzards = BlizzardBasin::new(data);
-
-      // Find quickest path
-      let steps_there                      = blizzards.traverse(blizzards.position_start.clone(), blizzards.position_end.clone(), 0);      
-      let steps_there_and_back             = blizzards.traverse(blizzards.position_end.clone(), blizzards.position_start.clone(), steps_there);      
-      let steps_there_back_and_there_again = blizzards.traverse(blizzards.position_start.clone(), blizzards.position_end.clone(), steps_there_and_back);      
-      
-      // Return result
-      // 819 => Too low!
-      String::from(format!("{:?}", steps_there_back_and_there_again))
-    }
-
-  );
+  2 => |data: &str, context: &PuzzleContext| -> Result<String, PuzzleError> {
+    // Process input data
+    let data = parse(data)?;
+
+    // Initialize blizzard basin
+    let mut blizzards = BlizzardBasin::new(&data);
+
+    // Find quickest path
+    context.frame(|| blizzards.render(&blizzards.position_start, 0));
+    let steps_there                      = blizzards.traverse(blizzards.position_start.clone(), blizzards.position_end.clone(), 0);      
+    context.frame(|| blizzards.render(&blizzards.position_end, steps_there));
+    let steps_there_and_back             = blizzards.traverse(blizzards.position_end.clone(), blizzards.position_start.clone(), steps_there);      
+    context.frame(|| blizzards.render(&blizzards.position_start, steps_there_and_back));
+    let steps_there_back_and_there_again = blizzards.traverse(blizzards.position_start.clone(), blizzards.position_end.clone(), steps_there_and_back);      
+    context.frame(|| blizzards.render(&blizzards.position_end, steps_there_back_and_there_again));
+    
+    // Return result
+    // 819 => Too low!
+    Ok(format!("{:?}", steps_there_back_and_there_again))
+  }
 
-  // Return registry ownership
-  registry
 }
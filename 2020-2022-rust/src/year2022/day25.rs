@@ -9,65 +9,37 @@ use crate::lib::input::*;
 use crate::year2022::lib::snafu::SNAFU;
 
 /// Parses input data
-fn parse<'a>(data: &'a String) -> Vec<&str> {
-  Input::parse(data.as_str().trim(), "\n", |data| {
+fn parse(data: &str) -> Vec<&str> {
+  Input::parse(data.trim(), "\n", |data| {
     data
   })
 }
 
-/// Registers puzzles for the day
-pub fn init (mut registry: PuzzleRegistry) -> PuzzleRegistry {
-  
-  // Part I
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 25,
-      index: 1,
-      tag: String::from("puzzle")
-    },
-
-    // Implementation
-    |data: String| {
-      // Process input data
-      let data = parse(&data);
+// Registers puzzles for the day
+puzzles! {
 
-      // Decode and sum all the numbers provided
-      let mut sum: isize = 0;
-      for num in data {
-        sum += SNAFU::decode(num);
-      }
-
-      // Encode the sum
-      let encoded = SNAFU::encode(sum);
-
-      // Return result
-      String::from(format!("{}", encoded))
+  // Part I
+  1 => |data: String| {
+    // Process input data
+    let data = parse(&data);
+
+    // Decode and sum all the numbers provided
+    let mut sum: isize = 0;
+    for num in data {
+      sum += SNAFU::decode(num);
     }
 
-  );
+    // Encode the sum
+    let encoded = SNAFU::encode(sum);
 
-  // Part II
-  registry.register(
-
-    // Info
-    PuzzleInfo {
-      year: 2022,
-      day: 25,
-      index: 2,
-      tag: String::from("puzzle")
-    },
+    // Return result
+    String::from(format!("{}", encoded))
+  },
 
-    // Implementation
-    |_| {
-      // Return result
-      String::from("Done!")
-    }
-
-  );
+  // Part II
+  2 => |_: String| {
+    // Return result
+    String::from("Done!")
+  }
 
-  // Return registry ownership
-  registry
 }
@@ -6,6 +6,8 @@
 // Include dependencies
 use std::collections::hash_set::HashSet;
 use crate::year::lib::math::Math;
+use crate::year::lib::graph::Graph;
+use crate::year::lib::grid::Grid;
 
 
 /// Blizzard Basin structure
@@ -19,60 +21,38 @@ pub struct BlizzardBasin {
   // Bounding dimensions for blizzards to move through
   blizzards_dimensions: Vec<usize>,
   // Blizard starting positions and speeds
-  blizzards: HashSet<(Vec<usize>, Vec<isize>)>,
-  // Keeps track of all previously tested positions at all previous times
-  _cache: HashSet<(Vec<usize>, usize)>
+  blizzards: HashSet<(Vec<usize>, Vec<isize>)>
 }
 
 /// Blizzard Basin implementation
 impl BlizzardBasin {
 
   /// Constructor
-  pub fn new (data: Vec<Vec<char>>) -> BlizzardBasin {
+  /// 
+  /// # Arguments
+  /// * data: Basin map, walled off except for a single gap in top and bottom rows
+  pub fn new (data: &Grid<char>) -> BlizzardBasin {
 
-    // Find starting position
-    let mut position_start_x: usize = 0;
-    for i in 0..data[0].len() {
-      if data[0][i] == '.' {
-        position_start_x = i;
-        break;
-      }
-    }
-    // Find ending position
-    let mut position_end_x: usize = 0;
-    for i in 0..data[data.len() - 1].len() {
-      if data[data.len() - 1][i] == '.' {
-        position_end_x = i;
-        break;
-      }
-    }
+    // Find starting and ending positions as gaps in the top and bottom walls
+    let position_start_x = data.row(0).iter().position(|c| *c == '.').unwrap_or(0);
+    let position_end_x = data.row(data.height() - 1).iter().position(|c| *c == '.').unwrap_or(0);
 
     // Get blizzard bounds and dimensions
-    let blizzards_bounds = vec![(1, data[0].len() - 1), (1, data.len() - 1)];
+    let blizzards_bounds = vec![(1, data.width() - 1), (1, data.height() - 1)];
     let blizzards_dimensions = vec![blizzards_bounds[0].1 - blizzards_bounds[0].0, blizzards_bounds[1].1 - blizzards_bounds[1].0];
 
-    // Initialize blizzards hashmap
+    // Initialize blizzards hashmap from the basin within the walls
     let mut blizzards: HashSet<(Vec<usize>, Vec<isize>)> = HashSet::new();
-    for y in blizzards_bounds[1].0..blizzards_bounds[1].1 {
-      for x in blizzards_bounds[0].0..blizzards_bounds[0].1 {
-        // Check if blizzard
-        if data[y][x] != '.' {
-
-          if data[y][x] == '<' {
-            blizzards.insert((vec![x, y], vec![-1,  0]));
-          }
-          else if data[y][x] == '>' {
-            blizzards.insert((vec![x, y], vec![ 1,  0]));
-          }
-          else if data[y][x] == '^' {
-            blizzards.insert((vec![x, y], vec![ 0, -1]));
-          }
-          else if data[y][x] == 'v' {
-            blizzards.insert((vec![x, y], vec![ 0,  1]));
-          }
-
-        }
-      }  
+    let basin = data.crop((blizzards_bounds[0].0, blizzards_bounds[1].0), blizzards_dimensions[0], blizzards_dimensions[1]);
+    for ((x, y), c) in basin.iter() {
+      let speed: Vec<isize> = match c {
+        '<' => vec![-1,  0],
+        '>' => vec![ 1,  0],
+        '^' => vec![ 0, -1],
+        'v' => vec![ 0,  1],
+        _ => continue
+      };
+      blizzards.insert((vec![x + blizzards_bounds[0].0, y + blizzards_bounds[1].0], speed));
     }
 
     // Return initialized Blizzard Basin instance
@@ -80,15 +60,13 @@ impl BlizzardBasin {
       // Starting player position
       position_start: vec![position_start_x, 0],
       // Starting player position
-      position_end: vec![position_end_x, data.len() - 1],
+      position_end: vec![position_end_x, data.height() - 1],
       // Bounding coordinates for blizzards to move through
       blizzards_bounds,
       // Bounding dimensions for blizzards to move through
       blizzards_dimensions,
       // Blizard starting positions indexed by path
-      blizzards,
-      // Keeps track of all previously tested positions at all previous times
-      _cache: HashSet::new()
+      blizzards
     }
   }
 
@@ -100,108 +78,61 @@ impl BlizzardBasin {
   /// * time_offset: Initial time offset
   /// 
   /// # Returns
-  /// Minimal number of steps it takes from start to end
+  /// Time at which the target is reached
   pub fn traverse (&mut self, start: Vec<usize>, target: Vec<usize>, time_offset: usize) -> usize {
-    // Find shortest possible path length
-    let limit = (self.position_end[0] as isize - self.position_start[0] as isize).abs() as usize
-              + (self.position_end[1] as isize - self.position_start[1] as isize).abs() as usize;
-
-    // Try finding a path with progressively more lax time limits
-    let mut limit_factor = 2;
-    loop {
-      self._cache.clear();
-      match self._traverse(start.clone(), target.clone(), time_offset, time_offset + limit * limit_factor) {
-        Some(steps) => return steps,
-        _ => ()
-      };
-      limit_factor += 1;
+    // Blizzards repeat their positions after a period of time
+    let period = Math::lcm(self.blizzards_dimensions[0], self.blizzards_dimensions[1]);
+    // Search through positions in time
+    match Graph::bfs_in_time(
+      start,
+      time_offset,
+      period,
+      |position, time| self.steps(position, time),
+      |position, _| position == &target
+    ) {
+      Some((_, time)) => time,
+      None => panic!("No path between start and target found!")
     }
   }
 
-  /// Finds the quickest path from start to end
+  /// Lists positions which can be moved to (or stayed at) from a position without colliding with a blizzard
   /// 
   /// # Arguments
   /// * position: Current position
-  /// * target: Target position
-  /// * time_offset: Time offset from starting
-  /// * time_limit: Maximum time a path is allowed to take
+  /// * time_offset: Current time offset
   /// 
   /// # Returns
-  /// Minimal number of steps it takes from start to end
-  fn _traverse (&mut self, position: Vec<usize>, target: Vec<usize>, time_offset: usize, time_limit: usize) -> Option<usize> {
-    // Limit length of the path
-    if time_offset > time_limit {
-      return Option::None;      
-    }
-    
-    // Check if collided with a blizzard
-    if self.check_coordinates_for_blizards(position.clone(), time_offset) > 0 {
-      return Option::None;
-    }
-
-    // Check if previously tested same "branch"
-    if self._cache.contains(&(position.clone(), time_offset)) {
-      return Option::None;
-    }
-    
-    // Cache current position and time
-    self._cache.insert((position.clone(), time_offset));
-
-    // Check if target position reached
-    if position == target {
-      return Some(time_offset);
-    }
-
-    // Initialize lowest steps count
-    let mut min_steps: usize = usize::MAX;
-
+  /// Positions which are free of blizzards at the following time offset
+  fn steps (&self, position: &[usize], time_offset: usize) -> Vec<Vec<usize>> {
+    let mut positions_next: Vec<Vec<usize>> = Vec::with_capacity(5);
     // Attempt a step down
     let position_next = vec![position[0], position[1] + 1];
     if position_next[1] < self.blizzards_bounds[1].1 || position_next == self.position_end {
-      match self._traverse(position_next, target.clone(), time_offset + 1, time_limit) {
-        Some(min_steps_candidate) => { if min_steps_candidate < min_steps { min_steps = min_steps_candidate; } },
-        _ => ()
-      };
+      positions_next.push(position_next);
     }
     // Check if inside the field and horizontal movement is allowed
     if position[1] >= self.blizzards_bounds[1].0 && position[1] < self.blizzards_bounds[1].1 {
       // Attempt a step right
-      let position_next = vec![position[0] + 1, position[1]];
-      if position_next[0] < self.blizzards_bounds[0].1 {
-        match self._traverse(position_next, target.clone(), time_offset + 1, time_limit) {
-          Some(min_steps_candidate) => { if min_steps_candidate < min_steps { min_steps = min_steps_candidate; } },
-          _ => ()
-        };
+      if position[0] + 1 < self.blizzards_bounds[0].1 {
+        positions_next.push(vec![position[0] + 1, position[1]]);
       }
       // Attempt a step left
-      if position[0] > 0 {
-        let position_next = vec![position[0] - 1, position[1]];
-        if position_next[0] >= self.blizzards_bounds[0].0 {
-          match self._traverse(position_next, target.clone(), time_offset + 1, time_limit) {
-            Some(min_steps_candidate) => { if min_steps_candidate < min_steps { min_steps = min_steps_candidate; } },
-            _ => ()
-          };
-        }
+      if position[0] > self.blizzards_bounds[0].0 {
+        positions_next.push(vec![position[0] - 1, position[1]]);
       }
     }
     // Attempt a step up
     if position[1] > 0 {
       let position_next = vec![position[0], position[1] - 1];
       if position_next[1] >= self.blizzards_bounds[1].0 || position_next == self.position_start {
-        match self._traverse(position_next, target.clone(), time_offset + 1, time_limit) {
-          Some(min_steps_candidate) => { if min_steps_candidate < min_steps { min_steps = min_steps_candidate; } },
-          _ => ()
-        };
+        positions_next.push(position_next);
       }
     }
     // Attemp to stay in place
-    match self._traverse(position, target.clone(), time_offset + 1, time_limit) {
-      Some(min_steps_candidate) => { if min_steps_candidate < min_steps { min_steps = min_steps_candidate; } },
-      _ => ()
-    };
-
-    // Return min steps found
-    if min_steps == usize::MAX { Option::None } else { Option::Some(min_steps) }
+    positions_next.push(position.to_vec());
+    // Keep only positions free of blizzards
+    positions_next.retain(|position_next| self.check_coordinates_for_blizards(position_next.clone(), time_offset + 1) == 0);
+    positions_next
   }
 
   /// Checks coordinates at a given point in time for passing blizzards
@@ -244,59 +175,35 @@ impl BlizzardBasin {
           + if blizzards_from_down  { 1 } else { 0 }
   }
 
-  /// Prompts the field at a given point in time
+  /// Renders the basin at a given point in time
   /// 
   /// # Arguments
-  /// * position: Current position of the player
-  /// * time_offset: Time offset to draw the grid for
-  fn _prompt (&self, position: &Vec<usize>, time_offset: usize) {
-    // Draw line spacing
-    println!();
-
-    // Prompt time
-    println!("Time: {}", time_offset);
-
-    // Draw starting line
-    print!("#");
-    for x in 1..self.blizzards_dimensions[1] {
-      let p = vec![x, 0];
-      print!("{}", if &p == position { 'E' } else if p == self.position_start { '.' } else { '#' });
-    }
-    print!("#");
-    println!();
-
-    // Draw blizzards field
-    for y in self.blizzards_bounds[1].0..self.blizzards_bounds[1].1 {
-      // Line start
-      print!("#");
-      for x in self.blizzards_bounds[0].0..self.blizzards_bounds[0].1 {
+  /// * position: Current position of the expedition
+  /// * time_offset: Time offset to render the basin at
+  /// 
+  /// # Returns
+  /// Rendered basin, one line per row, with the expedition marked as `E` and any blizzards as `@`
+  pub fn render (&self, position: &[usize], time_offset: usize) -> String {
+    (0..(self.blizzards_bounds[1].1 + 1))
+      .map(|y| (0..(self.blizzards_bounds[0].1 + 1)).map(|x| {
         let p = vec![x, y];
-        let blizzards_count = self.check_coordinates_for_blizards(p.clone(), time_offset);
-        if &p == position {
-          if blizzards_count > 0 {
-            panic!("STEPPED INTO A BLIZZARD - SHOULD NEVER EVER HAPPEN!!!");
-          }
-          print!("E");
-        } else if blizzards_count > 0 {
-          print!("{}", '@');
+        // Render expedition
+        if p == position {
+          'E'
+        }
+        // Render walls, with gaps at start and end positions
+        else if x < self.blizzards_bounds[0].0 || x >= self.blizzards_bounds[0].1 || y < self.blizzards_bounds[1].0 || y >= self.blizzards_bounds[1].1 {
+          if p == self.position_start || p == self.position_end { '.' } else { '#' }
+        }
+        // Render blizzards
+        else if self.check_coordinates_for_blizards(p, time_offset) > 0 {
+          '@'
         } else {
-          print!("{}", '.');
+          '.'
         }
-      }
-      // Line end
-      print!("#");
-      println!();
-    }
-
-    // Draw end line
-    print!("#");
-    for x in 1..self.blizzards_dimensions[0] + 1 {
-      let p = vec![x, self.blizzards_dimensions[1] + 1];
-      print!("{}", if &p == position { 'E' } else if p == self.position_start { '.' } else { '#' });
-    }
-    print!("#");
-    println!();
-
+      }).collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n")
   }
 
 }
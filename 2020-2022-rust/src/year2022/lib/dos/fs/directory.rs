@@ -6,33 +6,34 @@
 // Include dependencies
 use super::file::FsFile;
 use std::collections::hash_map::HashMap;
+use crate::lib::json::*;
 
 /// Device Operating System Filesystem directory structure
 #[derive(Debug)]
-pub struct FsDirectory<'a> {
+pub struct FsDirectory {
   // Name
-  pub name: &'a str,
+  pub name: String,
   // Full path
-  pub path: Vec<&'a str>,
+  pub path: Vec<String>,
   // Size
   pub size: usize,
   // Subdirectories
-  pub directories: HashMap<&'a str, FsDirectory<'a>>,
+  pub directories: HashMap<String, FsDirectory>,
   // Files
-  pub files: HashMap<&'a str, FsFile<'a>>  
+  pub files: HashMap<String, FsFile>
 }
 
 /// Device Operating System Filesystem directory implementation
-impl FsDirectory<'_> {
+impl FsDirectory {
 
   /// Constructor
   /// 
   /// # Arguments
   /// * name: Name of the directory
   /// * path: Full path of the directory
-  pub fn new<'a> (name: &'a str, path: Vec<&'a str>) -> FsDirectory<'a> {
+  pub fn new (name: &str, path: Vec<String>) -> FsDirectory {
     FsDirectory {
-      name,
+      name: name.to_string(),
       path,
       size: 0,
       directories: HashMap::new(),
@@ -79,5 +80,53 @@ impl FsDirectory<'_> {
     // Return edited aggregate
     aggregate
   }
-}
 
+  /// Gets full path of the directory, as text
+  pub fn path_text (&self) -> String {
+    format!("/{}", self.path.join("/"))
+  }
+
+  /// Gets subdirectories, ordered by name
+  pub fn sorted_directories (&self) -> Vec<&FsDirectory> {
+    let mut directories: Vec<&FsDirectory> = self.directories.values().collect();
+    directories.sort_by(|a, b| a.name.cmp(&b.name));
+    directories
+  }
+
+  /// Gets files, ordered by name
+  pub fn sorted_files (&self) -> Vec<&FsFile> {
+    let mut files: Vec<&FsFile> = self.files.values().collect();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    files
+  }
+
+  /// Renders the directory and all of its children as a tree, one entry per line (children ordered by name)
+  /// 
+  /// # Arguments
+  /// * depth: Depth of the directory within the tree, indenting its entry by 2 spaces per level
+  pub fn tree (&self, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    let name = if self.path.is_empty() { "/" } else { self.name.as_str() };
+    let mut lines: Vec<String> = vec![format!("{}- {} (dir)", indent, name)];
+    for dir in self.sorted_directories() {
+      lines.push(dir.tree(depth + 1));
+    }
+    for file in self.sorted_files() {
+      lines.push(format!("{}  - {} (file, size={})", indent, file.name, file.size));
+    }
+    lines.join("\n")
+  }
+
+  /// Serializes the directory and all of its children as JSON (children ordered by name)
+  pub fn to_json (&self) -> Json {
+    Json::Object(vec![
+      (String::from("name"), Json::String(String::from(if self.path.is_empty() { "/" } else { self.name.as_str() }))),
+      (String::from("directories"), Json::Array(self.sorted_directories().iter().map(|dir| dir.to_json()).collect())),
+      (String::from("files"), Json::Array(self.sorted_files().iter().map(|file| Json::Object(vec![
+        (String::from("name"), Json::String(file.name.clone())),
+        (String::from("size"), Json::Number(file.size as f64))
+      ])).collect()))
+    ])
+  }
+
+}
@@ -8,17 +8,17 @@
 
 /// Device Operating System Filesystem file structure
 #[derive(Debug)]
-pub struct FsFile<'a> {
+pub struct FsFile {
   // Name
-  pub name: &'a str,
-  // Full path
-  pub path: Vec<&'a str>,
+  pub name: String,
+  // Full path of the parent directory
+  pub path: Vec<String>,
   // Size
   pub size: usize
 }
 
 /// Device Operating System Filesystem file implementation
-impl FsFile<'_> {
+impl FsFile {
 
   /// Constructor
   /// 
@@ -26,13 +26,18 @@ impl FsFile<'_> {
   /// * path: Path of the parent directory
   /// * name: Name of the file
   /// * size: Size of the file
-  pub fn new<'a> (path: Vec<&'a str>, name: &'a str, size: usize) -> FsFile<'a> {
+  pub fn new (path: Vec<String>, name: &str, size: usize) -> FsFile {
     FsFile {
-      name,
+      name: name.to_string(),
       path,
       size
     }
   }
 
+  /// Gets full path of the file, as text
+  pub fn path_text (&self) -> String {
+    format!("/{}", [&self.path[..], std::slice::from_ref(&self.name)].concat().join("/"))
+  }
+
 }
 
@@ -8,28 +8,73 @@ pub mod directory;
 pub mod file;
 
 // Include dependecies
+use std::fmt;
 use self::directory::FsDirectory;
 use self::file::FsFile;
+use crate::lib::json::*;
+
+/// Device Operating System Filesystem errors
+#[derive(Debug, PartialEq)]
+pub enum FsError {
+  // Command isn't supported
+  UnknownCommand(String),
+  // Command was called with invalid arguments (command, expected usage)
+  InvalidArguments(String, String),
+  // Line of command output couldn't be parsed
+  InvalidOutput(String),
+  // No file or directory found at path
+  NotFound(String),
+  // File found at path, where a directory was expected
+  NotADirectory(String),
+  // Directory found at path, where a file was expected
+  IsADirectory(String),
+  // File or directory already exists at path
+  AlreadyExists(String),
+  // Snapshot couldn't be parsed (reason)
+  InvalidSnapshot(String)
+}
+impl fmt::Display for FsError {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      FsError::UnknownCommand(command) => write!(f, "Unknown command \"{}\"", command),
+      FsError::InvalidArguments(command, usage) => write!(f, "Invalid arguments for \"{}\", expected usage: {}", command, usage),
+      FsError::InvalidOutput(line) => write!(f, "Invalid command output \"{}\"", line),
+      FsError::NotFound(path) => write!(f, "No such file or directory \"{}\"", path),
+      FsError::NotADirectory(path) => write!(f, "Not a directory \"{}\"", path),
+      FsError::IsADirectory(path) => write!(f, "Is a directory \"{}\"", path),
+      FsError::AlreadyExists(path) => write!(f, "File or directory already exists \"{}\"", path),
+      FsError::InvalidSnapshot(reason) => write!(f, "Invalid snapshot: {}", reason)
+    }
+  }
+}
 
 /// Device Operating System Filesystem structure
-pub struct FS<'a> {
+pub struct FS {
   // Filesystem
-  pub root: FsDirectory<'a>,
-  // CWD 
-  pub cwd: Vec<&'a str>
+  pub root: FsDirectory,
+  // CWD
+  pub cwd: Vec<String>,
+  // Path of the directory output of the last fed `ls` command is being recorded into
+  listing: Option<Vec<String>>
 }
 
 /// Device Operating System Filesystem implementation
-impl<'a> FS<'a> {
+/// 
+/// Works as a small shell, executing commands (optionally with their output, as found in a session
+/// transcript) against the filesystem. Paths passed to commands are either absolute (`/a/b`) or
+/// relative to the CWD (`b`, `../c`). The filesystem owns all of its names, so it can be kept around
+/// (and built up) independently of any input it was built from
+impl FS {
 
   /// Constructor
-  pub fn new<'b> () -> FS<'b> {
+  pub fn new () -> FS {
     // Initialize FS
-    let root: FsDirectory<'b> = FsDirectory::new("", vec![]);
+    let root: FsDirectory = FsDirectory::new("", vec![]);
     // Initialize DOS
     FS {
       root,
-      cwd: vec![]
+      cwd: vec![],
+      listing: None
     }
   }
 
@@ -37,16 +82,18 @@ impl<'a> FS<'a> {
   /// 
   /// # Arguments
   /// * path: Path of a directory
-  fn get_dir (&mut self, path: Vec<&'a str>) {
+  fn get_dir (&mut self, path: &[String]) -> &mut FsDirectory {
     // Get starting directory
     let mut dir: &mut FsDirectory = &mut self.root;
     // Search for directory with given path and create if not exists
     for dirname in path {
       if !dir.directories.contains_key(dirname) {
-        dir.directories.insert(dirname, FsDirectory::new(dirname, [dir.path.clone(), vec![dirname]].concat()));
+        let child = FsDirectory::new(dirname, [&dir.path[..], std::slice::from_ref(dirname)].concat());
+        dir.directories.insert(dirname.clone(), child);
       }
       dir = dir.directories.get_mut(dirname).unwrap();
     }
+    dir
   }
   /// Makes sure a directory exists for a given path and filename and returns a nutable reference to it
   /// 
@@ -54,124 +101,373 @@ impl<'a> FS<'a> {
   /// * path: Path of a parent directory
   /// * filename: Name of the file
   /// * size: Size of the file
-  fn get_file(&mut self, path: Vec<&'a str>, filename: &'a str, size: usize) {
-    // Get starting directory
-    let mut dir: &mut FsDirectory = &mut self.root;
-    // Search for directory with given path and create if not exists
-    for dirname in path.clone() {
-      if !dir.directories.contains_key(dirname) {
-        dir.directories.insert(dirname, FsDirectory::new(dirname, [dir.path.clone(), vec![dirname]].concat()));
-      }
-      dir = dir.directories.get_mut(dirname).unwrap();
-    }
+  fn get_file(&mut self, path: &[String], filename: &str, size: usize) -> &mut FsFile {
+    // Get (or create) parent directory
+    let dir = self.get_dir(path);
     // Check if file exists
     if !dir.files.contains_key(filename) {
-      dir.files.insert(filename, FsFile::new(dir.path.clone(), filename, size));
+      dir.files.insert(filename.to_string(), FsFile::new(dir.path.clone(), filename, size));
+    }
+    dir.files.get_mut(filename).unwrap()
+  }
+
+  /// Resolves a path into the path segments it points to
+  /// 
+  /// # Arguments
+  /// * path: Absolute path, or path relative to the CWD
+  pub fn resolve (&self, path: &str) -> Vec<String> {
+    let mut resolved: Vec<String> = if path.starts_with('/') { vec![] } else { self.cwd.clone() };
+    for segment in path.split('/') {
+      match segment {
+        "" | "." => (),
+        ".." => { resolved.pop(); },
+        segment => resolved.push(segment.to_string())
+      }
     }
+    resolved
+  }
+
+  /// Finds a directory
+  /// 
+  /// # Arguments
+  /// * path: Path segments of the directory
+  /// 
+  /// # Returns
+  /// Found directory, or an error describing why it wasn't found
+  pub fn find_dir (&self, path: &[String]) -> Result<&FsDirectory, FsError> {
+    let mut dir: &FsDirectory = &self.root;
+    for (i, dirname) in path.iter().enumerate() {
+      dir = match dir.directories.get(dirname) {
+        Some(child) => child,
+        None if dir.files.contains_key(dirname) => return Err(FsError::NotADirectory(format!("/{}", path[..(i + 1)].join("/")))),
+        None => return Err(FsError::NotFound(format!("/{}", path[..(i + 1)].join("/"))))
+      };
+    }
+    Ok(dir)
+  }
+
+  /// Looks up a directory by its path
+  /// 
+  /// # Arguments
+  /// * path: Absolute path, or path relative to the CWD
+  /// 
+  /// # Returns
+  /// Found directory, or an error describing why it wasn't found
+  #[allow(dead_code)]
+  pub fn dir (&self, path: &str) -> Result<&FsDirectory, FsError> {
+    self.find_dir(&self.resolve(path))
+  }
+
+  /// Looks up a file by its path
+  /// 
+  /// # Arguments
+  /// * path: Absolute path, or path relative to the CWD
+  /// 
+  /// # Returns
+  /// Found file, or an error describing why it wasn't found
+  #[allow(dead_code)]
+  pub fn file (&self, path: &str) -> Result<&FsFile, FsError> {
+    let path = self.resolve(path);
+    let path_text = format!("/{}", path.join("/"));
+    let (name, parent) = path.split_last().ok_or_else(|| FsError::IsADirectory(path_text.clone()))?;
+    let dir = self.find_dir(parent)?;
+    match dir.files.get(name) {
+      Some(file) => Ok(file),
+      None if dir.directories.contains_key(name) => Err(FsError::IsADirectory(path_text)),
+      None => Err(FsError::NotFound(path_text))
+    }
+  }
+
+  /// Feeds a single line of a terminal session into the filesystem, allowing it to be built up
+  /// incrementally (e.g. as lines are being read from stdin)
+  /// 
+  /// Lines starting with a `$` prompt are executed as commands right away. Any lines following an
+  /// `ls` command are recorded as its output, while lines following any other command are ignored
+  /// 
+  /// # Arguments
+  /// * line: Line of a terminal session
+  /// 
+  /// # Returns
+  /// Output of the executed command (if line was a command), or an error describing why the line couldn't be processed
+  pub fn feed (&mut self, line: &str) -> Result<String, FsError> {
+    // Record output of the last command
+    if !line.trim_start().starts_with('$') {
+      if let (Some(path), false) = (self.listing.clone(), line.trim().is_empty()) {
+        let (size, name) = FS::parse_ls_output(line)?;
+        self.record(&path, size, name)?;
+      }
+      return Ok(String::default());
+    }
+    // Execute command
+    self.listing = None;
+    let output = self.exec(line, &[])?;
+    // Start recording output of an `ls` command
+    let words: Vec<&str> = line.split_whitespace().skip_while(|word| *word == "$").collect();
+    if let ["ls", args @ ..] = &words[..] {
+      self.listing = Some(self.resolve(args.first().copied().unwrap_or(".")));
+    }
+    Ok(output)
   }
 
   /// Executes an OS FS command
   /// 
   /// # Arguments
-  /// * cmd: Command to execute
+  /// * command: Command to execute (optionally prefixed with the `$` prompt), e.g. `$ cd /`
+  /// * output:  Lines of output following the command in a session transcript (only `ls` output is
+  ///   recorded into the filesystem, any other command's output is ignored)
   /// 
   /// # Returns
-  /// If command was processed or not
-  pub fn exec_terminal_stdout_section(&mut self, cmd: &Vec<&'a str>) -> bool {
-
-    // Extract 1st line of the command
-    if cmd.len() == 0 {
-      return false;
-    }
-    let command: Vec<&str> = cmd[0].split(" ").collect();    
-    let output: Vec<Vec<&str>> = if cmd.len() == 1 {
-      vec![]
-    } else {
-      cmd[1..].iter().map(|line| line.split(" ").collect()).collect()
+  /// Output of the command, or an error describing why command couldn't be executed
+  pub fn exec (&mut self, command: &str, output: &[&str]) -> Result<String, FsError> {
+    let words: Vec<&str> = command.split_whitespace().skip_while(|word| *word == "$").collect();
+    let (name, args) = match words.split_first() {
+      Some((name, args)) => (*name, args),
+      None => return Ok(String::default())
     };
+    match name {
+      "cd" => self.exec_cd(args),
+      "ls" => self.exec_ls(args, output),
+      "pwd" => Ok(format!("/{}", self.cwd.join("/"))),
+      "tree" => self.exec_tree(args),
+      "du" => self.exec_du(args),
+      "find" => self.exec_find(args),
+      "rm" => self.exec_rm(args),
+      "mkdir" => self.exec_mkdir(args),
+      name => Err(FsError::UnknownCommand(name.to_string()))
+    }
+  }
 
-    // Process "CD" command
-    if command.len() > 1 && command[0] == "$" && command[1] == "cd" {
-      self.exec_cd(&command);
-      return true;
+  /// Executes an OS FS "CD" command
+  /// 
+  /// Usage: `cd <path>`
+  fn exec_cd (&mut self, args: &[&str]) -> Result<String, FsError> {
+    if args.len() != 1 {
+      return Err(FsError::InvalidArguments(String::from("cd"), String::from("cd <path>")));
     }
+    let path = self.resolve(args[0]);
+    self.find_dir(&path)?;
+    self.cwd = path;
+    Ok(String::default())
+  }
 
-    // Process "LS" command
-    if command.len() > 1 && command[0] == "$" && command[1] == "ls" {
-      self.exec_ls(&output);
-      return true;
+  /// Executes an OS FS "LS" command, recording any files and directories listed in its output
+  /// 
+  /// Usage: `ls [path]`, followed by output lines formatted as `dir <name>` or `<size> <name>`
+  /// 
+  /// # Returns
+  /// Listing of the directory in the same format, if no output was recorded
+  fn exec_ls (&mut self, args: &[&str], output: &[&str]) -> Result<String, FsError> {
+    if args.len() > 1 {
+      return Err(FsError::InvalidArguments(String::from("ls"), String::from("ls [path]")));
+    }
+    let path = self.resolve(args.first().copied().unwrap_or("."));
+    // List directory
+    if output.is_empty() {
+      let dir = self.find_dir(&path)?;
+      let mut lines: Vec<String> = dir.sorted_directories().iter().map(|dir| format!("dir {}", dir.name)).collect();
+      lines.extend(dir.sorted_files().iter().map(|file| format!("{} {}", file.size, file.name)));
+      return Ok(lines.join("\n"));
+    }
+    // Parse (and check) all output before recording any of it
+    let dir = self.find_dir(&path)?;
+    let entries = output.iter().map(|line| FS::parse_ls_output(line)).collect::<Result<Vec<(Option<usize>, &str)>, FsError>>()?;
+    for (i, (size, name)) in entries.iter().enumerate() {
+      if FS::clashes(dir, *size, name) || entries[..i].iter().any(|(other, other_name)| other_name == name && other.is_some() != size.is_some()) {
+        return Err(FsError::AlreadyExists(format!("/{}", [&path[..], &[name.to_string()]].concat().join("/"))));
+      }
+    }
+    // Record listed directories and files
+    for (size, name) in entries {
+      self.record(&path, size, name)?;
     }
+    Ok(String::default())
+  }
 
-    // Command was not processed
-    false
+  /// Parses a line of "LS" command output
+  /// 
+  /// # Arguments
+  /// * line: Line of output, formatted as `dir <name>` or `<size> <name>`
+  /// 
+  /// # Returns
+  /// Size (if a file) and name of the listed entry
+  fn parse_ls_output (line: &str) -> Result<(Option<usize>, &str), FsError> {
+    match line.split_whitespace().collect::<Vec<&str>>()[..] {
+      ["dir", name] => Ok((None, name)),
+      [size, name] => Ok((Some(size.parse::<usize>().map_err(|_| FsError::InvalidOutput(line.to_string()))?), name)),
+      _ => Err(FsError::InvalidOutput(line.to_string()))
+    }
   }
-  /// Executes an OS FS "CD" command
+
+  /// Checks if a directory already contains an entry of a different kind under the same name
   /// 
   /// # Arguments
-  /// * command: Command to execute
-  fn exec_cd(&mut self, command: &Vec<&'a str>) {
-    // Validate command
-    if command.len() != 3 {
-      panic!("CD command should be prefaced with a '$' char and contain a single argument! {:?}", command);
-    }
-
-    // Update CWD
-    let arg: &'a str = command[2];    
-    let mut cwd_updated: bool = false;
-    // Keep to current directory
-    if arg == "." {
-      // Do nothing!
-    }
-    // Switch to parent directory unless already @ root directory
-    else if arg == ".." {
-      if self.cwd.len() > 0 {
-        cwd_updated = true;
-        self.cwd.pop();
-      }
+  /// * dir:  Parent directory
+  /// * size: Size of the file (or none if a directory)
+  /// * name: Name of the directory or file
+  fn clashes (dir: &FsDirectory, size: Option<usize>, name: &str) -> bool {
+    match size {
+      None => dir.files.contains_key(name),
+      Some(_) => dir.directories.contains_key(name)
     }
-    // Switch to @ root directory
-    else if arg == "/" {
-      cwd_updated = self.cwd.len() > 0;
-      self.cwd = vec![];
+  }
+
+  /// Records a listed directory or file
+  /// 
+  /// # Arguments
+  /// * path: Path of the parent directory
+  /// * size: Size of the file (or none if a directory)
+  /// * name: Name of the directory or file
+  /// 
+  /// # Returns
+  /// Nothing, or an error if an entry of a different kind already exists under the same name
+  fn record (&mut self, path: &[String], size: Option<usize>, name: &str) -> Result<(), FsError> {
+    let entry_path = [path, &[name.to_string()]].concat();
+    if FS::clashes(self.get_dir(path), size, name) {
+      return Err(FsError::AlreadyExists(format!("/{}", entry_path.join("/"))));
     }
-    // Switch to a named subdirectory
-    else {
-      cwd_updated = true;
-      self.cwd.push(arg);
+    match size {
+      None => { self.get_dir(&entry_path); },
+      Some(size) => self.get_file(path, name, size).size = size
     }
+    Ok(())
+  }
 
-    // Make sure FS reflects the updated CWD
-    if cwd_updated {
-      let path: Vec<&str> = self.cwd.clone();
-      self.get_dir(path);
+  /// Executes an OS FS "TREE" command
+  /// 
+  /// Usage: `tree [path]`
+  fn exec_tree (&mut self, args: &[&str]) -> Result<String, FsError> {
+    if args.len() > 1 {
+      return Err(FsError::InvalidArguments(String::from("tree"), String::from("tree [path]")));
     }
+    let path = self.resolve(args.first().copied().unwrap_or("."));
+    Ok(self.find_dir(&path)?.tree(0))
+  }
 
+  /// Executes an OS FS "DU" command, listing the total size of a directory and each of its subdirectories
+  /// 
+  /// Usage: `du [path]`
+  fn exec_du (&mut self, args: &[&str]) -> Result<String, FsError> {
+    if args.len() > 1 {
+      return Err(FsError::InvalidArguments(String::from("du"), String::from("du [path]")));
+    }
+    let path = self.resolve(args.first().copied().unwrap_or("."));
+    self.refresh_sizes();
+    let mut lines: Vec<String> = vec![];
+    let mut stack: Vec<&FsDirectory> = vec![self.find_dir(&path)?];
+    while let Some(dir) = stack.pop() {
+      lines.push(format!("{}\t{}", dir.size, dir.path_text()));
+      stack.extend(dir.sorted_directories().into_iter().rev());
+    }
+    Ok(lines.join("\n"))
   }
-  /// Executes an OS FS "LS" command
+
+  /// Executes an OS FS "FIND" command, listing paths of all files and directories (within a directory) matching criteria
   /// 
-  /// # Arguments
-  /// * output: Command output
-  fn exec_ls(&mut self, output: &Vec<Vec<&'a str>>) {
-    // Process output
-    for line in output {
-      // Verify line
-      if line.len() != 2 {
-        panic!("LS command output should only contain lines with 2 items!");
+  /// Usage: `find [path] [-type f|d] [-size [+|-]<size>]` (`+` matching sizes over, `-` sizes under, and
+  /// no sign sizes equal to the size)
+  fn exec_find (&mut self, args: &[&str]) -> Result<String, FsError> {
+    let usage = || FsError::InvalidArguments(String::from("find"), String::from("find [path] [-type f|d] [-size [+|-]<size>]"));
+    // Parse arguments
+    let (mut path, mut kind, mut size): (&str, Option<&str>, Option<(std::cmp::Ordering, usize)>) = (".", None, None);
+    let mut i = 0;
+    while i < args.len() {
+      match (args[i], args.get(i + 1)) {
+        ("-type", Some(value)) if *value == "f" || *value == "d" => kind = Some(value),
+        ("-size", Some(value)) => {
+          let (ordering, value) = match value.chars().next() {
+            Some('+') => (std::cmp::Ordering::Greater, &value[1..]),
+            Some('-') => (std::cmp::Ordering::Less, &value[1..]),
+            _ => (std::cmp::Ordering::Equal, &value[..])
+          };
+          size = Some((ordering, value.parse::<usize>().map_err(|_| usage())?));
+        },
+        (value, _) if i == 0 && !value.starts_with('-') => {
+          path = value;
+          i += 1;
+          continue;
+        },
+        _ => return Err(usage())
       }
-      // Process directory
-      if line[0] == "dir" {
-        let path: Vec<&str> = self.cwd.clone();
-        let dirname = line[1];
-        self.get_dir([path.clone(), vec![dirname]].concat());
+      i += 2;
+    }
+    // Find matching directories and files
+    let path = self.resolve(path);
+    self.refresh_sizes();
+    let matches = |entry_kind: &str, entry_size: usize| {
+      kind.is_none_or(|kind| kind == entry_kind) && size.is_none_or(|(ordering, size)| entry_size.cmp(&size) == ordering)
+    };
+    let mut lines: Vec<String> = vec![];
+    let mut stack: Vec<&FsDirectory> = vec![self.find_dir(&path)?];
+    while let Some(dir) = stack.pop() {
+      if matches("d", dir.size) {
+        lines.push(dir.path_text());
       }
-      // Process file
-      else {
-        let path: Vec<&str> = self.cwd.clone();
-        let size = line[0].parse::<usize>().unwrap();
-        let filename = line[1];
-        self.get_file(path, filename, size);
+      for file in dir.sorted_files() {
+        if matches("f", file.size) {
+          lines.push(file.path_text());
+        }
       }
+      stack.extend(dir.sorted_directories().into_iter().rev());
+    }
+    Ok(lines.join("\n"))
+  }
+
+  /// Executes an OS FS "RM" command (removing a directory moves the CWD out of it, if it was within the directory)
+  /// 
+  /// Usage: `rm [-r] <path>` (`-r` required to remove directories)
+  fn exec_rm (&mut self, args: &[&str]) -> Result<String, FsError> {
+    let (recursive, target) = match args {
+      ["-r", target] => (true, *target),
+      [target] => (false, *target),
+      _ => return Err(FsError::InvalidArguments(String::from("rm"), String::from("rm [-r] <path>")))
+    };
+    let path = self.resolve(target);
+    let (name, parent) = match path.split_last() {
+      Some((name, parent)) => (name.clone(), parent.to_vec()),
+      None => return Err(FsError::InvalidArguments(String::from("rm"), String::from("rm [-r] <path> (other than the root directory)")))
+    };
+    // Check what's being removed
+    let path_text = format!("/{}", path.join("/"));
+    let dir = self.find_dir(&parent)?;
+    if dir.directories.contains_key(&name) && !recursive {
+      return Err(FsError::IsADirectory(path_text));
+    }
+    if !dir.directories.contains_key(&name) && !dir.files.contains_key(&name) {
+      return Err(FsError::NotFound(path_text));
     }
+    // Remove
+    let dir = self.get_dir(&parent);
+    if dir.directories.remove(&name).is_none() {
+      dir.files.remove(&name);
+    }
+    if self.cwd.starts_with(&path) {
+      self.cwd.truncate(path.len() - 1);
+    }
+    Ok(String::default())
+  }
+
+  /// Executes an OS FS "MKDIR" command
+  /// 
+  /// Usage: `mkdir [-p] <path>` (`-p` creating any missing parent directories, and skipping existing directories)
+  fn exec_mkdir (&mut self, args: &[&str]) -> Result<String, FsError> {
+    let (parents, target) = match args {
+      ["-p", target] => (true, *target),
+      [target] => (false, *target),
+      _ => return Err(FsError::InvalidArguments(String::from("mkdir"), String::from("mkdir [-p] <path>")))
+    };
+    let path = self.resolve(target);
+    // Check directory can be created
+    for i in 0..path.len() {
+      match self.find_dir(&path[..(i + 1)]) {
+        Ok(_) if i + 1 == path.len() && !parents => return Err(FsError::AlreadyExists(format!("/{}", path.join("/")))),
+        Err(FsError::NotFound(missing)) if i + 1 < path.len() && !parents => return Err(FsError::NotFound(missing)),
+        Err(FsError::NotADirectory(file)) => return Err(FsError::AlreadyExists(file)),
+        _ => ()
+      }
+    }
+    // Create directory
+    self.get_dir(&path);
+    Ok(String::default())
   }
 
   /// Refreshes directory sizes after a changes has been made to the directory or any of its children
@@ -191,23 +487,294 @@ impl<'a> FS<'a> {
     self.root.traverse(callback, aggregate)
   }
 
-  /// Prompts the currently executing command, or just empty terminal prompt
+  /// Exports the filesystem as a tree text snapshot, e.g.:
+  /// 
+  /// ```text
+  /// - / (dir)
+  ///   - a (dir)
+  ///     - f (file, size=29116)
+  ///   - b.txt (file, size=14848514)
+  /// ```
+  #[allow(dead_code)]
+  pub fn to_tree (&self) -> String {
+    self.root.tree(0)
+  }
+
+  /// Exports the filesystem as a JSON snapshot: every directory as `{"name", "directories", "files"}`
+  /// and every file as `{"name", "size"}`
+  #[allow(dead_code)]
+  pub fn to_json (&self) -> String {
+    self.root.to_json().stringify()
+  }
+
+  /// Imports a filesystem from a tree text snapshot (as exported by `to_tree`)
   /// 
   /// # Arguments
-  /// * cmd: Option of command and command output to prompt
-  pub fn prompt (&self, _cmd: Option<(&Vec<&str>, &Vec<Vec<&str>>)>) {
-    // // Prompt executing command
-    // match _cmd {
-    //   Option::Some((command, output)) => {
-    //     println!("FS > /{} {}", self.cwd.join("/"), command.join(" "));
-    //     println!("{}", output.iter().map(|line| line.join(" ")).collect::<Vec<String>>().join("\n"));
-    //     println!();
-    //   },
-    //   Option::None => {
-    //     println!("FS > /{} $", self.cwd.join("/"));
-    //     println!();
-    //   }
-    // }
+  /// * snapshot: Tree text snapshot
+  /// 
+  /// # Returns
+  /// Imported filesystem (with refreshed directory sizes), or an error pointing to the first invalid line
+  #[allow(dead_code)]
+  pub fn from_tree (snapshot: &str) -> Result<FS, FsError> {
+    let mut fs = FS::new();
+    let mut path: Vec<String> = vec![];
+    let mut root = false;
+    for (i, line) in snapshot.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+      let error = |reason: &str| FsError::InvalidSnapshot(format!("Line {}: {}", i + 1, reason));
+      // Parse entry
+      let indent = line.len() - line.trim_start().len();
+      let entry = line.trim().strip_prefix("- ").ok_or_else(|| error("Expected an entry formatted as \"- <name> (dir)\" or \"- <name> (file, size=<size>)\""))?;
+      let (name, kind) = entry.rsplit_once(" (").ok_or_else(|| error("Expected entry's name followed by its kind"))?;
+      let depth = indent / 2;
+      if !root {
+        if indent != 0 || name != "/" || kind != "dir)" {
+          return Err(error("Expected the root directory \"- / (dir)\" first"));
+        }
+        root = true;
+        continue;
+      }
+      if indent % 2 != 0 || depth == 0 || depth > path.len() + 1 {
+        return Err(error("Unexpected indentation"));
+      }
+      // Record entry
+      path.truncate(depth - 1);
+      match kind.strip_prefix("file, size=").and_then(|size| size.strip_suffix(')')) {
+        Some(size) => {
+          let size = size.parse::<usize>().map_err(|_| error("Expected file size to be a whole number"))?;
+          fs.record(&path, Some(size), name).map_err(|err| error(&err.to_string()))?;
+        },
+        None if kind == "dir)" => {
+          fs.record(&path, None, name).map_err(|err| error(&err.to_string()))?;
+          path.push(name.to_string());
+        },
+        None => return Err(error("Expected entry kind to be \"dir\" or \"file, size=<size>\""))
+      }
+    }
+    fs.refresh_sizes();
+    Ok(fs)
   }
+
+  /// Imports a filesystem from a JSON snapshot (as exported by `to_json`)
+  /// 
+  /// # Arguments
+  /// * snapshot: JSON snapshot
+  /// 
+  /// # Returns
+  /// Imported filesystem (with refreshed directory sizes), or an error describing the first invalid entry
+  #[allow(dead_code)]
+  pub fn from_json (snapshot: &str) -> Result<FS, FsError> {
+    let json = Json::parse(snapshot).map_err(FsError::InvalidSnapshot)?;
+    if json.get("name").and_then(|name| name.to_text()).as_deref() != Some("/") {
+      return Err(FsError::InvalidSnapshot(String::from("Expected the root directory to be named \"/\"")));
+    }
+    let mut fs = FS::new();
+    fs.import_json(&json, vec![])?;
+    fs.refresh_sizes();
+    Ok(fs)
+  }
+
+  /// Imports children of a directory from its JSON snapshot
+  /// 
+  /// # Arguments
+  /// * json: JSON snapshot of the directory
+  /// * path: Path of the directory
+  fn import_json (&mut self, json: &Json, path: Vec<String>) -> Result<(), FsError> {
+    let error = |reason: &str| FsError::InvalidSnapshot(format!("Directory \"/{}\": {}", path.join("/"), reason));
+    let entries = |key: &str| json.get(key).and_then(|entries| entries.as_array()).ok_or_else(|| error(&format!("Expected \"{}\" to be an array", key)));
+    let name = |entry: &Json| match entry.get("name").and_then(|name| name.to_text()) {
+      Some(name) if !name.is_empty() && !name.contains('/') && !name.contains(char::is_whitespace) => Ok(name),
+      _ => Err(error("Expected every entry to have a name (without slashes or whitespace)"))
+    };
+    // Import files
+    for file in entries("files")? {
+      let size = match file.get("size").and_then(|size| size.as_number()) {
+        Some(size) if size >= 0.0 && size.fract() == 0.0 => size as usize,
+        _ => return Err(error("Expected every file to have a size, as a whole number"))
+      };
+      self.record(&path, Some(size), &name(file)?)?;
+    }
+    // Import (sub)directories
+    for dir in entries("directories")? {
+      let name = name(dir)?;
+      self.record(&path, None, &name)?;
+      self.import_json(dir, [&path[..], &[name]].concat())?;
+    }
+    Ok(())
+  }
+
 }
 
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Builds the filesystem from the day 7 example, by executing `ls` commands with their output
+  fn example () -> FS {
+    let mut fs = FS::new();
+    fs.exec("$ ls /", &["dir a", "14848514 b.txt", "8504156 c.dat", "dir d"]).unwrap();
+    fs.exec("$ ls /a", &["dir e", "29116 f", "2557 g", "62596 h.lst"]).unwrap();
+    fs.exec("$ ls /a/e", &["584 i"]).unwrap();
+    fs.exec("$ ls /d", &["4060174 j", "8033020 d.log", "5626152 d.ext", "7214296 k"]).unwrap();
+    fs
+  }
+
+  /// Day 7 example terminal session
+  const SESSION: &str = include_str!("../../../data/day07/input-test.txt");
+
+  #[test]
+  fn builds_up_from_fed_lines () {
+    let mut fs = FS::new();
+    for line in SESSION.lines() {
+      assert_eq!(fs.feed(line), Ok(String::default()), "{}", line);
+    }
+    fs.refresh_sizes();
+    assert_eq!(fs.to_tree(), example().to_tree());
+    assert_eq!((fs.root.size, fs.dir("/a").unwrap().size, fs.dir("/d").unwrap().size), (48381165, 94853, 24933642));
+    // Fed commands produce output, and lines following commands other than `ls` are ignored
+    assert_eq!(fs.feed("$ pwd"), Ok(String::from("/d")));
+    assert_eq!(fs.feed("123 ignored"), Ok(String::default()));
+    assert_eq!(fs.file("/d/ignored").err(), Some(FsError::NotFound(String::from("/d/ignored"))));
+    // Invalid fed lines are reported
+    fs.feed("$ ls").unwrap();
+    assert_eq!(fs.feed("12k x"), Err(FsError::InvalidOutput(String::from("12k x"))));
+    assert_eq!(fs.feed("dir j"), Err(FsError::AlreadyExists(String::from("/d/j"))));
+    assert_eq!(fs.feed("$ cd /x"), Err(FsError::NotFound(String::from("/x"))));
+  }
+
+  #[test]
+  fn looks_up_directories_and_files_by_path () {
+    let mut fs = example();
+    fs.exec("cd /a", &[]).unwrap();
+    // Absolute and relative paths
+    assert_eq!(fs.dir("/a/e").unwrap().path_text(), "/a/e");
+    assert_eq!(fs.dir("e").unwrap().path_text(), "/a/e");
+    assert_eq!(fs.dir("..").unwrap().path_text(), "/");
+    assert_eq!(fs.dir("./e/../../d").unwrap().path_text(), "/d");
+    assert_eq!(fs.file("/a/e/i").unwrap().size, 584);
+    assert_eq!(fs.file("e/i").unwrap().path_text(), "/a/e/i");
+    assert_eq!(fs.file("../d/d.log").unwrap().size, 8033020);
+    // Missing entries
+    assert_eq!(fs.dir("x").err(), Some(FsError::NotFound(String::from("/a/x"))));
+    assert_eq!(fs.dir("/x/y").err(), Some(FsError::NotFound(String::from("/x"))));
+    assert_eq!(fs.file("/a/e/x").err(), Some(FsError::NotFound(String::from("/a/e/x"))));
+    // Entries of the wrong kind
+    assert_eq!(fs.dir("f").err(), Some(FsError::NotADirectory(String::from("/a/f"))));
+    assert_eq!(fs.file("f/x").err(), Some(FsError::NotADirectory(String::from("/a/f"))));
+    assert_eq!(fs.file("e").err(), Some(FsError::IsADirectory(String::from("/a/e"))));
+    assert_eq!(fs.file("/").err(), Some(FsError::IsADirectory(String::from("/"))));
+  }
+
+  #[test]
+  fn changes_directories () {
+    let mut fs = example();
+    assert_eq!(fs.exec("cd /a/e", &[]), Ok(String::default()));
+    assert_eq!(fs.exec("pwd", &[]), Ok(String::from("/a/e")));
+    fs.exec("cd ../../d", &[]).unwrap();
+    assert_eq!(fs.exec("pwd", &[]), Ok(String::from("/d")));
+    assert_eq!(fs.exec("cd /b.txt", &[]), Err(FsError::NotADirectory(String::from("/b.txt"))));
+    assert_eq!(fs.exec("cd /a/x", &[]), Err(FsError::NotFound(String::from("/a/x"))));
+    assert!(matches!(fs.exec("cd", &[]), Err(FsError::InvalidArguments(_, _))));
+    assert_eq!(fs.exec("pwd", &[]), Ok(String::from("/d")));
+    assert_eq!(fs.exec("cat /b.txt", &[]), Err(FsError::UnknownCommand(String::from("cat"))));
+  }
+
+  #[test]
+  fn lists_and_records_directories () {
+    let mut fs = example();
+    assert_eq!(fs.exec("ls /a", &[]), Ok(String::from("dir e\n29116 f\n2557 g\n62596 h.lst")));
+    assert_eq!(fs.exec("ls /b.txt", &[]), Err(FsError::NotADirectory(String::from("/b.txt"))));
+    assert_eq!(fs.exec("ls /a", &["dir"]), Err(FsError::InvalidOutput(String::from("dir"))));
+    assert_eq!(fs.exec("ls /a", &["12k f"]), Err(FsError::InvalidOutput(String::from("12k f"))));
+  }
+
+  #[test]
+  fn rejects_listed_name_clashes () {
+    let mut fs = example();
+    // Clash with an already recorded entry
+    assert_eq!(fs.exec("ls /", &["dir x", "dir b.txt"]), Err(FsError::AlreadyExists(String::from("/b.txt"))));
+    assert_eq!(fs.exec("ls /a", &["10 e"]), Err(FsError::AlreadyExists(String::from("/a/e"))));
+    // Clash within the same listing
+    assert_eq!(fs.exec("ls /", &["dir x", "10 x"]), Err(FsError::AlreadyExists(String::from("/x"))));
+    // Nothing recorded from rejected listings
+    assert_eq!(fs.dir("/x").err(), Some(FsError::NotFound(String::from("/x"))));
+    // Listing the same entries again is fine
+    assert_eq!(fs.exec("ls /a", &["dir e", "29116 f"]), Ok(String::default()));
+  }
+
+  #[test]
+  fn renders_trees_and_disk_usage () {
+    let mut fs = example();
+    assert_eq!(fs.exec("tree /a", &[]), Ok(String::from("- a (dir)\n  - e (dir)\n    - i (file, size=584)\n  - f (file, size=29116)\n  - g (file, size=2557)\n  - h.lst (file, size=62596)")));
+    assert_eq!(fs.exec("du /", &[]), Ok(String::from("48381165\t/\n94853\t/a\n584\t/a/e\n24933642\t/d")));
+    assert_eq!(fs.exec("du /c.dat", &[]), Err(FsError::NotADirectory(String::from("/c.dat"))));
+  }
+
+  #[test]
+  fn finds_entries_by_type_and_size () {
+    let mut fs = example();
+    assert_eq!(fs.exec("find / -size +10000000", &[]), Ok(String::from("/\n/b.txt\n/d")));
+    assert_eq!(fs.exec("find -size -3000", &[]), Ok(String::from("/a/g\n/a/e\n/a/e/i")));
+    assert_eq!(fs.exec("find -size 584", &[]), Ok(String::from("/a/e\n/a/e/i")));
+    assert_eq!(fs.exec("find -size 584 -type f", &[]), Ok(String::from("/a/e/i")));
+    assert_eq!(fs.exec("find /a -type d", &[]), Ok(String::from("/a\n/a/e")));
+    assert!(matches!(fs.exec("find -size 1k", &[]), Err(FsError::InvalidArguments(_, _))));
+    assert!(matches!(fs.exec("find -type x", &[]), Err(FsError::InvalidArguments(_, _))));
+    assert_eq!(fs.exec("find /x", &[]), Err(FsError::NotFound(String::from("/x"))));
+  }
+
+  #[test]
+  fn removes_files_and_directories () {
+    let mut fs = example();
+    fs.exec("cd /a/e", &[]).unwrap();
+    // Remove a file
+    assert_eq!(fs.exec("rm ../f", &[]), Ok(String::default()));
+    assert_eq!(fs.file("/a/f").err(), Some(FsError::NotFound(String::from("/a/f"))));
+    assert_eq!(fs.exec("rm /a/f", &[]), Err(FsError::NotFound(String::from("/a/f"))));
+    // Remove a directory
+    assert_eq!(fs.exec("rm /a", &[]), Err(FsError::IsADirectory(String::from("/a"))));
+    assert_eq!(fs.exec("rm -r /a", &[]), Ok(String::default()));
+    assert_eq!(fs.exec("pwd", &[]), Ok(String::from("/")));
+    assert_eq!(fs.exec("ls", &[]), Ok(String::from("dir d\n14848514 b.txt\n8504156 c.dat")));
+    assert!(matches!(fs.exec("rm -r /", &[]), Err(FsError::InvalidArguments(_, _))));
+  }
+
+  #[test]
+  fn makes_directories () {
+    let mut fs = example();
+    assert_eq!(fs.exec("mkdir /a/x", &[]), Ok(String::default()));
+    assert_eq!(fs.exec("mkdir /a/x", &[]), Err(FsError::AlreadyExists(String::from("/a/x"))));
+    assert_eq!(fs.exec("mkdir /y/z", &[]), Err(FsError::NotFound(String::from("/y"))));
+    assert_eq!(fs.exec("mkdir /b.txt/z", &[]), Err(FsError::AlreadyExists(String::from("/b.txt"))));
+    // Create missing parents, skipping existing directories
+    assert_eq!(fs.exec("mkdir -p /y/z", &[]), Ok(String::default()));
+    assert_eq!(fs.exec("mkdir -p /a/x", &[]), Ok(String::default()));
+    assert_eq!(fs.exec("mkdir -p /b.txt/z", &[]), Err(FsError::AlreadyExists(String::from("/b.txt"))));
+    assert_eq!(fs.exec("tree /y", &[]), Ok(String::from("- y (dir)\n  - z (dir)")));
+  }
+
+  #[test]
+  fn round_trips_tree_snapshots () {
+    let fs = example();
+    let imported = FS::from_tree(&fs.to_tree()).unwrap();
+    assert_eq!(imported.to_tree(), fs.to_tree());
+    assert_eq!(imported.root.size, 48381165);
+    assert_eq!(FS::from_tree("- a (dir)").err(), Some(FsError::InvalidSnapshot(String::from("Line 1: Expected the root directory \"- / (dir)\" first"))));
+    assert_eq!(FS::from_tree("- / (dir)\n  - a (dir)\n  - a (file, size=1)").err(), Some(FsError::InvalidSnapshot(String::from("Line 3: File or directory already exists \"/a\""))));
+    assert!(FS::from_tree("- / (dir)\n  - a (file, size=x)").is_err());
+    assert!(FS::from_tree("- / (dir)\n      - a (dir)").is_err());
+  }
+
+  #[test]
+  fn round_trips_json_snapshots () {
+    let fs = example();
+    let imported = FS::from_json(&fs.to_json()).unwrap();
+    assert_eq!(imported.to_json(), fs.to_json());
+    assert_eq!(imported.to_tree(), fs.to_tree());
+    assert_eq!(imported.root.size, 48381165);
+    assert_eq!(FS::from_json(r#"{"name": "a", "directories": [], "files": []}"#).err(), Some(FsError::InvalidSnapshot(String::from("Expected the root directory to be named \"/\""))));
+    assert!(FS::from_json(r#"{"name": "/", "directories": [], "files": [{"name": "a", "size": 1.5}]}"#).is_err());
+    assert!(FS::from_json(r#"{"name": "/", "directories": [{"name": "a/b", "directories": [], "files": []}], "files": []}"#).is_err());
+    assert!(FS::from_json("{").is_err());
+  }
+
+}
@@ -3,118 +3,398 @@
 //! Device Operating System GPU module
 // -----------------------------------------------------------------------------
 
+// Include dependencies
+use std::fmt;
+use crate::lib::input::*;
+use crate::lib::error::*;
+
+/// Effect of an instruction: updates registers (given operands of the command) and returns the offset
+/// of the next command to execute, relative to the executing command (1 continuing with the next command)
+pub type GpuEffect = fn(&mut [isize], &[GpuOperand]) -> isize;
+
+/// Kinds of operands an instruction accepts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GpuOperandKind {
+  // Name of a register (e.g. one being written to)
+  #[allow(dead_code)] Register,
+  // Explicit value
+  Value,
+  // Either a register (read from) or an explicit value
+  #[allow(dead_code)] Any
+}
+
+/// Device Operating System GPU command operand
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GpuOperand {
+  // Register, by index
+  Register(usize),
+  // Explicit value
+  Value(isize)
+}
+
+/// Device Operating System GPU command operand implementation
+impl GpuOperand {
+
+  /// Gets value of the operand
+  /// 
+  /// # Arguments
+  /// * registers: Registers to read from, if operand is a register
+  pub fn value (&self, registers: &[isize]) -> isize {
+    match self {
+      GpuOperand::Register(index) => registers[*index],
+      GpuOperand::Value(value) => *value
+    }
+  }
+
+  /// Gets index of the register the operand refers to
+  /// 
+  /// Only called for operands of instructions declaring a `GpuOperandKind::Register` operand,
+  /// so that operand was already checked to be a register when the command was parsed
+  #[allow(dead_code)]
+  pub fn register (&self) -> usize {
+    match self {
+      GpuOperand::Register(index) => *index,
+      GpuOperand::Value(_) => panic!("Operand {:?} isn't a register!", self)
+    }
+  }
+
+}
+
+/// Device Operating System GPU instruction
+#[derive(Clone)]
+pub struct GpuInstruction {
+  // Name (opcode) of the instruction
+  pub name: String,
+  // Number of cycles the instruction takes to execute (its effect only being visible after the last one)
+  pub cycles: usize,
+  // Kinds of operands the instruction expects
+  pub operands: Vec<GpuOperandKind>,
+  // Effect of executing the instruction
+  pub effect: GpuEffect
+}
+
+/// Device Operating System GPU instruction implementation
+impl GpuInstruction {
+
+  /// Constructor
+  /// 
+  /// # Arguments
+  /// * name:     Name (opcode) of the instruction
+  /// * cycles:   Number of cycles the instruction takes to execute
+  /// * operands: Kinds of operands the instruction expects
+  /// * effect:   Effect of executing the instruction
+  pub fn new (name: &str, cycles: usize, operands: &[GpuOperandKind], effect: GpuEffect) -> GpuInstruction {
+    GpuInstruction {
+      name: String::from(name),
+      cycles: cycles.max(1),
+      operands: operands.to_vec(),
+      effect
+    }
+  }
+
+}
+
+/// Device Operating System GPU command (an instruction with operands, as queued for execution)
+#[derive(Clone, Debug)]
+struct GpuCommand {
+  // Index of the instruction within the instruction set
+  instruction: usize,
+  // Operands of the command
+  operands: Vec<GpuOperand>,
+  // Source of the command, as loaded
+  source: String
+}
+
+/// Device Operating System GPU breakpoints (and watchpoints)
+#[derive(Clone, Debug, PartialEq)]
+pub enum GpuBreakpoint {
+  // Breaks on a clock cycle
+  Clock(usize),
+  // Breaks on the first cycle of the command at a position in the command queue
+  #[allow(dead_code)] Pointer(usize),
+  // Breaks on the first cycle of any command executing an instruction, by name
+  #[allow(dead_code)] Instruction(String),
+  // Watches a register (by index, see `GpuController::watch` for watching registers by name), breaking on any cycle it holds a value
+  #[allow(dead_code)] Equals(usize, isize),
+  // Watches a register (by index, see `GpuController::watch` for watching registers by name), breaking on any cycle it holds a different value than on the previous cycle
+  #[allow(dead_code)] Change(usize)
+}
+
+/// Reason the GPU halted running
+#[derive(Clone, Debug, PartialEq)]
+pub enum GpuHalt {
+  // No commands left to execute
+  Finished,
+  // Breakpoint hit (by index of the breakpoint)
+  Breakpoint(usize)
+}
+
+/// Device Operating System GPU cycle trace entry
+#[derive(Clone, Debug, PartialEq)]
+pub struct GpuTrace {
+  // Clock cycle
+  pub clock: usize,
+  // Position of the executing command in the command queue
+  pub pointer: usize,
+  // Source of the executing command
+  pub command: String,
+  // Cycle of the executing command (starting with 1) and number of cycles it takes
+  pub cycle: (usize, usize),
+  // Registers during the cycle
+  pub registers: Vec<isize>
+}
+
 /// Device Operating System GPU structure
-pub struct GpuController<'a> {
+pub struct GpuController {
   // GPU clock
   pub clock: usize,
   // GPU registers
-  pub registers: [isize; 1],
+  pub registers: Vec<isize>,
+  // Names of GPU registers, by index of the register
+  pub register_names: Vec<String>,
+  // Instruction set
+  pub instructions: Vec<GpuInstruction>,
+  // Breakpoints and watchpoints to halt running on
+  breakpoints: Vec<GpuBreakpoint>,
+  // If a trace of every executed cycle is being kept
+  pub tracing: bool,
+  // Trace of executed cycles
+  trace: Vec<GpuTrace>,
+  // Last executed cycle
+  last: Option<GpuTrace>,
   // Command queue storing all commands queued for execution
-  command_queue: Vec<(&'a str, Option<isize>)>,
+  command_queue: Vec<GpuCommand>,
   // Command pointer pointing to the next command in command queue to be executed
   command_pointer: usize,
   // If executing a microtask, here is the number of cycles left before it is executed
   microtask_remaning_cycles: usize,
-  // If executing a microtask, here is the state of the registers after completion
-  microtask_result: [isize; 1]
+  // If executing a microtask, here is the state of the registers and the command pointer after completion
+  microtask_result: (Vec<isize>, Option<usize>)
 }
 
 /// Device Operating System GPU implementation
-impl<'a> GpuController<'a> {
+impl GpuController {
 
   /// Constructor
-  pub fn new () -> GpuController<'a> {
+  /// 
+  /// Handheld GPU with a single `x` register (starting at 1) and `noop` (1 cycle) and `addx <value>`
+  /// (2 cycles, adding value to `x`) instructions
+  pub fn new () -> GpuController {
+    GpuController::empty()
+      .with_register("x", 1)
+      .with_instruction(GpuInstruction::new("noop", 1, &[], |_, _| 1))
+      .with_instruction(GpuInstruction::new("addx", 2, &[GpuOperandKind::Value], |registers, operands| {
+        registers[0] += operands[0].value(registers);
+        1
+      }))
+  }
+
+  /// Constructor
+  /// 
+  /// GPU without any registers or instructions
+  pub fn empty () -> GpuController {
     GpuController {
       clock: 0,
-      registers: [1],
+      registers: vec![],
+      register_names: vec![],
+      instructions: vec![],
+      breakpoints: vec![],
+      tracing: false,
+      trace: vec![],
+      last: None,
       command_queue: vec![],
       command_pointer: 0,
       microtask_remaning_cycles: 0,
-      microtask_result: [0]
+      microtask_result: (vec![], None)
     }
   }
 
-  pub fn process_controller_stdin (&mut self, lines: &Vec<&'a str>) {
-    // Process lines
-    for line in lines {
-      let cmd = self.parse_controller_stdin_command(line);
-      self.command_queue.push(cmd);
+  /// Adds a register (or resets an existing register's initial value)
+  /// 
+  /// # Arguments
+  /// * name:  Name of the register
+  /// * value: Initial value of the register
+  pub fn with_register (mut self, name: &str, value: isize) -> GpuController {
+    match self.register(name) {
+      Some(index) => self.registers[index] = value,
+      None => {
+        self.register_names.push(String::from(name));
+        self.registers.push(value);
+      }
     }
+    self
   }
 
-  /// Executes an GPU controller command
+  /// Adds an instruction to the instruction set (or replaces an existing instruction of the same name)
   /// 
   /// # Arguments
-  /// * cmd: Command to execute
-  pub fn parse_controller_stdin_command(&mut self, line: &'a str) -> (&'a str, Option<isize>) {
-    // Parse commnad
-    let parsed: Vec<&str> = line.split(" ").collect();
-    if parsed.len() < 2 {
-      (parsed[0], Option::None)
-    } else {
-      (parsed[0], Option::Some(parsed[1].parse::<isize>().unwrap()))
+  /// * instruction: Instruction to add
+  pub fn with_instruction (mut self, instruction: GpuInstruction) -> GpuController {
+    match self.instructions.iter().position(|existing| existing.name == instruction.name) {
+      Some(index) => self.instructions[index] = instruction,
+      None => self.instructions.push(instruction)
     }
+    self
+  }
+
+  /// Gets index of a register
+  /// 
+  /// # Arguments
+  /// * name: Name of the register
+  pub fn register (&self, name: &str) -> Option<usize> {
+    self.register_names.iter().position(|register| register == name)
   }
 
-  /// Executes a command
+  /// Adds a breakpoint (or a watchpoint) to halt running on
   /// 
   /// # Arguments
-  /// * cmd: Command (name and opttional argument) to execute
+  /// * breakpoint: Breakpoint to add
   /// 
   /// # Returns
-  /// A tuple of register x value after the command executes and number of cycles execution should take
-  fn exec (&mut self, cmd: (&'a str, Option<isize>)) -> ([isize; 1], usize) {
-    // Execute "NOOP" command
-    if cmd.0 == "noop" {
-      return self.exec_noop();
-    }
-    
-    // Execute "ADDX" command
-    else if cmd.0 == "addx" {
-      return self.exec_addx(cmd.1.unwrap());
+  /// Index of the added breakpoint (as reported when it's hit), or an error if it watches an unknown register
+  pub fn break_on (&mut self, breakpoint: GpuBreakpoint) -> Result<usize, String> {
+    if let GpuBreakpoint::Equals(register, _) | GpuBreakpoint::Change(register) = breakpoint {
+      if register >= self.registers.len() {
+        return Err(format!("Can't watch unknown register #{}", register));
+      }
     }
+    self.breakpoints.push(breakpoint);
+    Ok(self.breakpoints.len() - 1)
+  }
+
+  /// Adds a watchpoint on a register
+  /// 
+  /// # Arguments
+  /// * name:  Name of the register to watch
+  /// * value: Value to break on the register holding, or none to break on any change of the register's value
+  /// 
+  /// # Returns
+  /// Index of the added watchpoint (as reported when it's hit), or an error if there is no register by the name
+  #[allow(dead_code)]
+  pub fn watch (&mut self, name: &str, value: Option<isize>) -> Result<usize, String> {
+    let register = self.register(name).ok_or_else(|| format!("Can't watch unknown register \"{}\"", name))?;
+    self.break_on(match value {
+      Some(value) => GpuBreakpoint::Equals(register, value),
+      None => GpuBreakpoint::Change(register)
+    })
+  }
 
-    // Execute noting
-    (self.registers, 0)
+  /// Removes all breakpoints and watchpoints
+  #[allow(dead_code)]
+  pub fn clear_breakpoints (&mut self) {
+    self.breakpoints.clear();
   }
-  /// Executes a "NOOP" command
+
+  /// Loads a program, queueing all of its commands for execution
+  /// 
+  /// # Arguments
+  /// * program: Program, one command (instruction followed by whitespace separated operands) per line
   /// 
   /// # Returns
-  /// A tuple of register x value after the command executes and number of cycles execution should take
-  fn exec_noop(&mut self) -> ([isize; 1], usize) {
-    // Do nothing
-    // ...
-    // Return number of cycles required to execute
-    (self.registers, 1)
+  /// Nothing, or an error pointing to the first command which isn't valid for the instruction set
+  pub fn load (&mut self, program: &str) -> Result<(), ParseError> {
+    let commands = Parser::new(program).parse_lines(|line| self.parse_command(line))?;
+    self.command_queue.extend(commands);
+    Ok(())
   }
-  /// Executes a "ADDX" command
+
+  /// Parses a GPU controller command
   /// 
   /// # Arguments
-  /// * value: Value argument for the "ADDX" command
+  /// * line: Line holding the command
+  fn parse_command (&self, line: Parser) -> Result<GpuCommand, ParseError> {
+    let words = line.words();
+    // Find instruction
+    let instruction = self.instructions.iter().position(|instruction| instruction.name == words[0].text())
+      .ok_or_else(|| words[0].error(format!("Unknown instruction \"{}\"", words[0].text())))?;
+    let kinds = &self.instructions[instruction].operands;
+    if words.len() - 1 != kinds.len() {
+      return Err(line.trim().error(format!("Instruction \"{}\" expects {} operand(s), found {}", words[0].text(), kinds.len(), words.len() - 1)));
+    }
+    // Parse operands
+    let operands = words[1..].iter().zip(kinds.iter())
+      .map(|(word, kind)| {
+        let operand = match (self.register(word.text()), word.text().parse::<isize>()) {
+          (Some(index), _) => GpuOperand::Register(index),
+          (None, Ok(value)) => GpuOperand::Value(value),
+          (None, Err(_)) => return Err(word.error(format!("Unknown register \"{}\"", word.text())))
+        };
+        match (kind, operand) {
+          (GpuOperandKind::Register, GpuOperand::Value(_)) => Err(word.error(format!("Expected a register, found \"{}\"", word.text()))),
+          (GpuOperandKind::Value, GpuOperand::Register(_)) => Err(word.error(format!("Expected a value, found \"{}\"", word.text()))),
+          _ => Ok(operand)
+        }
+      })
+      .collect::<Result<Vec<GpuOperand>, ParseError>>()?;
+    Ok(GpuCommand { instruction, operands, source: line.trim().text().to_string() })
+  }
+
+  /// Runs until a breakpoint is hit or there are no commands left to execute
   /// 
   /// # Returns
-  /// A tuple of register x value after the command executes and number of cycles execution should take
-  fn exec_addx(&mut self, value: isize) -> ([isize; 1], usize) {
-    // Calculate updated register x value
-    let mut registers = self.registers.clone();
-    registers[0] += value;
-    // Return number of cycles required to execute
-    (registers, 2)
+  /// Reason running halted (running can be resumed by running again, after a breakpoint was hit)
+  pub fn run (&mut self) -> GpuHalt {
+    loop {
+      let previous = self.registers.clone();
+      if self.next().is_none() {
+        return GpuHalt::Finished;
+      }
+      let cycle = self.last.as_ref().unwrap();
+      let hit = self.breakpoints.iter().position(|breakpoint| match breakpoint {
+        GpuBreakpoint::Clock(clock) => cycle.clock == *clock,
+        GpuBreakpoint::Pointer(pointer) => cycle.pointer == *pointer && cycle.cycle.0 == 1,
+        GpuBreakpoint::Instruction(name) => self.instructions[self.command_queue[cycle.pointer].instruction].name == *name && cycle.cycle.0 == 1,
+        GpuBreakpoint::Equals(register, value) => cycle.registers[*register] == *value,
+        GpuBreakpoint::Change(register) => cycle.registers[*register] != previous[*register]
+      });
+      if let Some(index) = hit {
+        return GpuHalt::Breakpoint(index);
+      }
+    }
+  }
+
+  /// Gets the last executed cycle
+  pub fn last_cycle (&self) -> Option<&GpuTrace> {
+    self.last.as_ref()
+  }
+
+  /// Takes the trace of all cycles executed (while tracing) so far
+  pub fn take_trace (&mut self) -> Vec<GpuTrace> {
+    self.trace.drain(..).collect()
   }
 
-  /// Prompts current state of the controllers
+  /// Records an executed cycle
   /// 
   /// # Arguments
-  /// * comment: Comment to prompt
-  fn prompt (&mut self, _comment: &str) {
-    // println!("GPU > clock: {}, registers: {:?}: {}", self.clock, self.registers, _comment);
+  /// * pointer: Position of the executing command in the command queue
+  /// * cycle:   Cycle of the executing command (starting with 1)
+  fn record (&mut self, pointer: usize, cycle: usize) {
+    let command = &self.command_queue[pointer];
+    let trace = GpuTrace {
+      clock: self.clock,
+      pointer,
+      command: command.source.clone(),
+      cycle: (cycle, self.instructions[command.instruction].cycles),
+      registers: self.registers.clone()
+    };
+    if self.tracing {
+      self.trace.push(trace.clone());
+    }
+    self.last = Some(trace);
+  }
+}
+
+// Implement display trait for GpuTrace
+impl fmt::Display for GpuTrace {
+  fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "GPU > clock: {}, command #{} \"{}\" ({}/{}), registers: {:?}", self.clock, self.pointer, self.command, self.cycle.0, self.cycle.1, self.registers)
   }
 }
 
 // Implement iterator trait for GpuController
-impl<'a> Iterator for GpuController<'a> {
-  // Define iterator item type
-  type Item = (usize, [isize; 1]);
+impl Iterator for GpuController {
+  // Define iterator item type: clock and registers during the cycle
+  type Item = (usize, Vec<isize>);
 
   // next() is the only required method
   fn next(&mut self) -> Option<Self::Item> {
@@ -124,59 +404,157 @@ impl<'a> Iterator for GpuController<'a> {
       self.microtask_remaning_cycles -= 1;
       // Forward clock
       self.clock += 1;
-      self.prompt(format!("Executing microtask, cycles remaining: {}", self.microtask_remaning_cycles).as_str());
-      return Option::Some((self.clock, self.registers));
+      let pointer = self.last.as_ref().unwrap().pointer;
+      let cycle = self.last.as_ref().unwrap().cycle.0 + 1;
+      self.record(pointer, cycle);
+      return Option::Some((self.clock, self.registers.clone()));
     }
-    // If microtask finished executing, set value and proceed to ingest next command
+    // If microtask finished executing, commit its result and proceed to ingest next command
     else if self.microtask_remaning_cycles == 1 {
       // Forward microtask counter
       self.microtask_remaning_cycles -= 1;
       // Commit microtask result
-      self.registers = self.microtask_result;
-      self.prompt(format!("... done executing microtask, stored value {:?}", self.registers).as_str());
+      let (registers, pointer) = std::mem::take(&mut self.microtask_result);
+      self.registers = registers;
+      self.command_pointer = pointer.unwrap_or(self.command_queue.len());
     }
 
     // Check if next command available
     if self.command_pointer >= self.command_queue.len() {
-      // Refuse execution
-      self.prompt(format!("No commands left in queue!").as_str());
       return Option::None;
     }
-    
-    // Simulate execution of next command
-    let cp = self.command_pointer;
-    let cmd = self.command_queue[cp];
-    let (execution_result, execution_duration) = self.exec(cmd);
-    self.prompt(format!("... ingested command from queue: {:?}", cmd).as_str());
-    self.prompt(format!("... simulated command execution: result {:?} in {} cycles", execution_result, execution_duration).as_str());
-    // Forward command pointer
-    self.command_pointer += 1;
-
-    // Check if command can't be executed
-    if execution_duration == 0 {
-      // Refuse execution
-      self.prompt(format!("Command failed executing!").as_str());
-      return Option::None;
-    }
-    // If command can be executed in single cycle, execute command
-    else if execution_duration == 1 {
-      // Commit execution result
-      self.registers = execution_result;
-      // Forward clock
-      self.clock += 1;
-      self.prompt(format!("Command executed in current cycle with result: {:?}", execution_result).as_str());
-      return Option::Some((self.clock, self.registers));
-    }
+
+    // Simulate execution of next command (its effect only being committed after its last cycle)
+    let pointer = self.command_pointer;
+    let command = &self.command_queue[pointer];
+    let instruction = &self.instructions[command.instruction];
+    let mut registers = self.registers.clone();
+    let offset = (instruction.effect)(&mut registers, &command.operands);
+    let next_pointer = pointer.checked_add_signed(offset);
     // Store microtask to execute
-    else {
-      // Forward microtask counter
-      self.microtask_remaning_cycles = execution_duration;
-      // Store microtask result
-      self.microtask_result = execution_result;
-      // Forward clock
-      self.clock += 1;
-      self.prompt(format!("Command executing, microtask started: {} cycles will produce result {:?}", execution_duration, execution_result).as_str());
-      return Option::Some((self.clock, self.registers));
+    self.microtask_remaning_cycles = instruction.cycles;
+    self.microtask_result = (registers, next_pointer);
+    // Forward clock
+    self.clock += 1;
+    self.record(pointer, 1);
+    Option::Some((self.clock, self.registers.clone()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Multiplies 3 by 4 into register `c`, by repeatedly adding `b` to `c` while decrementing `a` down to 0
+  const PROGRAM: &str = "set a 3\nset b 4\nadd c b\ndec a\njnz a -2\n";
+
+  /// Composes a GPU with 3 registers and instructions setting, adding, decrementing and jumping
+  fn controller () -> GpuController {
+    GpuController::empty()
+      .with_register("a", 0)
+      .with_register("b", 0)
+      .with_register("c", 0)
+      .with_instruction(GpuInstruction::new("set", 1, &[GpuOperandKind::Register, GpuOperandKind::Value], |registers, operands| {
+        registers[operands[0].register()] = operands[1].value(registers);
+        1
+      }))
+      .with_instruction(GpuInstruction::new("add", 2, &[GpuOperandKind::Register, GpuOperandKind::Any], |registers, operands| {
+        registers[operands[0].register()] += operands[1].value(registers);
+        1
+      }))
+      .with_instruction(GpuInstruction::new("dec", 1, &[GpuOperandKind::Register], |registers, operands| {
+        registers[operands[0].register()] -= 1;
+        1
+      }))
+      .with_instruction(GpuInstruction::new("jnz", 1, &[GpuOperandKind::Any, GpuOperandKind::Value], |registers, operands| {
+        if operands[0].value(registers) != 0 { operands[1].value(registers) } else { 1 }
+      }))
+  }
+
+  #[test]
+  fn runs_programs_with_multiple_registers_and_jumps () {
+    let mut gpu = controller();
+    gpu.tracing = true;
+    gpu.load(PROGRAM).unwrap();
+    assert_eq!(gpu.run(), GpuHalt::Finished);
+    assert_eq!(gpu.registers, vec![0, 4, 12]);
+    // 2 sets, and 3 loops of a 2 cycle add, a dec and a jnz
+    assert_eq!(gpu.clock, 2 + 3 * 4);
+    let trace = gpu.take_trace();
+    assert_eq!(trace.len(), gpu.clock);
+    assert_eq!(trace.iter().map(|cycle| cycle.pointer).collect::<Vec<usize>>(), vec![0, 1, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4]);
+    assert_eq!((trace[2].command.as_str(), trace[2].cycle, trace[3].cycle), ("add c b", (1, 2), (2, 2)));
+    // Effect of a multi cycle instruction is only visible once it has executed
+    assert_eq!((trace[3].registers[2], trace[4].registers[2]), (0, 4));
+    // Jumping out of the program finishes it
+    let mut gpu = controller();
+    gpu.load("jnz 1 -5\nset a 1").unwrap();
+    assert_eq!(gpu.run(), GpuHalt::Finished);
+    assert_eq!((gpu.clock, gpu.registers[0]), (1, 0));
+  }
+
+  #[test]
+  fn breaks_on_pointers_and_instructions_and_resumes () {
+    let mut gpu = controller();
+    gpu.load(PROGRAM).unwrap();
+    assert_eq!(gpu.break_on(GpuBreakpoint::Pointer(2)), Ok(0));
+    assert_eq!(gpu.break_on(GpuBreakpoint::Instruction(String::from("jnz"))), Ok(1));
+    let mut hits: Vec<(GpuHalt, usize)> = vec![];
+    loop {
+      let halt = gpu.run();
+      hits.push((halt.clone(), gpu.clock));
+      if halt == GpuHalt::Finished { break; }
     }
+    // Breaks only on the first cycle of the 2 cycle add
+    assert_eq!(hits, vec![
+      (GpuHalt::Breakpoint(0), 3), (GpuHalt::Breakpoint(1), 6),
+      (GpuHalt::Breakpoint(0), 7), (GpuHalt::Breakpoint(1), 10),
+      (GpuHalt::Breakpoint(0), 11), (GpuHalt::Breakpoint(1), 14),
+      (GpuHalt::Finished, 14)
+    ]);
+    assert_eq!(gpu.registers, vec![0, 4, 12]);
   }
+
+  #[test]
+  fn fires_watchpoints_on_register_values_and_changes () {
+    let mut gpu = controller();
+    gpu.load(PROGRAM).unwrap();
+    assert_eq!(gpu.watch("c", Some(8)), Ok(0));
+    assert_eq!(gpu.run(), GpuHalt::Breakpoint(0));
+    assert_eq!((gpu.clock, gpu.last_cycle().unwrap().command.as_str()), (9, "dec a"));
+    // Keeps firing for as long as the register holds the value
+    assert_eq!(gpu.run(), GpuHalt::Breakpoint(0));
+    assert_eq!(gpu.clock, 10);
+    // Fires on every change of the watched register
+    gpu.clear_breakpoints();
+    assert_eq!(gpu.watch("a", None), Ok(0));
+    assert_eq!(gpu.run(), GpuHalt::Breakpoint(0));
+    assert_eq!((gpu.clock, gpu.registers[0]), (14, 0));
+    assert_eq!(gpu.run(), GpuHalt::Finished);
+    // Can't watch unknown registers
+    assert!(gpu.watch("z", None).is_err());
+    assert!(gpu.break_on(GpuBreakpoint::Equals(3, 0)).is_err());
+    assert!(gpu.break_on(GpuBreakpoint::Change(3)).is_err());
+  }
+
+  #[test]
+  fn reports_invalid_commands_at_their_position () {
+    let errors = [
+      ("set a 1\nmul a 2", (2, 1)),
+      ("set a 1\n  set a", (2, 3)),
+      ("set 5 1", (1, 5)),
+      ("add a q", (1, 7)),
+      ("jnz a b", (1, 7))
+    ];
+    for (program, position) in errors.iter() {
+      let error = controller().load(program).err().unwrap();
+      assert_eq!((error.line, error.column), *position, "{}: {}", program, error.message);
+    }
+    // Nothing is queued from a program failing to load
+    let mut gpu = controller();
+    assert!(gpu.load("set a 1\nmul a 2").is_err());
+    assert_eq!(gpu.run(), GpuHalt::Finished);
+    assert_eq!(gpu.clock, 0);
+  }
+
 }
@@ -11,29 +11,31 @@ pub mod fs;
 // Include dependecies
 use self::gpu::GpuController;
 use self::screen::Screen;
-use self::fs::FS;
+use self::fs::{FS, FsError};
+use crate::lib::input::*;
+use crate::lib::error::*;
 
 /// Device Operating System structure
-pub struct DOS<'a> {
+pub struct DOS {
   // GPU controller
-  pub gpu: GpuController<'a>,
+  pub gpu: GpuController,
   // Screen controller
   pub screen: Screen,
   // Filesystem
-  pub fs: FS<'a>,
+  pub fs: FS,
 }
 
 /// Device Operating System implementation
-impl<'a> DOS<'a> {
+impl DOS {
 
   /// Constructor
-  pub fn new<'b> () -> DOS<'b> {
+  pub fn new () -> DOS {
     // Initialize GPU
-    let gpu: GpuController<'b> = GpuController::new();
+    let gpu: GpuController = GpuController::new();
     // Initialize Screen
     let screen: Screen = Screen::new();
     // Initialize FS
-    let fs: FS<'b> = FS::new();
+    let fs: FS = FS::new();
     // Initialize DOS
     DOS {
       gpu,
@@ -45,40 +47,43 @@ impl<'a> DOS<'a> {
   /// Process terminal input/output and execute all found commands
   /// 
   /// # Arguments
-  /// * lines: Terminal lines to process
-  pub fn process_terminal_stdout(&mut self, lines: &Vec<&'a str>) {
-    // Parse out individial commands
-    let mut command: Vec<&str> = vec![];
-    for line in lines {
-      // New command found
-      if line.starts_with("$") {
-        // Execute previous command
-        if command.len() > 0 {
-          self.exec_terminal_stdout_section(&command);
-        }
-        // Clear previous command
-        command.clear();
-      }
-      // Append line to command
-      command.push(line);
-    }
-    // Execute final command
-    if command.len() > 0 {
-      self.exec_terminal_stdout_section(&command);
+  /// * transcript: Terminal session transcript, each command (prefixed by a `$` prompt) followed by its output
+  /// 
+  /// # Returns
+  /// Nothing, or an error pointing to the first line which failed processing
+  pub fn process_terminal_stdout (&mut self, transcript: &str) -> Result<(), ParseError> {
+    // Feed session line by line
+    for line in Parser::new(transcript).lines() {
+      self.feed(line.text()).map_err(|err| line.error(err.to_string()))?;
     }
+    Ok(())
+  }
 
-    // Prompt empty prompt to show final CWD
-    self.fs.prompt(Option::None);
+  /// Feeds a single line of a terminal session, executing it if it's a command or recording it as the
+  /// last command's output otherwise
+  /// 
+  /// # Arguments
+  /// * line: Line of a terminal session
+  /// 
+  /// # Returns
+  /// Output of the executed command (if any), or an error describing why the line couldn't be processed
+  pub fn feed (&mut self, line: &str) -> Result<String, FsError> {
+    // Support feeding of FS sessions
+    self.fs.feed(line)
   }
 
   /// Executes an OS command
   /// 
   /// # Arguments
-  /// * cmd: Command to execute
-  fn exec_terminal_stdout_section(&mut self, cmd: &Vec<&'a str>) {
+  /// * command: Command to execute (optionally prefixed by a `$` prompt)
+  /// * output:  Lines of output following the command in a session transcript
+  /// 
+  /// # Returns
+  /// Output of the command, or an error describing why command couldn't be executed
+  #[allow(dead_code)]
+  pub fn exec (&mut self, command: &str, output: &[&str]) -> Result<String, FsError> {
     // Support execution of FS commands
-    if self.fs.exec_terminal_stdout_section(cmd) { return; }
+    self.fs.exec(command, output)
   }
 
 }
-
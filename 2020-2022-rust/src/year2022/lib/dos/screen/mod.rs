@@ -3,52 +3,145 @@
 //! Device Operating System Screen module
 // -----------------------------------------------------------------------------
 
+// Include dependencies
+use crate::year::lib::ocr::*;
+
 /// Device Operating System Screen structure
 pub struct Screen {
+  // Width of the screen, in pixels
+  pub width: usize,
+  // Height of the screen, in pixels
+  pub height: usize,
   // Collection of points drawn in sequence by copying a matching location on the sprite
-  pub screen_buffer: [bool; 240],
+  pub screen_buffer: Vec<bool>,
   // Location of the next point on the screen to be drawn
   screen_buffer_pointer: usize,
-  // Sprite used to copy points to the screen when drawing
-  sprite_buffer: [bool; 40]
+  // Shape of the sprite, as offsets of its pixels from the sprite's position
+  sprite: Vec<isize>,
+  // Position of the sprite (column of the row being drawn)
+  sprite_position: isize
 }
 
 /// Device Operating System Screen implementation
 impl Screen {
 
   /// Constructor
+  /// 
+  /// Handheld screen of 40x6 pixels, with a 3 pixel wide sprite
   pub fn new () -> Screen {
+    Screen::with_resolution(40, 6).with_sprite(&[-1, 0, 1])
+  }
+
+  /// Constructor
+  /// 
+  /// Screen of any resolution (of at least 1x1 pixels), with a single pixel sprite
+  /// 
+  /// Arguments
+  /// * width:  Width of the screen, in pixels
+  /// * height: Height of the screen, in pixels
+  pub fn with_resolution (width: usize, height: usize) -> Screen {
     Screen {
-      screen_buffer: [false; 240],
+      width: width.max(1),
+      height: height.max(1),
+      screen_buffer: vec![false; width.max(1) * height.max(1)],
       screen_buffer_pointer: 0,
-      sprite_buffer: [false; 40],
+      sprite: vec![0],
+      sprite_position: 0
     }
   }
 
+  /// Sets shape of the sprite
+  /// 
+  /// Arguments
+  /// * sprite: Offsets of the sprite's pixels from the sprite's position, e.g. `[-1, 0, 1]` for a 3 pixel wide sprite centered on its position
+  pub fn with_sprite (mut self, sprite: &[isize]) -> Screen {
+    self.sprite = sprite.to_vec();
+    self
+  }
+
   /// Updates the sprite position to requested location
   /// 
   /// Arguments
-  /// * position: New position of the middle pixel of the 3 pixel wide sprite
+  /// * position: New position of the sprite (any parts of the sprite outside the screen are never drawn)
   pub fn update_sprite_position(&mut self, position: isize) {
-    // Reset sprice
-    self.sprite_buffer = [false; 40];
-    // Draw sprite
-    if position >=  1 { self.sprite_buffer[(position - 1) as usize] = true; }
-    if position >=  0 { self.sprite_buffer[(position + 0) as usize] = true; }
-    if position >= -1 { self.sprite_buffer[(position + 1) as usize] = true; }
+    self.sprite_position = position;
   }
 
-  /// Draws a pixel to the screen by copying the matching position from the sprite
+  /// Draws a pixel to the screen by copying the matching position from the sprite (once the whole screen
+  /// has been drawn, drawing continues over the first pixel)
   pub fn draw_pixel(&mut self) {
-    self.screen_buffer[self.screen_buffer_pointer] = self.sprite_buffer[self.screen_buffer_pointer % self.sprite_buffer.len()];
-    self.screen_buffer_pointer += 1;
+    let column = (self.screen_buffer_pointer % self.width) as isize;
+    self.screen_buffer[self.screen_buffer_pointer] = self.sprite.iter().any(|offset| self.sprite_position.checked_add(*offset) == Some(column));
+    self.screen_buffer_pointer = (self.screen_buffer_pointer + 1) % self.screen_buffer.len();
+  }
+
+  /// Renders contents of the screen in friendly format
+  pub fn render(&self) -> String {
+    self.screen_buffer.chunks(self.width).map(|row| row.iter().map(|x| if *x { '#' } else { '.' }).collect::<String>()).collect::<Vec<String>>().join("\n")
   }
 
-  /// Outputs contents of the screen in firndly format
-  pub fn prompt(&mut self) {
-    for i in 0..6 {
-      println!("{}", self.screen_buffer[(i*40)..((i+1)*40)].iter().map(|x| if x.clone() { "#" } else { "." }).collect::<Vec<&str>>().join(""));
+  /// Reads text drawn on the screen
+  /// 
+  /// Returns
+  /// Read text, or a description of why text couldn't be read
+  pub fn read(&self) -> Result<String, String> {
+    Ocr::read_buffer(&self.screen_buffer, self.width)
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Draws a full screen, moving the sprite before drawing each pixel
+  fn draw (screen: &mut Screen, positions: &[isize]) {
+    for position in positions.iter() {
+      screen.update_sprite_position(*position);
+      screen.draw_pixel();
     }
   }
 
+  #[test]
+  fn draws_with_configured_resolution_and_sprite () {
+    let mut screen = Screen::with_resolution(4, 2).with_sprite(&[0, 2]);
+    assert_eq!(screen.render(), "....\n....");
+    draw(&mut screen, &[0, 0, 0, 1, 1, 1, 1, 1]);
+    assert_eq!(screen.render(), "#.##\n.#.#");
+    // Single pixel sprite by default, and at least a single pixel screen
+    let mut screen = Screen::with_resolution(0, 0);
+    assert_eq!((screen.width, screen.height), (1, 1));
+    draw(&mut screen, &[0]);
+    assert_eq!(screen.render(), "#");
+  }
+
+  #[test]
+  fn ignores_sprite_pixels_outside_the_screen () {
+    let mut screen = Screen::with_resolution(3, 1).with_sprite(&[-1, 0, 1]);
+    draw(&mut screen, &[-1, -1, -1]);
+    assert_eq!(screen.render(), "#..");
+    draw(&mut screen, &[3, 3, 3]);
+    assert_eq!(screen.render(), "..#");
+    draw(&mut screen, &[isize::MIN, isize::MAX, 100]);
+    assert_eq!(screen.render(), "...");
+  }
+
+  #[test]
+  fn wraps_drawing_around_to_the_first_pixel () {
+    let mut screen = Screen::with_resolution(2, 1);
+    draw(&mut screen, &[0, 1, 5]);
+    assert_eq!(screen.render(), ".#");
+  }
+
+  #[test]
+  fn reads_drawn_text () {
+    let mut screen = Screen::new();
+    let glyph = ["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."];
+    let positions: Vec<isize> = (0..screen.height)
+      .flat_map(|y| (0..screen.width).map(move |x| if glyph[y].chars().nth(x % 5) == Some('#') { x as isize } else { -5 }))
+      .collect();
+    draw(&mut screen, &positions);
+    assert_eq!(screen.read(), Ok(String::from("HHHHHHHH")));
+  }
+
 }
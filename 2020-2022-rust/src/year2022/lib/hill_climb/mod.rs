@@ -4,14 +4,13 @@
 // -----------------------------------------------------------------------------
 
 // Include dependecies
-use crate::year::lib::matrix::Matrix;
+use crate::year::lib::grid::Grid;
+use crate::year::lib::graph::Graph;
 
 /// Hill Climb structure
 pub struct HillClimb {  
-  // Hightmap matrix
-  pub heightmap: Matrix,
-  // Hightmap data
-  heightmap_data: Vec<usize>,
+  // Hightmap
+  pub heightmap: Grid<usize>,
   // Coordinates of the starting point as represented in height data
   pub start: Vec<usize>,
   // Coordinates of the end point as represented in height data
@@ -32,45 +31,26 @@ impl HillClimb {
   /// * is_step_allowed_callback: Callback function checking if step from one height to another height is allowed
   /// * is_position_final_callback: Callback function checking if a position is the final position
   pub fn new (
-    data: Vec<Vec<&str>>,
+    data: &Grid<char>,
     is_step_allowed_callback: fn(hillclimb: &HillClimb, from_height: usize, to_height: usize) -> bool,
     is_position_final_callback: fn(hillclimb: &HillClimb, coords: Vec<usize>, height: usize) -> bool
   ) -> HillClimb {
-    // Initialize hight map data
-    let hightmap = Matrix::new(vec![data[0].len(), data.len()]);    
-    let mut hightmap_data: Vec<usize> = vec![usize::MAX; hightmap.length];
-    let mut start: Vec<usize> = vec![];
-    let mut end: Vec<usize> = vec![];
+    // Find start and end points
+    let start = data.find(&'S').map_or(vec![], |(x, y)| vec![x, y]);
+    let end = data.find(&'E').map_or(vec![], |(x, y)| vec![x, y]);
 
-    // Copy input data into the hightmap
-    for y in 0..data.len() {
-      for x in 0..data[y].len() {
-        // Get index in height map and point
-        let height = data[y][x];
-        let index = hightmap.coords_to_index(&vec![x, y]).unwrap();
-        // Check if start
-        if height == "S" {
-          start = vec![x, y];
-          hightmap_data[index] = 'a' as usize - 96;
-        }
-        // Check if end
-        else if height == "E" {
-          end = vec![x, y];
-          hightmap_data[index] = 'z' as usize - 96;
-        }
-        // Set height
-        else {
-          hightmap_data[index] = height.chars().collect::<Vec<char>>()[0] as usize - 96;
-        }
-      }
-    }
+    // Convert input data into heights, start and end being at lowest and highest heights
+    let heights = data.data.iter().map(|c| match c {
+      'S' => 'a',
+      'E' => 'z',
+      c => *c
+    } as usize - 96).collect();
 
     // Return created hill climb instance
     HillClimb {
       start,
       end,
-      heightmap: hightmap,
-      heightmap_data: hightmap_data,
+      heightmap: Grid::new(data.width(), data.height(), heights),
       is_step_allowed_callback,
       is_position_final_callback
     }
@@ -83,71 +63,30 @@ impl HillClimb {
   /// 
   /// # Returns
   /// * Matrix data (of same measure as .hightmap matrix) containing distances from given start point
-  ///   to all points of the height map
+  ///   to all points of the height map reached before reaching a final position (others being `usize::MAX`)
   pub fn calculate_distances(&mut self, start: &Vec<usize>) -> Vec<usize> {
-    // Initialize result distances
-    let mut distances = vec![usize::MAX; self.heightmap.length];
-
-    // Set starting distance
-    distances[self.heightmap.coords_to_index(start).unwrap()] = 0;
-
-    // Calculate distances
-    let start_index = self.heightmap.coords_to_index(start).unwrap();
-    let mut calculated_points_latest_indexes: Vec<usize> = vec![start_index];
-    let mut calculated_points_count = calculated_points_latest_indexes.len();    
-    while calculated_points_count < self.heightmap.length {
-
-      // Deduplicate latest calculated points
-      calculated_points_latest_indexes.dedup();
-
-      // Check if stuck and unable to unstuck
-      if calculated_points_latest_indexes.len() == 0 {
-        panic!("Can't find a path forward or any miscalculated points!");
-      }
-
-      // Find points next to latest calculated points
-      let mut calculated_points_next_indexes: Vec<(usize, usize)> = vec![];
-      for point_index in calculated_points_latest_indexes {
-        // Find neighbours
-        let neignbouring_point_indexes = self.heightmap.get_neighbours(&point_index, false);
-        // Add neighbours as points to calculate next
-        for neignbouring_point_index in neignbouring_point_indexes {
-          calculated_points_next_indexes.push((point_index, neignbouring_point_index));
-        }
-      }
-
-      // Clear latest calculated points
-      calculated_points_latest_indexes = vec![];
-
-      // Calculate next points
-      for (from_point_index, to_point_index) in calculated_points_next_indexes {
-        // Check if point can be reached
-        let from_height = self.heightmap_data[from_point_index];
-        let to_height = self.heightmap_data[to_point_index];
-        if (self.is_step_allowed_callback)(self, from_height, to_height) {
-          // Set point distance
-          let distance = distances[from_point_index] + 1;
-          if distance < distances[to_point_index] {
-            // Update calculated points count (only if first calculation)
-            if distances[to_point_index] == usize::MAX {
-              calculated_points_count += 1;
-            }
-            // Update distance
-            distances[to_point_index] = distance;
-            // Add point as one of latest calculated point
-            calculated_points_latest_indexes.push(to_point_index);
-          }
-          // Check if end point
-          let to_point = self.heightmap.index_to_coords(&to_point_index).unwrap();
-          if (self.is_position_final_callback)(self, to_point, to_height) {
-            return distances;
-          }
-        }
-      }
+    // Search for the closest final position, stepping only between allowed heights
+    let start_index = self.heightmap.matrix.coords_to_index(start).unwrap();
+    let search = Graph::bfs(
+      start_index,
+      |index| {
+        let from_height = self.heightmap.data[*index];
+        self.heightmap.matrix.neighbours(*index, false)
+          .filter(|neighbour_index| (self.is_step_allowed_callback)(self, from_height, self.heightmap.data[*neighbour_index]))
+          .collect::<Vec<usize>>()
+      },
+      |index| (self.is_position_final_callback)(self, self.heightmap.matrix.index_to_coords(index).unwrap(), self.heightmap.data[*index])
+    );
+    if search.target.is_none() {
+      panic!("All points processed and no path to end found! This should bever happen!");
     }
 
     // Return result distances
-    panic!("All points processed and no path to end found! This should bever happen!");
+    let mut distances = vec![usize::MAX; self.heightmap.matrix.length];
+    for (index, distance) in search.distances {
+      distances[index] = distance;
+    }
+    distances
   }
 
 }
@@ -7,6 +7,7 @@
 use std::collections::hash_set::HashSet;
 use crate::year::lib::math::Math;
 use crate::year::lib::matrix::Matrix;
+use crate::lib::context::*;
 
 /// Monkey Map Face structure
 pub struct MonkeyMapFace {
@@ -137,16 +138,15 @@ impl MonkeyMap {
   /// Starts following directions on the map provided
   /// 
   /// # Arguments
+  /// * context: Execution context, visualizing the map after each direction followed
   /// * coords_callback: Callback function used to determine the next coordinates
   /// 
   /// # Returns
   /// Final coordinates having followed all directions
-  pub fn follow_directions (&mut self, coords_callback: fn(map: &MonkeyMap, position: ((usize, usize), (usize, usize)), orientation: (isize, isize)) -> (((usize, usize), (usize, usize)), (isize, isize))) -> (usize, usize) {
-    // Prompt map state
-    // self._prompt(true, format!("Face: ({}, {})", self.position.0.0, self.position.0.1).as_str(), Option::None);
-
+  pub fn follow_directions (&mut self, context: &PuzzleContext, coords_callback: fn(map: &MonkeyMap, position: ((usize, usize), (usize, usize)), orientation: (isize, isize)) -> (((usize, usize), (usize, usize)), (isize, isize))) -> (usize, usize) {
     // Follow each direction
     let mut path: HashSet<((usize, usize), (usize, usize))> = HashSet::new();
+    context.frame(|| self.render(&path));
     for direction in &self.directions {
       match direction {
 
@@ -154,8 +154,6 @@ impl MonkeyMap {
         MonkeyMapDirection::Turn(direction) => {
           // Turn
           self.orientation = self.turn(self.orientation, direction.clone());
-          // Prompt map state
-          // self._prompt(true, format!("Face: ({}, {}); Turn {}", self.position.0.0, self.position.0.1, direction).as_str(), Option::None);
         }
 
         // Move
@@ -198,20 +196,17 @@ impl MonkeyMap {
               // Move to next position and orientation
               self.position = position;              
               self.orientation = orientation;
-              // Prompt map state
-              // self._prompt(true, format!("Face: ({}, {}); Move {}: {}", position.0.0, position.0.1, distance, i + 1).as_str(), Option::None);
             } else {              
               break;
             }
           }
         }
 
-      }      
+      }
+      // Visualize map state
+      context.frame(|| self.render(&path));
     }
 
-    // Prompt final path
-    // self._prompt(false, "DONE", Option::Some(&path));
-
     // Return final position
     (
       (self.position.0.0 * self.face_length + self.position.1.0 + 1),
@@ -941,8 +936,7 @@ impl MonkeyMap {
     }
 
     // If face found, panic
-    self._prompt(false, format!("Face: ({}, {})", self.position.0.0, self.position.0.1).as_str(), Option::None);
-    panic!("Could not find an indirectly connected cube face!");
+    panic!("Could not find an indirectly connected cube face from face ({}, {})!", self.position.0.0, self.position.0.1);
   }
   /// Determines next candidate coordinates bases only on current coordinates and orientation
   /// 
@@ -965,54 +959,47 @@ impl MonkeyMap {
     )
   }
 
-  /// Prompts current state of the map
+  /// Renders current state of the map
   /// 
   /// # Arguments
-  /// * single_face: If only current face should be prompted
-  /// * comment: Message to print next to the layout
-  /// * path: Optional path already traveled
-  pub fn _prompt (&self, single_face: bool, comment: &str, path: Option<&HashSet<((usize, usize), (usize, usize))>>) {
-    // Skip partial prompts
-    if single_face { return; }
-    // Prompt state
-    for fy in if single_face { self.position.0.1..(self.position.0.1 + 1) } else { 0..4 } {
+  /// * path: Positions already traveled through
+  /// 
+  /// # Returns
+  /// Rendered map, one line per row, with traveled path marked as `x` and current position as its orientation
+  pub fn render (&self, path: &HashSet<((usize, usize), (usize, usize))>) -> String {
+    let mut lines: Vec<String> = vec![];
+    for fy in 0..4 {
       for y in 0..self.face_length {
-        // Print line
-        for fx in if single_face { self.position.0.0..(self.position.0.0 + 1) } else { 0..4 } {
+        let mut line = String::with_capacity(4 * self.face_length);
+        for fx in 0..4 {
           for x in 0..self.face_length {
-            // Prompt current position
+            // Render current position
             if ((fx, fy), (x, y)) == self.position {
-              print!("{}", match self.orientation {
-                ( 1,  0 ) => ">",
-                ( 0,  -1) => "^",
-                (-1,  0 ) => "<",
-                ( 0,  1 ) => "v",
+              line.push(match self.orientation {
+                ( 1,  0 ) => '>',
+                ( 0,  -1) => '^',
+                (-1,  0 ) => '<',
+                ( 0,  1 ) => 'v',
                 _ => panic!("Orientation not supported!")
               });
             }
-            // Prompt map element
+            // Render map element
             else {
-              match &self.faces[self.faces_matrix.coords_to_index(&vec![fx, fy]).unwrap()] {
-                Option::None => {
-                  print!(" ");
-                },
-                Option::Some(face) => {
-                  let position = face.coords[self.face_matrix.coords_to_index(&vec![x, y]).unwrap()];
-                  print!("{}", match path {
-                    Option::None => position,
-                    Option::Some(path) => if path.contains(&((fx, fy), (x, y))) { 'x' } else { position }
-                  });
-                }
-              }
-              
+              line.push(match &self.faces[self.faces_matrix.coords_to_index(&vec![fx, fy]).unwrap()] {
+                Option::None => ' ',
+                Option::Some(_) if path.contains(&((fx, fy), (x, y))) => 'x',
+                Option::Some(face) => face.coords[self.face_matrix.coords_to_index(&vec![x, y]).unwrap()]
+              });
             }
           }
         }
-        // New line
-        if fy == self.position.0.1 && y == 0 { print!(" {}", comment); }
-        println!();
+        lines.push(line.trim_end().to_string());
       }
     }
-    println!();
+    // Drop empty rows of faces
+    while lines.last().is_some_and(|line| line.is_empty()) {
+      lines.pop();
+    }
+    lines.join("\n")
   }
 }
@@ -135,11 +135,11 @@ impl RegolithReservoir {
     }
   }
 
-  /// Prompts current reservoir state
-  pub fn _prompt(&self) {
-    // Prompt reservoir
+  /// Renders current reservoir state
+  pub fn render(&self) -> String {
+    // Render reservoir
     let values = self.hash.keys().map(|k| k.clone()).collect::<Vec<(usize, usize)>>();
-    DotDisplay::print(
+    DotDisplay::render(
       [
         values,
         match self.current {
@@ -152,6 +152,6 @@ impl RegolithReservoir {
         Option::Some(value) => if value.clone() { '#' } else { 'o' }
       },
       &self.hash
-    );
+    )
   }
 }
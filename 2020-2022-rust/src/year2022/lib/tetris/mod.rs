@@ -117,9 +117,6 @@ impl Tetris {
     // Calculate initial spawning offset
     let mut offset_diff = 0;
     let mut offset = self.field_empty - shape_rows - 3;
-
-    // Prompt field and staged tetrimino
-    // self._prompt((shape, shape_shift), offset);
   
     // Drop shape until contact with floor or locked tetrimino is made    
     loop {
@@ -137,9 +134,6 @@ impl Tetris {
         } else {
           shape_shift
         };
-
-      // Prompt field and falling tetrimino
-      // self._prompt((shape, shape_shift), offset);
   
       // Fall down if shape not settled after being shifted by the wind
       if offset_diff < 3 || !self.shape_check_collision((shape, shape_shift), offset + 1) {
@@ -151,9 +145,6 @@ impl Tetris {
         self.shape_lock((shape, shape_shift), offset);
         break;
       }
-      
-      // Prompt field and falling tetrimino
-      // self._prompt((shape, shape_shift), offset);
     }
 
     // Update empty rows
@@ -164,9 +155,6 @@ impl Tetris {
     // Update number of tetriminos dropped
     self.field_dropped += 1;
 
-    // Prompt field and falling tetrimino
-    // self._prompt((shape, shape_shift), offset);
-
     // Check if more than 50% field full and needs partial clearing
     if self.field_empty < FIELD_SIZE / 2 {
       // Find 2 subsequent rows making a tetris(ish)
@@ -246,20 +234,11 @@ impl Tetris {
     self.field[(offset + 3) % field_len] = self.field[(offset + 3) % field_len] | shape.0[3][shape.1] & 0b0_1111111;
   }
 
-  /// Prompts current state of the field and any faling tetriminos
-  /// 
-  /// # Arguments
-  /// * shape: Definition of a shape currently dropping and its current shift
-  /// * offset: Distance from the top the shape has dropped
-  pub fn _prompt (&self, shape: (&[[u8; 9]; 4], usize), offset: usize) {
-    println!();
-    for i in (self.field_empty - 7)..self.field.len() {
-      // Prompt field line with the currently falling tetrimino superimposed
-      let field_line = if i < self.field_empty { 0b0_0000000 } else { self.field[i] };
-      let line_combined: u8 = if i >= offset && i < (offset + shape.0.len()) { field_line | shape.0[i - offset][shape.1] } else { field_line };
-      let line_combined_string = (format!("{:#10b}", line_combined)).chars().map(|c| if c == '1' { '#' } else { '.' } ).collect::<String>()[3..].to_string();
-      println!("{}", line_combined_string);
-    }
-    return;
+  /// Renders current state of the field (down to the floor, or the last cleared row)
+  pub fn render (&self) -> String {
+    self.field[self.field_empty.saturating_sub(3)..].iter()
+      .map(|line| (0..7).rev().map(|bit| if line & (1 << bit) != 0 { '#' } else { '.' }).collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n")
   }
 }
@@ -7,6 +7,7 @@
 use std::collections::hash_set::HashSet;
 use std::collections::hash_map::HashMap;
 use crate::year::lib::dot_display::DotDisplay;
+use crate::year::lib::grid::Grid;
 
 /// Unstable Diffusion structure
 pub struct UnstableDiffusion  {
@@ -20,17 +21,15 @@ pub struct UnstableDiffusion  {
 impl UnstableDiffusion {
 
   /// Constructor
-  pub fn new (data: Vec<Vec<&str>>) -> UnstableDiffusion {
-    // Initialize a hashset
-    let mut points: HashSet<(isize, isize)> = HashSet::with_capacity(data.len());
-    // Fill hashset
-    for y in 0..data.len() {
-      for x in 0..data[y].len() {
-        if data[y][x] == "#" {
-          points.insert((x as isize, y as isize));
-        }
-      }
-    }
+  /// 
+  /// # Arguments
+  /// * data: Initial map of occupied positions
+  pub fn new (data: &Grid<bool>) -> UnstableDiffusion {
+    // Collect occupied positions into a hashset
+    let points: HashSet<(isize, isize)> = data.iter()
+      .filter(|(_, occupied)| **occupied)
+      .map(|((x, y), _)| (x as isize, y as isize))
+      .collect();
     // Return new instance of Unstable Diffusion
     UnstableDiffusion {
       // Directions stack
@@ -160,11 +159,11 @@ impl UnstableDiffusion {
     ((max.0 - min.0 + 1) as usize, (max.1 - min.1 + 1) as usize)
   }
 
-  /// Prompts current state of the field of coordinates
-  pub fn _prompt (&self) {
+  /// Renders current state of the field of coordinates
+  pub fn render (&self) -> String {
     // Draw coordinates
     let points = &self.points.iter().map(|p| p.clone()).collect::<Vec<(isize, isize)>>();
     let normalized = DotDisplay::normalize(points);
-    DotDisplay::print_binary(normalized);
+    DotDisplay::render_binary(normalized)
   }
 }